@@ -2,14 +2,43 @@
 
 extern crate test;
 
+use std::alloc::{GlobalAlloc, Layout, System};
 use std::ffi::{CStr, CString};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use test::{black_box, Bencher};
 
+use jiter::FloatMode;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString};
 
 use _pydantic_core::SchemaValidator;
 
+/// Counts allocations made through Rust's allocator, so benches can check how much validation allocates
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// The number of allocations made by `f`
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(f());
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
 fn build_schema_validator_with_globals(
     py: Python,
     code: &CStr,
@@ -27,13 +56,54 @@ fn json<'a>(py: Python<'a>, code: &'a str) -> Bound<'a, PyAny> {
     black_box(PyString::new(py, code).into_any())
 }
 
+/// `validate_python` with the default for every keyword argument
+fn validate(validator: &SchemaValidator, py: Python, input: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+    validator.validate_python(
+        py,
+        input,
+        None,
+        None,
+        None,
+        None,
+        None,
+        false.into(),
+        None,
+        None,
+        false,
+        false,
+        true,
+        false,
+        false,
+        false,
+        false,
+        false,
+        false,
+    )
+}
+
 #[bench]
 fn ints_json(bench: &mut Bencher) {
     Python::attach(|py| {
         let validator = build_schema_validator(py, c"{'type': 'int'}");
 
         let result = validator
-            .validate_json(py, &json(py, "123"), None, None, None, None, false.into(), None, None)
+            .validate_json(
+                py,
+                &json(py, "123"),
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                FloatMode::Float,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
@@ -41,7 +111,23 @@ fn ints_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, "123"), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, "123"),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        FloatMode::Float,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -54,20 +140,12 @@ fn ints_python(bench: &mut Bencher) {
         let validator = build_schema_validator(py, c"{'type': 'int'}");
 
         let Ok(input) = 123_i64.into_pyobject(py);
-        let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        let result = validate(&validator, py, &input).unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
 
         let input = black_box(input);
-        bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            )
-        })
+        bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
     })
 }
 
@@ -83,7 +161,23 @@ fn list_int_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        FloatMode::Float,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -108,9 +202,7 @@ fn list_int_python(bench: &mut Bencher) {
         let (validator, input) = list_int_input(py);
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                .unwrap();
+            let v = validate(&validator, py, &input).unwrap();
             black_box(v)
         })
     })
@@ -135,6 +227,61 @@ fn list_int_python_isinstance(bench: &mut Bencher) {
     })
 }
 
+fn many_int_input(py: Python<'_>) -> (SchemaValidator, Py<PyList>) {
+    let validator = build_schema_validator(py, c"{'type': 'int'}");
+    let code = CString::new(format!(
+        "[{}]",
+        (0..100).map(|x| format!("'{x}'")).collect::<Vec<String>>().join(",")
+    ))
+    .unwrap();
+
+    let input = py.eval(&code, None, None).unwrap().downcast_into().unwrap();
+    (validator, input.unbind())
+}
+
+#[bench]
+fn many_int_python_loop(bench: &mut Bencher) {
+    Python::attach(|py| {
+        let (validator, input) = many_int_input(py);
+        let input = black_box(input.bind(py));
+        bench.iter(|| {
+            let results = input
+                .iter()
+                .map(|item| validate(&validator, py, &item).unwrap())
+                .collect::<Vec<_>>();
+            black_box(results)
+        })
+    })
+}
+
+#[bench]
+fn many_int_python_batch(bench: &mut Bencher) {
+    Python::attach(|py| {
+        let (validator, input) = many_int_input(py);
+        let input = black_box(input.bind(py));
+        bench.iter(|| {
+            let v = validator
+                .validate_python_batch(py, input.as_any(), None, None, None, None, None, None)
+                .unwrap();
+            black_box(v)
+        })
+    })
+}
+
+#[bench]
+fn many_int_python_many(bench: &mut Bencher) {
+    Python::attach(|py| {
+        let (validator, input) = many_int_input(py);
+        let input = black_box(input.bind(py));
+        bench.iter(|| {
+            let v = validator
+                .validate_python_many(py, input, None, None, None, None, None, None)
+                .unwrap();
+            black_box(v)
+        })
+    })
+}
+
 #[bench]
 fn list_error_json(bench: &mut Bencher) {
     Python::attach(|py| {
@@ -147,7 +294,23 @@ fn list_error_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        match validator.validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None) {
+        match validator.validate_json(
+            py,
+            &json(py, &code),
+            None,
+            None,
+            None,
+            None,
+            false.into(),
+            None,
+            None,
+            FloatMode::Float,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -159,7 +322,23 @@ fn list_error_json(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            match validator.validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None) {
+            match validator.validate_json(
+                py,
+                &json(py, &code),
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                FloatMode::Float,
+                false,
+                false,
+                false,
+                false,
+                false,
+            ) {
                 Ok(_) => panic!("unexpectedly valid"),
                 Err(e) => black_box(e),
             }
@@ -180,7 +359,7 @@ fn list_error_python_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
 
     let input = py.eval(&code, None, None).unwrap().extract().unwrap();
 
-    match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None) {
+    match validate(&validator, py, &input) {
         Ok(_) => panic!("unexpectedly valid"),
         Err(e) => {
             let v = e.value(py);
@@ -200,7 +379,7 @@ fn list_error_python(bench: &mut Bencher) {
 
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None);
+            let result = validate(&validator, py, &input);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -210,6 +389,37 @@ fn list_error_python(bench: &mut Bencher) {
     })
 }
 
+/// Validate `rows` lists of ints with an invalid item in each, only the errors' locations differ between rows
+fn list_of_lists_error_python_allocations(py: Python<'_>, validator: &SchemaValidator, rows: usize) -> usize {
+    let code = CString::new(format!("[[1, 'x', 3]] * {rows}")).unwrap();
+    let input = py.eval(&code, None, None).unwrap();
+    count_allocations(|| validate(&validator, py, &input).unwrap_err())
+}
+
+#[bench]
+fn list_of_lists_error_python(bench: &mut Bencher) {
+    Python::attach(|py| {
+        let validator = build_schema_validator(
+            py,
+            c"{'type': 'list', 'items_schema': {'type': 'list', 'items_schema': {'type': 'int'}}}",
+        );
+
+        // each row's error vector is reused for the next row, so a row only allocates its output vector and
+        // its error's location, plus the amortized growth of the outer errors; without reuse it's over 300
+        let extra = list_of_lists_error_python_allocations(py, &validator, 200)
+            - list_of_lists_error_python_allocations(py, &validator, 100);
+        assert!(extra <= 250, "100 extra rows made {extra} allocations");
+
+        let code = CString::new("[[1, 'x', 3]] * 100").unwrap();
+        let input = py.eval(&code, None, None).unwrap();
+        let input = black_box(input);
+        bench.iter(|| {
+            let result = validate(&validator, py, &input);
+            black_box(result.unwrap_err())
+        })
+    })
+}
+
 #[bench]
 fn list_error_python_isinstance(bench: &mut Bencher) {
     Python::attach(|py| {
@@ -242,7 +452,23 @@ fn list_any_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        FloatMode::Float,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -261,9 +487,7 @@ fn list_any_python(bench: &mut Bencher) {
         let input = py.eval(&code, None, None).unwrap();
         let input = black_box(input);
         bench.iter(|| {
-            let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                .unwrap();
+            let v = validate(&validator, py, &input).unwrap();
             black_box(v)
         })
     })
@@ -296,7 +520,23 @@ fn dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        FloatMode::Float,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -322,9 +562,7 @@ fn dict_python(bench: &mut Bencher) {
         let input = py.eval(&code, None, None).unwrap();
         let input = black_box(input);
         bench.iter(|| {
-            let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                .unwrap();
+            let v = validate(&validator, py, &input).unwrap();
             black_box(v)
         })
     })
@@ -353,7 +591,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = py.eval(&code, None, None).unwrap();
 
-        match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None) {
+        match validate(&validator, py, &input) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -366,7 +604,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = black_box(input);
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None);
+            let result = validate(&validator, py, &input);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -404,7 +642,23 @@ fn typed_dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, code), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        FloatMode::Float,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -438,9 +692,7 @@ fn typed_dict_python(bench: &mut Bencher) {
         let input = py.eval(&code, None, None).unwrap();
         let input = black_box(input);
         bench.iter(|| {
-            let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                .unwrap();
+            let v = validate(&validator, py, &input).unwrap();
             black_box(v)
         })
     })
@@ -480,7 +732,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         let input = py.eval(code, None, None).unwrap();
         let input = black_box(input);
 
-        match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None) {
+        match validate(&validator, py, &input) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -492,7 +744,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None);
+            let result = validate(&validator, py, &input);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -516,11 +768,7 @@ fn complete_model(bench: &mut Bencher) {
         let input = black_box(input);
 
         bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            );
+            black_box(validate(&validator, py, &input).unwrap());
         })
     })
 }
@@ -538,16 +786,10 @@ fn nested_model_using_definitions(bench: &mut Bencher) {
         let input = complete_schema.call_method0("input_data_valid").unwrap();
         let input = black_box(input);
 
-        validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        validate(&validator, py, &input).unwrap();
 
         bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            );
+            black_box(validate(&validator, py, &input).unwrap());
         })
     })
 }
@@ -565,16 +807,10 @@ fn nested_model_inlined(bench: &mut Bencher) {
         let input = complete_schema.call_method0("input_data_valid").unwrap();
         let input = black_box(input);
 
-        validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        validate(&validator, py, &input).unwrap();
 
         bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            );
+            black_box(validate(&validator, py, &input).unwrap());
         })
     })
 }
@@ -585,20 +821,12 @@ fn literal_ints_few_python(bench: &mut Bencher) {
         let validator = build_schema_validator(py, c"{'type': 'literal', 'expected': list(range(5))}");
 
         let Ok(input) = 4_i64.into_pyobject(py);
-        let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        let result = validate(&validator, py, &input).unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 4);
 
         let input = black_box(input);
-        bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            )
-        })
+        bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
     })
 }
 
@@ -609,20 +837,12 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
 
         let input = py.eval(c"'4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
-        let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        let result = validate(&validator, py, &input).unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            )
-        })
+        bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
     })
 }
 
@@ -636,20 +856,12 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
 
         let input = py.eval(c"'a' * 25 + '4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
-        let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        let result = validate(&validator, py, &input).unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            )
-        })
+        bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
     })
 }
 
@@ -679,19 +891,11 @@ class Foo(Enum):
         );
 
         let input = py.eval(c"Foo.v4", Some(&globals), None).unwrap();
-        let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        let result = validate(&validator, py, &input).unwrap();
         assert!(input.eq(result).unwrap());
 
         let input = black_box(input);
-        bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            )
-        })
+        bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
     })
 }
 
@@ -701,20 +905,12 @@ fn literal_ints_many_python(bench: &mut Bencher) {
         let validator = build_schema_validator(py, c"{'type': 'literal', 'expected': list(range(100))}");
 
         let Ok(input) = 99_i64.into_pyobject(py);
-        let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        let result = validate(&validator, py, &input).unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
 
         let input = black_box(input);
-        bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            )
-        })
+        bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
     })
 }
 
@@ -726,20 +922,12 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
 
         let input = py.eval(c"'99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
-        let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        let result = validate(&validator, py, &input).unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            )
-        })
+        bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
     })
 }
 
@@ -753,20 +941,12 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
 
         let input = py.eval(c"'a' * 25 + '99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
-        let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-            .unwrap();
+        let result = validate(&validator, py, &input).unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| {
-            black_box(
-                validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                    .unwrap(),
-            )
-        })
+        bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
     })
 }
 
@@ -777,7 +957,23 @@ fn literal_ints_many_json(bench: &mut Bencher) {
 
         let input_json = py.eval(c"'99'", None, None).unwrap();
         let result = validator
-            .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+            .validate_json(
+                py,
+                &input_json,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                FloatMode::Float,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
@@ -786,7 +982,23 @@ fn literal_ints_many_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &input_json,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        FloatMode::Float,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -805,7 +1017,23 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         let input_json = py.eval(c"'\"' + 'a' * 25 + '99' + '\"'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+            .validate_json(
+                py,
+                &input_json,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                FloatMode::Float,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -814,7 +1042,23 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &input_json,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        FloatMode::Float,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -849,76 +1093,44 @@ class Foo(Enum):
         {
             let input = py.eval(c"'null'", None, None).unwrap();
             let input_str: String = input.extract().unwrap();
-            let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                .unwrap();
+            let result = validate(&validator, py, &input).unwrap();
             let result_str: String = result.extract(py).unwrap();
             assert_eq!(result_str, input_str);
 
             let input = black_box(input);
-            bench.iter(|| {
-                black_box(
-                    validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                        .unwrap(),
-                )
-            })
+            bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
         }
 
         // Int
         {
             let input = py.eval(c"-1", None, None).unwrap();
             let input_int: i64 = input.extract().unwrap();
-            let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                .unwrap();
+            let result = validate(&validator, py, &input).unwrap();
             let result_int: i64 = result.extract(py).unwrap();
             assert_eq!(result_int, input_int);
 
             let input = black_box(input);
-            bench.iter(|| {
-                black_box(
-                    validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                        .unwrap(),
-                )
-            })
+            bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
         }
 
         // None
         {
             let input = py.eval(c"None", None, None).unwrap();
-            let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                .unwrap();
+            let result = validate(&validator, py, &input).unwrap();
             assert!(input.eq(result).unwrap());
 
             let input = black_box(input);
-            bench.iter(|| {
-                black_box(
-                    validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                        .unwrap(),
-                )
-            })
+            bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
         }
 
         // Enum
         {
             let input = py.eval(c"Foo.v4", Some(&globals), None).unwrap();
-            let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                .unwrap();
+            let result = validate(&validator, py, &input).unwrap();
             assert!(input.eq(result).unwrap());
 
             let input = black_box(input);
-            bench.iter(|| {
-                black_box(
-                    validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
-                        .unwrap(),
-                )
-            })
+            bench.iter(|| black_box(validate(&validator, py, &input).unwrap()))
         }
     })
 }
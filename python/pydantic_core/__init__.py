@@ -23,6 +23,7 @@ from ._pydantic_core import (
     TzInfo,
     Url,
     ValidationError,
+    ValidationResult,
     __version__,
     from_json,
     to_json,
@@ -58,6 +59,7 @@ __all__ = [
     'ErrorDetails',
     'InitErrorDetails',
     'ValidationError',
+    'ValidationResult',
     'PydanticCustomError',
     'PydanticKnownError',
     'PydanticOmit',
@@ -1,5 +1,5 @@
 import datetime
-from collections.abc import Mapping
+from collections.abc import Iterable, Mapping
 from typing import Any, Callable, Generic, Literal, TypeVar, final
 
 from _typeshed import SupportsAllComparisons
@@ -29,6 +29,7 @@ __all__ = [
     'PydanticUndefined',
     'PydanticUndefinedType',
     'Some',
+    'ValidationResult',
     'to_json',
     'from_json',
     'to_jsonable_python',
@@ -63,6 +64,35 @@ class Some(Generic[_T]):
     @classmethod
     def __class_getitem__(cls, item: Any, /) -> type[Self]: ...
 
+@final
+class ValidationResult:
+    """
+    The result of [`SchemaValidator.validate_python`][pydantic_core.SchemaValidator.validate_python] when it's asked
+    for more than the validated object, attributes which weren't asked for are `None`.
+    """
+
+    @property
+    def value(self) -> Any:
+        """The validated object."""
+    @property
+    def changed(self) -> bool | None:
+        """Whether validation changed the input, with `report_changes=True`."""
+    @property
+    def hash(self) -> int | None:
+        """The stable hash of the validated object, with `with_hash=True`."""
+    @property
+    def field_timings(self) -> dict[tuple[int | str, ...], int] | None:
+        """Nanoseconds spent validating each field by its location, with `profile=True`."""
+    @property
+    def union_matches(self) -> list[tuple[tuple[int | str, ...], int | str]] | None:
+        """The location of each union and the member it matched, with `union_matches=True`."""
+    @property
+    def extra_types(self) -> dict[tuple[int | str, ...], type] | None:
+        """The type of each extra field by its location, with `extra_types=True`."""
+    @property
+    def defaults_used(self) -> set[tuple[int | str, ...]] | None:
+        """The location of each field set from its default, with `defaults_used=True`."""
+
 @final
 class SchemaValidator:
     """
@@ -99,7 +129,16 @@ class SchemaValidator:
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
-    ) -> Any:
+        report_changes: bool = False,
+        with_hash: bool = False,
+        track_locations: bool = True,
+        intern_strings: bool = False,
+        profile: bool = False,
+        union_matches: bool = False,
+        deep_freeze: bool = False,
+        extra_types: bool = False,
+        defaults_used: bool = False,
+    ) -> Any | ValidationResult:
         """
         Validate a Python object against the schema and return the validated object.
 
@@ -120,13 +159,88 @@ class SchemaValidator:
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            report_changes: Whether to also report if validation changed the input, as `ValidationResult.changed`,
+                which is `False` when the validated object is the input itself, or has the same type as the input
+                and compares equal to it.
+            with_hash: Whether to also return a 64-bit hash of the validated object, as `ValidationResult.hash`.
+                The hash is computed from the canonical structure of the object, so it's stable between processes
+                and independent of dict and set ordering.
+                A `TypeError` is raised if the object contains a value without a canonical form to hash.
+            track_locations: Whether to build the location of each error, with `False` errors have an empty `loc`,
+                which saves work when only whether validation passed or failed matters.
+            intern_strings: Whether equal strings in the validated object should share a single string object,
+                reducing memory for data with many repeated values.
+            profile: Whether to also return the time spent validating each model and typed dict field, as
+                `ValidationResult.field_timings`, a dict mapping the location of each field to nanoseconds.
+                A field's timing includes the time spent validating any fields nested within it.
+            union_matches: Whether to also return the member matched by each union validated, as
+                `ValidationResult.union_matches`, a list of `(location, member)` pairs in the order the unions
+                finish validating, where `member` is the index of the chosen member, or its tag for a tagged union.
+                Unions within members which weren't chosen aren't included.
+            deep_freeze: Whether containers should be returned immutable, lists are returned as tuples, sets as
+                frozensets and dicts wrapped in a read-only `types.MappingProxyType`, at every level of nesting.
+            extra_types: Whether to also return the type of each extra field of the models validated, as
+                `ValidationResult.extra_types`, a dict mapping the location of each extra field to its type,
+                within a union only the extra fields of the member chosen are included.
+            defaults_used: Whether to also return the location of each field set from its default, as
+                `ValidationResult.defaults_used`, a set of locations,
+                within a union only the defaults of the member chosen are included.
 
         Raises:
             ValidationError: If validation fails.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated object.
+            The validated object, or a `ValidationResult` holding it if any of `report_changes`, `with_hash`,
+            `profile`, `union_matches`, `extra_types` or `defaults_used` is `True`.
+        """
+    def validate_python_batch(
+        self,
+        inputs: Iterable[Any],
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> list[Any | ValidationError]:
+        """
+        Validate each item of `inputs` with [`validate_python()`][pydantic_core.SchemaValidator.validate_python],
+        without one invalid item stopping the rest from being validated. The validation state is shared between
+        items, which is faster than calling `validate_python()` for each one.
+
+        Arguments match `validate_python()`.
+
+        Raises:
+            Exception: Internal errors are raised rather than collected.
+
+        Returns:
+            A list with one entry per input, either the validated object or the `ValidationError` for that input.
+        """
+    def validate_python_many(
+        self,
+        inputs: list[Any],
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> tuple[list[Any], list[ValidationError | None]]:
+        """
+        Validate each item of `inputs` like [`validate_python()`][pydantic_core.SchemaValidator.validate_python],
+        sharing the validation state between items, which is faster than calling `validate_python()` for each one.
+
+        Arguments match `validate_python()`.
+
+        Raises:
+            Exception: Internal errors are raised rather than collected.
+
+        Returns:
+            A tuple of two lists the same length as `inputs`, the validated objects and the `ValidationError`s,
+                with `None` in the other list at each position.
         """
     def isinstance_python(
         self,
@@ -146,6 +260,26 @@ class SchemaValidator:
         Arguments match `validate_python()`. This method will not raise `ValidationError`s but will raise internal
         errors.
 
+        Returns:
+            `True` if validation succeeds, `False` if validation fails.
+        """
+    def isinstance_json(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        self_instance: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> bool:
+        """
+        Similar to [`validate_json()`][pydantic_core.SchemaValidator.validate_json] but returns a boolean.
+
+        Arguments match `validate_json()`. Validation stops at the first error and error details aren't built,
+        invalid JSON returns `False`. This method will not raise `ValidationError`s but will raise internal errors.
+
         Returns:
             `True` if validation succeeds, `False` if validation fails.
         """
@@ -160,6 +294,12 @@ class SchemaValidator:
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        json_number_mode: Literal['float', 'decimal', 'lossless-float'] = 'float',
+        parse_only: bool = False,
+        forbid_duplicate_keys: bool = False,
+        preserve_number_type: bool = False,
+        json_warnings: bool = False,
+        deep_freeze: bool = False,
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -184,6 +324,19 @@ class SchemaValidator:
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            json_number_mode: How JSON numbers with a fractional part or exponent are read; with `'decimal'` they're
+                exact `Decimal` values wherever the raw value is used, e.g. by `any` and function validators or by
+                `decimal` fields, the data is still validated as JSON. `'lossless-float'` requires `parse_only`.
+            parse_only: Whether to skip validation and return the parsed JSON data as plain Python objects.
+            forbid_duplicate_keys: Whether to raise an error when an object in the JSON data has duplicate keys,
+                by default the last value for a duplicated key is used.
+            preserve_number_type: Whether float fields keep integer JSON numbers as ints, so `1` and `1.0`
+                in the JSON data remain distinguishable after validation.
+            json_warnings: Whether to emit a `UserWarning` for anomalies in the JSON data instead of failing or
+                silently losing information: lone surrogate escapes in strings are replaced with U+FFFD,
+                and numbers decoded as floats with more significant digits than a float can hold are reported.
+            deep_freeze: Whether containers should be returned immutable, as with
+                [`validate_python()`][pydantic_core.SchemaValidator.validate_python].
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
@@ -192,6 +345,93 @@ class SchemaValidator:
         Returns:
             The validated Python object.
         """
+    def validate_json_prefix(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        self_instance: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+        return_consumed: bool = False,
+    ) -> Any:
+        """
+        Validate the first JSON document in `input` against the schema, ignoring any data after it.
+
+        This is useful for reading several JSON documents from a single buffer.
+
+        Arguments:
+            input: The JSON data to validate, starting with the document to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            self_instance: An instance of a model set attributes on from validation.
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+            return_consumed: Whether to also return the number of bytes the first document occupied,
+                including any leading whitespace.
+
+        Raises:
+            ValidationError: If validation fails or if the first JSON document is invalid.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The validated Python object, or a `(value, consumed)` tuple if `return_consumed` is set.
+        """
+    def validate_ndjson(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+        json_number_mode: Literal['float', 'decimal', 'lossless-float'] = 'float',
+        parse_only: bool = False,
+        forbid_duplicate_keys: bool = False,
+        preserve_number_type: bool = False,
+        json_warnings: bool = False,
+        deep_freeze: bool = False,
+    ) -> tuple[list[Any], list[tuple[int, ValidationError]]]:
+        """
+        Validate each line of newline-delimited JSON in `input` against the schema, skipping blank lines.
+
+        An invalid line doesn't stop validation of the following lines, instead its error is collected.
+        Each line is validated as with [`validate_json()`][pydantic_core.SchemaValidator.validate_json].
+
+        Arguments:
+            input: The newline-delimited JSON data to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+            json_number_mode: How JSON numbers with a fractional part or exponent are read, as with `validate_json()`.
+            parse_only: Whether to skip validation and return the parsed JSON data of each line.
+            forbid_duplicate_keys: Whether to raise an error when an object in a line has duplicate keys.
+            preserve_number_type: Whether float fields keep integer JSON numbers as ints.
+            json_warnings: Whether to emit a `UserWarning` for anomalies in the JSON data, as with `validate_json()`.
+            deep_freeze: Whether containers should be returned immutable, as with
+                [`validate_python()`][pydantic_core.SchemaValidator.validate_python].
+
+        Raises:
+            ValidationError: If `input` isn't a str, bytes or bytearray.
+            Exception: Internal errors are raised rather than collected.
+
+        Returns:
+            A tuple of the validated objects of the valid lines, and a list of `(line_number, error)` tuples with
+                the `ValidationError` of each invalid line and its 1-based line number, which is also the first item
+                of each error's location.
+        """
     def validate_strings(
         self,
         input: _StringInput,
@@ -323,6 +563,8 @@ class SchemaSerializer:
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
+        diff_against: dict[str, Any] | None = None,
+        emit_null_for_excluded: bool = False,
     ) -> Any:
         """
         Serialize/marshal a Python object to a Python object including transforming and filtering data.
@@ -347,6 +589,10 @@ class SchemaSerializer:
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            diff_against: A dict of previously serialized field values, fields whose serialized value
+                equals the value in this dict are omitted from the output.
+            emit_null_for_excluded: Whether fields excluded by `include` or `exclude` are serialized as `None`
+                rather than omitted from the output, fields omitted for any other reason are still omitted.
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -372,6 +618,8 @@ class SchemaSerializer:
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
+        diff_against: dict[str, Any] | None = None,
+        emit_null_for_excluded: bool = False,
     ) -> bytes:
         """
         Serialize a Python object to JSON including transforming and filtering data.
@@ -397,6 +645,10 @@ class SchemaSerializer:
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            diff_against: A dict of previously serialized field values, fields whose serialized value
+                equals the value in this dict are omitted from the output.
+            emit_null_for_excluded: Whether fields excluded by `include` or `exclude` are serialized as `None`
+                rather than omitted from the output, fields omitted for any other reason are still omitted.
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -694,6 +946,23 @@ class ValidationError(ValueError):
         Returns:
             A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error.
         """
+    def errors_tree(
+        self, *, include_url: bool = True, include_context: bool = True, include_input: bool = True
+    ) -> dict[str | int, Any]:
+        """
+        Details about each error in the validation error, nested following each error's `loc`.
+
+        Each location item becomes a key of a nested dict, with the errors at a location collected in a list.
+        Errors at the root, or at a location which also has errors nested below it, are stored under `'__root__'`.
+
+        Args:
+            include_url: Whether to include a URL to documentation on the error each error.
+            include_context: Whether to include the context of each error.
+            include_input: Whether to include the input value of each error.
+
+        Returns:
+            A nested dict of [`ErrorDetails`][pydantic_core.ErrorDetails] lists.
+        """
     def json(
         self,
         *,
@@ -746,15 +1015,25 @@ class PydanticCustomError(ValueError):
         error_type: The error type.
         message_template: The message template.
         context: The data to inject into the message template.
+        loc: A location appended to the location the error is raised at, e.g. to point at a field from a model validator.
     """
 
     def __init__(
-        self, error_type: LiteralString, message_template: LiteralString, context: dict[str, Any] | None = None, /
+        self,
+        error_type: LiteralString,
+        message_template: LiteralString,
+        context: dict[str, Any] | None = None,
+        loc: tuple[str | int, ...] | list[str | int] | None = None,
+        /,
     ) -> None: ...
     @property
     def context(self) -> dict[str, Any] | None:
         """Values which are required to render the error message, and could hence be useful in passing error data forward."""
 
+    @property
+    def loc(self) -> tuple[str | int, ...] | None:
+        """The location appended to the location the error is raised at, if any."""
+
     @property
     def type(self) -> str:
         """The error type associated with the error. For consistency with Pydantic, this is typically a snake_case string."""
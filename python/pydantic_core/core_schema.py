@@ -45,7 +45,8 @@ class CoreConfig(TypedDict, total=False):
     Base class for schema configuration options.
 
     Attributes:
-        title: The name of the configuration.
+        title: The name of the configuration, on the config of a model or typed dict which is the outermost schema
+            it replaces the class name in `ValidationError` titles.
         strict: Whether the configuration should strictly adhere to specified rules.
         extra_fields_behavior: The behavior for handling extra fields.
         typed_dict_total: Whether the TypedDict should be considered total. Default is `True`.
@@ -54,6 +55,8 @@ class CoreConfig(TypedDict, total=False):
             `field_names` to construct error `loc`s. Default is `True`.
         revalidate_instances: Whether instances of models and dataclasses should re-validate. Default is 'never'.
         validate_default: Whether to validate default values during validation. Default is `False`.
+        validate_defaults_on_build: Whether to validate each `default` value the config applies to against its
+            schema when the `SchemaValidator` is built, raising a `SchemaError` if one is invalid. Default is `False`.
         str_max_length: The maximum length for string fields.
         str_min_length: The minimum length for string fields.
         str_strip_whitespace: Whether to strip whitespace from string fields.
@@ -96,6 +99,8 @@ class CoreConfig(TypedDict, total=False):
     revalidate_instances: Literal['always', 'never', 'subclass-instances']
     # whether to validate default values during validation, default False
     validate_default: bool
+    # whether to validate default values when building the validator, default False
+    validate_defaults_on_build: bool
     # used on typed-dicts and arguments
     # fields related to string fields only
     str_max_length: int
@@ -581,6 +586,7 @@ def none_schema(
 class BoolSchema(TypedDict, total=False):
     type: Required[Literal['bool']]
     strict: bool
+    str_coercion: bool
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -591,6 +597,7 @@ def bool_schema(
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
+    str_coercion: bool | None = None,
 ) -> BoolSchema:
     """
     Returns a schema that matches a bool value, e.g.:
@@ -608,8 +615,16 @@ def bool_schema(
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
+        str_coercion: Whether strings like `'true'` are converted to bools, regardless of `strict`
     """
-    return _dict_not_none(type='bool', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='bool',
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+        str_coercion=str_coercion,
+    )
 
 
 class IntSchema(TypedDict, total=False):
@@ -619,7 +634,11 @@ class IntSchema(TypedDict, total=False):
     ge: int
     lt: int
     gt: int
+    le_context_key: str
+    ge_context_key: str
     strict: bool
+    warn_lossy: bool
+    int_as_string_threshold: int
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -632,7 +651,11 @@ def int_schema(
     ge: int | None = None,
     lt: int | None = None,
     gt: int | None = None,
+    le_context_key: str | None = None,
+    ge_context_key: str | None = None,
     strict: bool | None = None,
+    warn_lossy: bool | None = None,
+    int_as_string_threshold: int | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -654,7 +677,13 @@ def int_schema(
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
+        le_context_key: The key in the validation context of a number the value must be less than or equal to
+        ge_context_key: The key in the validation context of a number the value must be greater than or equal to
         strict: Whether the value should be a int or a value that can be converted to a int
+        warn_lossy: Whether to emit a `UserWarning` when a float, decimal or string with a fractional part
+            (e.g. `'1.0'`) is coerced to an int, within a union only if the int's member is the one chosen
+        int_as_string_threshold: Serialize ints with a magnitude above this as strings in JSON,
+            e.g. `2**53` for consumers which decode JSON numbers as doubles
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -666,7 +695,11 @@ def int_schema(
         ge=ge,
         lt=lt,
         gt=gt,
+        le_context_key=le_context_key,
+        ge_context_key=ge_context_key,
         strict=strict,
+        warn_lossy=warn_lossy,
+        int_as_string_threshold=int_as_string_threshold,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -681,7 +714,12 @@ class FloatSchema(TypedDict, total=False):
     ge: float
     lt: float
     gt: float
+    le_context_key: str
+    ge_context_key: str
     strict: bool
+    warn_lossy: bool
+    reject_subnormal: bool
+    round_to: int
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -695,7 +733,12 @@ def float_schema(
     ge: float | None = None,
     lt: float | None = None,
     gt: float | None = None,
+    le_context_key: str | None = None,
+    ge_context_key: str | None = None,
     strict: bool | None = None,
+    warn_lossy: bool | None = None,
+    reject_subnormal: bool | None = None,
+    round_to: int | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -718,7 +761,12 @@ def float_schema(
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
+        le_context_key: The key in the validation context of a number the value must be less than or equal to
+        ge_context_key: The key in the validation context of a number the value must be greater than or equal to
         strict: Whether the value should be a float or a value that can be converted to a float
+        warn_lossy: Whether to emit a `UserWarning` when an int which can't be represented exactly is coerced to a float
+        reject_subnormal: Whether to reject subnormal (denormalized) values, e.g. `5e-324`
+        round_to: The number of decimal places to round the value to when serializing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -731,7 +779,12 @@ def float_schema(
         ge=ge,
         lt=lt,
         gt=gt,
+        le_context_key=le_context_key,
+        ge_context_key=ge_context_key,
         strict=strict,
+        warn_lossy=warn_lossy,
+        reject_subnormal=reject_subnormal,
+        round_to=round_to,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -854,15 +907,26 @@ def complex_schema(
     )
 
 
+StrFormat = Literal['uri', 'email', 'hostname', 'ipv4', 'ipv6', 'base64', 'base32', 'language_tag', 'json_pointer']
+
+
 class StringSchema(TypedDict, total=False):
     type: Required[Literal['str']]
     pattern: Union[str, Pattern[str]]
     max_length: int
     min_length: int
     strip_whitespace: bool
+    strip_leading: bool
+    strip_trailing: bool
+    collapse_whitespace: bool
+    normalize: Literal['NFC', 'NFD', 'NFKC', 'NFKD']
     to_lower: bool
     to_upper: bool
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    regex_step_limit: int
+    in_set: Union[set[str], frozenset[str], list[str]]
+    format: StrFormat
+    strict_nonempty: bool
     strict: bool
     coerce_numbers_to_str: bool
     ref: str
@@ -876,9 +940,17 @@ def str_schema(
     max_length: int | None = None,
     min_length: int | None = None,
     strip_whitespace: bool | None = None,
+    strip_leading: bool | None = None,
+    strip_trailing: bool | None = None,
+    collapse_whitespace: bool | None = None,
+    normalize: Literal['NFC', 'NFD', 'NFKC', 'NFKD'] | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
     regex_engine: Literal['rust-regex', 'python-re'] | None = None,
+    regex_step_limit: int | None = None,
+    in_set: set[str] | frozenset[str] | list[str] | None = None,
+    format: StrFormat | None = None,
+    strict_nonempty: bool | None = None,
     strict: bool | None = None,
     coerce_numbers_to_str: bool | None = None,
     ref: str | None = None,
@@ -900,7 +972,14 @@ def str_schema(
         pattern: A regex pattern that the value must match
         max_length: The value must be at most this length
         min_length: The value must be at least this length
-        strip_whitespace: Whether to strip whitespace from the value
+        strip_whitespace: Whether to strip whitespace from the value, if `True` both ends are stripped
+            whatever `strip_leading` and `strip_trailing` are set to
+        strip_leading: Whether to strip whitespace from the start of the value, applied before length checks
+        strip_trailing: Whether to strip whitespace from the end of the value, applied before length checks
+        collapse_whitespace: Whether to replace each run of whitespace in the value with a single space,
+            applied after `strip_whitespace` and before length and pattern checks
+        normalize: The Unicode normalization form to convert the value to, applied after `collapse_whitespace`
+            and before length and pattern checks
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
         regex_engine: The regex engine to use for pattern validation. Default is 'rust-regex'.
@@ -909,6 +988,17 @@ def str_schema(
               resistant, but does not support all regex features.
             - `python-re` use the [`re`](https://docs.python.org/3/library/re.html) module,
               which supports all regex features, but may be slower.
+        regex_step_limit: The maximum number of steps matching `pattern` may take, a step being one state of the
+            compiled pattern at one position of the value, values which could take more fail without being matched.
+            Only supported by `rust-regex`, which then matches with a bounded backtracker.
+        in_set: A set of allowed values, checked after `strip_whitespace`, `to_lower` and `to_upper` are applied
+        format: A format the value must conform to, one of `'uri'`, `'email'`, `'hostname'`, `'ipv4'`, `'ipv6'`,
+            or `'base64'` or `'base32'` which check the alphabet and padding without decoding,
+            the value is still returned as a plain string. `'language_tag'` checks the shape of a BCP 47 tag
+            and returns it with each subtag in its conventional case, e.g. `'en-us'` becomes `'en-US'`.
+            `'json_pointer'` checks the shape of an RFC 6901 JSON Pointer like `'/a/b'`
+        strict_nonempty: Whether to reject values which are empty after stripping whitespace, the value is only
+            returned stripped if `strip_whitespace` is set
         strict: Whether the value should be a string or a value that can be converted to a string
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
         ref: optional unique identifier of the schema, used to reference the schema in other places
@@ -921,9 +1011,17 @@ def str_schema(
         max_length=max_length,
         min_length=min_length,
         strip_whitespace=strip_whitespace,
+        strip_leading=strip_leading,
+        strip_trailing=strip_trailing,
+        collapse_whitespace=collapse_whitespace,
+        normalize=normalize,
         to_lower=to_lower,
         to_upper=to_upper,
         regex_engine=regex_engine,
+        regex_step_limit=regex_step_limit,
+        in_set=in_set,
+        format=format,
+        strict_nonempty=strict_nonempty,
         strict=strict,
         coerce_numbers_to_str=coerce_numbers_to_str,
         ref=ref,
@@ -936,6 +1034,9 @@ class BytesSchema(TypedDict, total=False):
     type: Required[Literal['bytes']]
     max_length: int
     min_length: int
+    encoded_max_length: int
+    keep_bytearray: bool
+    require_utf8: bool
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -946,6 +1047,9 @@ def bytes_schema(
     *,
     max_length: int | None = None,
     min_length: int | None = None,
+    encoded_max_length: int | None = None,
+    keep_bytearray: bool | None = None,
+    require_utf8: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -963,8 +1067,11 @@ def bytes_schema(
     ```
 
     Args:
-        max_length: The value must be at most this length
+        max_length: The value must be at most this length, after any decoding (e.g. base64) of string input
         min_length: The value must be at least this length
+        encoded_max_length: String input must be at most this many bytes before it is decoded
+        keep_bytearray: Whether to return `bytearray` input as is, rather than copying it to `bytes`
+        require_utf8: Whether the value must be valid UTF-8, the value is still returned as bytes
         strict: Whether the value should be a bytes or a value that can be converted to a bytes
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -974,6 +1081,9 @@ def bytes_schema(
         type='bytes',
         max_length=max_length,
         min_length=min_length,
+        encoded_max_length=encoded_max_length,
+        keep_bytearray=keep_bytearray,
+        require_utf8=require_utf8,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -992,6 +1102,7 @@ class DateSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400:
     now_utc_offset: int
+    epoch_offset_seconds: int
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -1006,6 +1117,7 @@ def date_schema(
     gt: date | None = None,
     now_op: Literal['past', 'future'] | None = None,
     now_utc_offset: int | None = None,
+    epoch_offset_seconds: int | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1030,6 +1142,8 @@ def date_schema(
         gt: The value must be strictly greater than this date
         now_op: The value must be in the past or future relative to the current date
         now_utc_offset: The value must be in the past or future relative to the current date with this utc offset
+        epoch_offset_seconds: Seconds added to numeric timestamps, including numeric strings, so they count from
+            a custom epoch, the shifted timestamp must be an exact date
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1043,6 +1157,7 @@ def date_schema(
         gt=gt,
         now_op=now_op,
         now_utc_offset=now_utc_offset,
+        epoch_offset_seconds=epoch_offset_seconds,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1128,6 +1243,10 @@ class DatetimeSchema(TypedDict, total=False):
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
     microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
+    rfc2822: bool
+    assume_tz: str
+    epoch_offset_seconds: int
+    datetime_mode: Literal['temporal', 'epoch']  # default: 'temporal'
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -1144,6 +1263,10 @@ def datetime_schema(
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
     now_utc_offset: int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    rfc2822: bool | None = None,
+    assume_tz: str | None = None,
+    epoch_offset_seconds: int | None = None,
+    datetime_mode: Literal['temporal', 'epoch'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1172,6 +1295,14 @@ def datetime_schema(
             TODO: use of a tzinfo where offset changes based on the datetime is not yet supported
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        rfc2822: Whether to also accept RFC 2822 strings like `'Wed, 02 Oct 2002 13:00:00 GMT'` in lax mode
+        assume_tz: The timezone given to parsed datetimes without one, `'utc'` or an offset like `'+05:30'`;
+            numeric timestamps are always UTC and `datetime` instances are left unchanged
+        epoch_offset_seconds: Seconds added to numeric timestamps, including numeric strings, so they count from
+            a custom epoch, e.g. `315964800` for the GPS epoch of 1980-01-06
+        datetime_mode: How the value is serialized to JSON, `'temporal'` follows `ser_json_temporal`,
+            `'epoch'` gives the seconds since the Unix epoch as an int, or a float if there are microseconds;
+            aware datetimes are converted to UTC first and naive datetimes are taken to be UTC
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1187,6 +1318,10 @@ def datetime_schema(
         tz_constraint=tz_constraint,
         now_utc_offset=now_utc_offset,
         microseconds_precision=microseconds_precision,
+        rfc2822=rfc2822,
+        assume_tz=assume_tz,
+        epoch_offset_seconds=epoch_offset_seconds,
+        datetime_mode=datetime_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1201,6 +1336,8 @@ class TimedeltaSchema(TypedDict, total=False):
     lt: timedelta
     gt: timedelta
     microseconds_precision: Literal['truncate', 'error']
+    timedelta_seconds_int: bool
+    duration_format: Literal['number-or-colon', 'iso8601']
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -1214,6 +1351,8 @@ def timedelta_schema(
     lt: timedelta | None = None,
     gt: timedelta | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    timedelta_seconds_int: bool | None = None,
+    duration_format: Literal['number-or-colon', 'iso8601'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1237,6 +1376,11 @@ def timedelta_schema(
         lt: The value must be strictly less than this timedelta
         gt: The value must be strictly greater than this timedelta
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        timedelta_seconds_int: When serializing to JSON as seconds, whether timedeltas with no fractional seconds
+            are serialized as an int rather than a float
+        duration_format: The string format accepted, both are accepted if unset. `'number-or-colon'` accepts
+            `'[-][DD days, ]HH:MM:SS[.ffffff]'`, `'iso8601'` accepts ISO 8601 durations like `'P1DT2H30M'`,
+            `'-P1D'`, `'PT-5M'` or `'PT0.5S'`. Numbers are accepted as seconds either way
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1249,6 +1393,8 @@ def timedelta_schema(
         lt=lt,
         gt=gt,
         microseconds_precision=microseconds_precision,
+        timedelta_seconds_int=timedelta_seconds_int,
+        duration_format=duration_format,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1258,6 +1404,8 @@ def timedelta_schema(
 class LiteralSchema(TypedDict, total=False):
     type: Required[Literal['literal']]
     expected: Required[list[Any]]
+    case_insensitive: bool
+    check_serialization: bool
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -1266,6 +1414,8 @@ class LiteralSchema(TypedDict, total=False):
 def literal_schema(
     expected: list[Any],
     *,
+    case_insensitive: bool | None = None,
+    check_serialization: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1283,11 +1433,23 @@ def literal_schema(
 
     Args:
         expected: The value must be one of these values
+        case_insensitive: Whether strings match the string values of `expected` ignoring ASCII case,
+            the value from `expected` is returned
+        check_serialization: Whether to check values are one of `expected` when serializing,
+            a value which isn't emits a serialization warning
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='literal', expected=expected, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='literal',
+        expected=expected,
+        case_insensitive=case_insensitive,
+        check_serialization=check_serialization,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class EnumSchema(TypedDict, total=False):
@@ -1296,6 +1458,7 @@ class EnumSchema(TypedDict, total=False):
     members: Required[list[Any]]
     sub_type: Literal['str', 'int', 'float']
     missing: Callable[[Any], Any]
+    value_map: dict[Any, Any]
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -1308,6 +1471,7 @@ def enum_schema(
     *,
     sub_type: Literal['str', 'int', 'float'] | None = None,
     missing: Callable[[Any], Any] | None = None,
+    value_map: dict[Any, Any] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -1335,6 +1499,8 @@ def enum_schema(
         members: The members of the enum, generally `list(MyEnum.__members__.values())`
         sub_type: The type of the enum, either 'str' or 'int' or None for plain enums
         missing: A function to use when the value is not found in the enum, from `_missing_`
+        value_map: A mapping of members to the value to serialize them as in JSON mode,
+            members not in the mapping are serialized as their `.value`
         strict: Whether to use strict mode, defaults to False
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1346,6 +1512,7 @@ def enum_schema(
         members=members,
         sub_type=sub_type,
         missing=missing,
+        value_map=value_map,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1532,9 +1699,16 @@ IncExSeqOrElseSerSchema = Union[IncExSeqSerSchema, SerSchema]
 class ListSchema(TypedDict, total=False):
     type: Required[Literal['list']]
     items_schema: CoreSchema
+    prefix_items: list[CoreSchema]
     min_length: int
     max_length: int
     fail_fast: bool
+    tuple_coercion: bool
+    pad_to: int
+    pad_value: Any
+    truncate_to: int
+    collection: Literal['list', 'deque']
+    maxlen: int
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -1544,9 +1718,16 @@ class ListSchema(TypedDict, total=False):
 def list_schema(
     items_schema: CoreSchema | None = None,
     *,
+    prefix_items: list[CoreSchema] | None = None,
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    tuple_coercion: bool | None = None,
+    pad_to: int | None = None,
+    pad_value: Any = None,
+    truncate_to: int | None = None,
+    collection: Literal['list', 'deque'] | None = None,
+    maxlen: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -1565,9 +1746,22 @@ def list_schema(
 
     Args:
         items_schema: The value must be a list of items that match this schema
+        prefix_items: Schemas for the first items of the list by position, `items_schema` applies to any items
+            after them, the value is still returned as a list
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
         fail_fast: Stop validation on the first error
+        tuple_coercion: Whether tuples are accepted and converted to lists in lax mode, defaults to `True`
+        pad_to: Pad the validated list to this many items with `pad_value`, after `min_length` and `max_length`
+            are checked, it can't be greater than `max_length` or used with `prefix_items`
+        pad_value: The value to pad the list with, defaults to `None`, it's validated against `items_schema` once
+            when the schema is built and the same validated object is used for every item added
+        truncate_to: Drop any items beyond this many from the validated list, after `min_length` and `max_length`
+            are checked
+        collection: The type of the validated value, `'deque'` returns a `collections.deque`, defaults to `'list'`,
+            with `deep_freeze` a deque's items are frozen but it's kept as a deque
+        maxlen: The `maxlen` of the returned deque, only valid with `collection='deque'`, like `collections.deque`
+            items beyond it are dropped from the left after `min_length` and `max_length` are checked
         strict: The value must be a list with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1576,9 +1770,16 @@ def list_schema(
     return _dict_not_none(
         type='list',
         items_schema=items_schema,
+        prefix_items=prefix_items,
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        tuple_coercion=tuple_coercion,
+        pad_to=pad_to,
+        pad_value=pad_value,
+        truncate_to=truncate_to,
+        collection=collection,
+        maxlen=maxlen,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1687,6 +1888,8 @@ class TupleSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    sequence_coercion: Literal['lax', 'always', 'never']  # default: 'lax'
+    namedtuple_cls: type[tuple[Any, ...]]
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -1700,6 +1903,8 @@ def tuple_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    sequence_coercion: Literal['lax', 'always', 'never'] | None = None,
+    namedtuple_cls: type[tuple[Any, ...]] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -1725,6 +1930,14 @@ def tuple_schema(
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
         fail_fast: Stop validation on the first error
+        sequence_coercion: When other sequences (e.g. lists) are accepted in place of a tuple, default `'lax'`.
+            - `lax` accepts them only in lax mode
+            - `always` accepts them in strict mode too
+            - `never` only accepts tuples
+            Strings, bytes and mappings are never accepted
+        namedtuple_cls: A named tuple class (e.g. a `typing.NamedTuple` subclass) to build the output with via
+            `namedtuple_cls._make(items)`, error locations use the class's `_fields` in place of indices, it must have
+            a field per item of `items_schema` and can't be used with `variadic_item_index`
         strict: The value must be a tuple with exactly this many items
         ref: Optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1737,6 +1950,8 @@ def tuple_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        sequence_coercion=sequence_coercion,
+        namedtuple_cls=namedtuple_cls,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1809,6 +2024,7 @@ class FrozenSetSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    reject_mutable_input: bool
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -1821,6 +2037,7 @@ def frozenset_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    reject_mutable_input: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -1844,6 +2061,7 @@ def frozenset_schema(
         min_length: The value must be a frozenset with at least this many items
         max_length: The value must be a frozenset with at most this many items
         fail_fast: Stop validation on the first error
+        reject_mutable_input: Whether to reject `set` instances as input, even in lax mode. Default is `False`
         strict: The value must be a frozenset with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1855,6 +2073,7 @@ def frozenset_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        reject_mutable_input=reject_mutable_input,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1943,6 +2162,10 @@ class DictSchema(TypedDict, total=False):
     max_length: int
     fail_fast: bool
     strict: bool
+    strict_keys: bool
+    enum_keys_by_name: bool
+    key_mode: Literal['str', 'error']  # default: 'str'
+    nan_key_mode: Literal['allow', 'forbid']  # default: 'allow'
     ref: str
     metadata: dict[str, Any]
     serialization: IncExDictOrElseSerSchema
@@ -1956,6 +2179,10 @@ def dict_schema(
     max_length: int | None = None,
     fail_fast: bool | None = None,
     strict: bool | None = None,
+    strict_keys: bool | None = None,
+    enum_keys_by_name: bool | None = None,
+    key_mode: Literal['str', 'error'] | None = None,
+    nan_key_mode: Literal['allow', 'forbid'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1980,6 +2207,15 @@ def dict_schema(
         max_length: The value must be a dict with at most this many items
         fail_fast: Stop validation on the first error
         strict: Whether the keys and values should be validated with strict mode
+        strict_keys: Whether the keys should always be validated with strict mode, e.g. so JSON object keys
+            aren't coerced to ints
+        enum_keys_by_name: Whether keys which aren't valid enum values can be an enum member's name,
+            `keys_schema` must be an enum schema
+        key_mode: How keys which aren't strings are rendered when serializing to JSON, `'str'` (the default)
+            stringifies them, `'error'` raises an error
+        nan_key_mode: How validated keys which are float `NaN` are handled, `'allow'` (the default) passes them
+            through, `'forbid'` raises a `finite_number` error. `NaN` is never equal to itself, so `NaN` keys
+            are never deduplicated
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1992,6 +2228,10 @@ def dict_schema(
         max_length=max_length,
         fail_fast=fail_fast,
         strict=strict,
+        strict_keys=strict_keys,
+        enum_keys_by_name=enum_keys_by_name,
+        key_mode=key_mode,
+        nan_key_mode=nan_key_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2602,6 +2842,7 @@ class UnionSchema(TypedDict, total=False):
     custom_error_message: str
     custom_error_context: dict[str, Union[str, int, float]]
     mode: Literal['smart', 'left_to_right']  # default: 'smart'
+    union_serialization: Literal['smart', 'left_to_right']  # default: 'smart'
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -2616,6 +2857,7 @@ def union_schema(
     custom_error_message: str | None = None,
     custom_error_context: dict[str, str | int] | None = None,
     mode: Literal['smart', 'left_to_right'] | None = None,
+    union_serialization: Literal['smart', 'left_to_right'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -2641,6 +2883,9 @@ def union_schema(
         mode: How to select which choice to return
             * `smart` (default) will try to return the choice which is the closest match to the input value
             * `left_to_right` will return the first choice in `choices` which succeeds validation
+        union_serialization: How to select which choice's serializer to use when serializing
+            * `smart` (default) will prefer the choice which exactly matches the type of the value
+            * `left_to_right` will use the first choice in `choices` which accepts the value, including subclasses
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -2653,6 +2898,7 @@ def union_schema(
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
         mode=mode,
+        union_serialization=union_serialization,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2978,9 +3224,12 @@ class TypedDictSchema(TypedDict, total=False):
     computed_fields: list[ComputedField]
     strict: bool
     extras_schema: CoreSchema
+    allowed_extra_keys: list[str]
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     total: bool  # default: True
+    coerce_keys: bool
+    case_insensitive_keys: bool
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -2995,8 +3244,11 @@ def typed_dict_schema(
     computed_fields: list[ComputedField] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
+    allowed_extra_keys: list[str] | None = None,
     extra_behavior: ExtraBehavior | None = None,
     total: bool | None = None,
+    coerce_keys: bool | None = None,
+    case_insensitive_keys: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -3028,10 +3280,14 @@ def typed_dict_schema(
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
         strict: Whether the typed dict is strict
         extras_schema: The extra validator to use for the typed dict
+        allowed_extra_keys: Extra keys which are kept rather than rejected when `extra_behavior='forbid'`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
         total: Whether the typed dict is total, otherwise uses `typed_dict_total` from config
+        coerce_keys: Whether to convert integer keys of the input to strings in lax mode, e.g. `1` to `'1'`
+        case_insensitive_keys: Whether keys of the input match field names and validation aliases regardless of case,
+            e.g. `'Name'` to `'name'`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -3042,8 +3298,11 @@ def typed_dict_schema(
         computed_fields=computed_fields,
         strict=strict,
         extras_schema=extras_schema,
+        allowed_extra_keys=allowed_extra_keys,
         extra_behavior=extra_behavior,
         total=total,
+        coerce_keys=coerce_keys,
+        case_insensitive_keys=case_insensitive_keys,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -3058,6 +3317,7 @@ class ModelField(TypedDict, total=False):
     serialization_alias: str
     serialization_exclude: bool  # default: False
     serialization_exclude_if: Callable[[Any], bool]  # default: None
+    serialization_flatten_prefix: str
     frozen: bool
     metadata: dict[str, Any]
 
@@ -3069,6 +3329,7 @@ def model_field(
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
     serialization_exclude_if: Callable[[Any], bool] | None = None,
+    serialization_flatten_prefix: str | None = None,
     frozen: bool | None = None,
     metadata: dict[str, Any] | None = None,
 ) -> ModelField:
@@ -3087,6 +3348,9 @@ def model_field(
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
         serialization_exclude_if: A Callable that determines whether to exclude a field during serialization based on its value.
+        serialization_flatten_prefix: When serializing, emit the items of the field's serialized value (e.g. a nested
+            model's fields) in the parent with this prefix on their keys instead of under the field's own key, the
+            keys are serialized by alias like the parent's and a key colliding with another of the parent's is an error
         frozen: Whether the field is frozen
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
@@ -3097,6 +3361,7 @@ def model_field(
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
         serialization_exclude_if=serialization_exclude_if,
+        serialization_flatten_prefix=serialization_flatten_prefix,
         frozen=frozen,
         metadata=metadata,
     )
@@ -3110,6 +3375,7 @@ class ModelFieldsSchema(TypedDict, total=False):
     strict: bool
     extras_schema: CoreSchema
     extras_keys_schema: CoreSchema
+    allowed_extra_keys: list[str]
     extra_behavior: ExtraBehavior
     from_attributes: bool
     ref: str
@@ -3125,6 +3391,7 @@ def model_fields_schema(
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
     extras_keys_schema: CoreSchema | None = None,
+    allowed_extra_keys: list[str] | None = None,
     extra_behavior: ExtraBehavior | None = None,
     from_attributes: bool | None = None,
     ref: str | None = None,
@@ -3152,6 +3419,8 @@ def model_fields_schema(
         strict: Whether the model is strict
         extras_schema: The schema to use when validating extra input data
         extras_keys_schema: The schema to use when validating the keys of extra input data
+        allowed_extra_keys: Extra keys which are stored on `__pydantic_extra__` rather than rejected when
+            `extra_behavior='forbid'`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the model fields
@@ -3166,6 +3435,7 @@ def model_fields_schema(
         strict=strict,
         extras_schema=extras_schema,
         extras_keys_schema=extras_keys_schema,
+        allowed_extra_keys=allowed_extra_keys,
         extra_behavior=extra_behavior,
         from_attributes=from_attributes,
         ref=ref,
@@ -3174,6 +3444,39 @@ def model_fields_schema(
     )
 
 
+class ModelPrivateAttribute(TypedDict, total=False):
+    default: Any
+    default_factory: Union[Callable[[], Any], Callable[[dict[str, Any]], Any]]
+    default_factory_takes_data: bool
+
+
+def model_private_attribute(
+    *,
+    default: Any = PydanticUndefined,
+    default_factory: Union[Callable[[], Any], Callable[[dict[str, Any]], Any], None] = None,
+    default_factory_takes_data: bool | None = None,
+) -> ModelPrivateAttribute:
+    """
+    Returns a private attribute for a model, its default is set in `__pydantic_private__` once
+    the model's fields have been validated, e.g.:
+
+    ```py
+    from pydantic_core import core_schema
+
+    private_attribute = core_schema.model_private_attribute(default_factory=list)
+    ```
+
+    Args:
+        default: The default value of the private attribute
+        default_factory: A callable that returns the default value of the private attribute
+        default_factory_takes_data: Whether the default factory takes the validated field data as an argument
+    """
+    attr = _dict_not_none(default_factory=default_factory, default_factory_takes_data=default_factory_takes_data)
+    if default is not PydanticUndefined:
+        attr['default'] = default
+    return attr
+
+
 class ModelSchema(TypedDict, total=False):
     type: Required[Literal['model']]
     cls: Required[type[Any]]
@@ -3182,6 +3485,12 @@ class ModelSchema(TypedDict, total=False):
     custom_init: bool
     root_model: bool
     post_init: str
+    private_attributes: dict[str, ModelPrivateAttribute]
+    from_tuple: bool
+    required_one_of: list[list[str]]
+    lazy_fields: list[str]
+    extra_field_name: str
+    require_all: bool
     revalidate_instances: Literal['always', 'never', 'subclass-instances']  # default: 'never'
     strict: bool
     frozen: bool
@@ -3200,6 +3509,12 @@ def model_schema(
     custom_init: bool | None = None,
     root_model: bool | None = None,
     post_init: str | None = None,
+    private_attributes: dict[str, ModelPrivateAttribute] | None = None,
+    from_tuple: bool | None = None,
+    required_one_of: list[list[str]] | None = None,
+    lazy_fields: list[str] | None = None,
+    extra_field_name: str | None = None,
+    require_all: bool | None = None,
     revalidate_instances: Literal['always', 'never', 'subclass-instances'] | None = None,
     strict: bool | None = None,
     frozen: bool | None = None,
@@ -3248,6 +3563,15 @@ def model_schema(
         custom_init: Whether the model has a custom init method
         root_model: Whether the model is a `RootModel`
         post_init: The call after init to use for the model
+        private_attributes: Private attributes to initialise in `__pydantic_private__` from their defaults
+        from_tuple: Whether to accept a tuple or list of field values in the order the fields are declared
+        required_one_of: Groups of field names where exactly one field of each group must be provided
+        lazy_fields: (Experimental) Fields whose given values are stored in `__pydantic_lazy__` rather than `__dict__`,
+            each is validated when the model resolves it by calling `validate_assignment` with the pending value,
+            typically from `__getattr__`
+        extra_field_name: The attribute extra fields are stored under, defaults to `'__pydantic_extra__'`
+        require_all: Whether to ignore field defaults, so a missing field is an error even if it has a default,
+            `on_error='default'` still applies to fields which are present
         revalidate_instances: whether instances of models and dataclasses (including subclass instances)
             should re-validate defaults to config.revalidate_instances, else 'never'
         strict: Whether the model is strict
@@ -3266,6 +3590,12 @@ def model_schema(
         custom_init=custom_init,
         root_model=root_model,
         post_init=post_init,
+        private_attributes=private_attributes,
+        from_tuple=from_tuple,
+        required_one_of=required_one_of,
+        lazy_fields=lazy_fields,
+        extra_field_name=extra_field_name,
+        require_all=require_all,
         revalidate_instances=revalidate_instances,
         strict=strict,
         frozen=frozen,
@@ -4227,15 +4557,20 @@ ErrorType = Literal[
     'json_invalid',
     'json_type',
     'needs_python_object',
+    'json_duplicate_key',
     'recursion_loop',
     'missing',
     'frozen_field',
     'frozen_instance',
     'extra_forbidden',
+    'ambiguous_key',
+    'key_collision',
     'invalid_key',
     'get_attribute_error',
     'model_type',
     'model_attributes_type',
+    'model_tuple_length',
+    'exactly_one_required',
     'dataclass_type',
     'dataclass_exact_type',
     'default_factory_not_called',
@@ -4246,6 +4581,7 @@ ErrorType = Literal[
     'less_than_equal',
     'multiple_of',
     'finite_number',
+    'float_subnormal',
     'too_short',
     'too_long',
     'iterable_type',
@@ -4256,6 +4592,9 @@ ErrorType = Literal[
     'string_too_short',
     'string_too_long',
     'string_pattern_mismatch',
+    'string_not_in_set',
+    'string_format',
+    'regex_timeout',
     'enum',
     'dict_type',
     'mapping_type',
@@ -4274,7 +4613,9 @@ ErrorType = Literal[
     'bytes_type',
     'bytes_too_short',
     'bytes_too_long',
+    'bytes_encoded_too_long',
     'bytes_invalid_encoding',
+    'bytes_invalid_utf8',
     'value_error',
     'assertion_error',
     'literal_error',
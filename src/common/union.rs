@@ -1,9 +1,31 @@
+use std::str::FromStr;
+
 use pyo3::prelude::*;
 use pyo3::{PyTraverseError, PyVisit};
 
+use crate::build_tools::py_schema_err;
 use crate::lookup_key::LookupKey;
 use crate::py_gc::PyGcTraverse;
 
+/// How to pick a union choice, used by both validation and serialization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnionMode {
+    Smart,
+    LeftToRight,
+}
+
+impl FromStr for UnionMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "smart" => Ok(Self::Smart),
+            "left_to_right" => Ok(Self::LeftToRight),
+            s => py_schema_err!("Invalid union mode: `{}`, expected `smart` or `left_to_right`", s),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum Discriminator {
     /// use `LookupKey` to find the tag, same as we do to find values in typed_dict aliases
@@ -132,15 +132,28 @@ impl<T: PyGcTraverse> PyGcTraverse for Definitions<T> {
 #[derive(Debug)]
 pub struct DefinitionsBuilder<T> {
     definitions: Definitions<T>,
+    // values which can only be checked once every definition has been filled
+    deferred: Vec<T>,
 }
 
 impl<T: std::fmt::Debug> DefinitionsBuilder<T> {
     pub fn new() -> Self {
         Self {
             definitions: Definitions(AHashMap::new()),
+            deferred: Vec::new(),
         }
     }
 
+    /// Record a value to be checked after building, once all definitions have been filled
+    pub fn defer(&mut self, value: T) {
+        self.deferred.push(value);
+    }
+
+    /// Take the values recorded by `defer`
+    pub fn take_deferred(&mut self) -> Vec<T> {
+        std::mem::take(&mut self.deferred)
+    }
+
     /// Get a ReferenceId for the given reference string.
     pub fn get_definition(&mut self, reference: &str) -> DefinitionRef<T> {
         // We either need a String copy or two hashmap lookups
@@ -7,6 +7,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyList, PyTuple};
 use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
+use smallvec::SmallVec;
 
 /// Used to store individual items of the error location, e.g. a string for key/field names
 /// or a number for array indices.
@@ -81,6 +82,12 @@ impl Serialize for LocItem {
     }
 }
 
+/// Most locations are only a few items deep, up to this many items are stored inline without a heap allocation
+const INLINE_LOC_ITEMS: usize = 3;
+
+/// The items of a location, in reverse order, see `Location` below
+pub type LocItems = SmallVec<[LocItem; INLINE_LOC_ITEMS]>;
+
 /// Error locations are represented by a vector of `LocItem`s.
 /// e.g. if the error occurred in the third member of a list called `foo`,
 /// the location would be `["foo", 2]`.
@@ -94,8 +101,8 @@ pub enum Location {
     #[default]
     Empty,
     // store the in a vec of LocItems, Note: this is the REVERSE of location, see above
-    // we could perhaps use a smallvec or similar here, probably only worth it if we store a Cow in LocItem
-    List(Vec<LocItem>),
+    // shallow locations are stored inline, deeper ones spill to the heap
+    List(LocItems),
 }
 
 static EMPTY_TUPLE: PyOnceLock<Py<PyTuple>> = PyOnceLock::new();
@@ -129,9 +136,9 @@ impl fmt::Display for Location {
 }
 
 impl Location {
-    /// create a new location vec with a value, 3 is plucked out of thin air, should it just be 1?
+    /// create a new location with a single item, stored inline
     pub fn new_some(item: LocItem) -> Self {
-        let mut loc = Vec::with_capacity(3);
+        let mut loc = LocItems::new();
         loc.push(item);
         Self::List(loc)
     }
@@ -171,7 +178,7 @@ impl TryFrom<Option<&Bound<'_, PyAny>>> for Location {
     /// Thus this expects the location to *not* be reversed and reverses it before storing it.
     fn try_from(location: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
         if let Some(location) = location {
-            let mut loc_vec: Vec<LocItem> = if let Ok(tuple) = location.downcast::<PyTuple>() {
+            let mut loc_vec: LocItems = if let Ok(tuple) = location.downcast::<PyTuple>() {
                 tuple.iter().map(Into::into).collect()
             } else if let Ok(list) = location.downcast::<PyList>() {
                 list.iter().map(Into::into).collect()
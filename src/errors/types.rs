@@ -42,6 +42,14 @@ pub fn list_all_errors(py: Python<'_>) -> PyResult<Bound<'_, PyList>> {
     PyList::new(py, errors)
 }
 
+/// Context for a `greater_than`, `greater_than_equal`, `less_than` or `less_than_equal` error raised by
+/// a validator, holding the value which failed the comparison under `input` alongside the bound.
+pub fn comparison_context<'py>(py: Python<'py>, input: impl IntoPyObject<'py>) -> PyResult<Bound<'py, PyDict>> {
+    let context = PyDict::new(py);
+    context.set_item("input", input)?;
+    Ok(context)
+}
+
 fn field_from_context<'py, T: FromPyObject<'py>>(
     context: Option<&Bound<'py, PyDict>>,
     field_name: &str,
@@ -168,6 +176,9 @@ error_types! {
     },
     JsonType {},
     NeedsPythonObject { method_name: {ctx_type: String, ctx_fn: field_from_context} },
+    JsonDuplicateKey {
+        key: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // recursion error
     RecursionLoop {},
@@ -177,6 +188,8 @@ error_types! {
     FrozenField {},
     FrozenInstance {},
     ExtraForbidden {},
+    AmbiguousKey {},
+    KeyCollision {},
     InvalidKey {},
     GetAttributeError {
         error: {ctx_type: String, ctx_fn: field_from_context},
@@ -187,6 +200,14 @@ error_types! {
         class_name: {ctx_type: String, ctx_fn: field_from_context},
     },
     ModelAttributesType {},
+    ModelTupleLength {
+        expected_length: {ctx_type: usize, ctx_fn: field_from_context},
+        actual_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    ExactlyOneRequired {
+        fields: {ctx_type: String, ctx_fn: field_from_context},
+        actual_count: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // dataclass errors (we don't talk about ArgsKwargs here for simplicity)
     DataclassType {
@@ -219,6 +240,7 @@ error_types! {
         multiple_of: {ctx_type: Number, ctx_fn: field_from_context},
     },
     FiniteNumber {},
+    FloatSubnormal {},
     // ---------------------
     // generic length errors - used for everything with a length except strings and bytes which need custom messages
     TooShort {
@@ -251,6 +273,14 @@ error_types! {
     StringPatternMismatch {
         pattern: {ctx_type: String, ctx_fn: field_from_context},
     },
+    StringNotInSet {},
+    StringFormat {
+        format: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    RegexTimeout {
+        step_limit: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // enum errors
     Enum {
@@ -295,10 +325,16 @@ error_types! {
     BytesTooLong {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    BytesEncodedTooLong {
+        max_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     BytesInvalidEncoding {
         encoding: {ctx_type: String, ctx_fn: field_from_context},
         encoding_error: {ctx_type: String, ctx_fn: field_from_context},
     },
+    BytesInvalidUtf8 {
+        utf8_error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // python errors from functions
     ValueError {
@@ -420,6 +456,7 @@ error_types! {
     },
     UuidVersion {
         expected_version: {ctx_type: usize, ctx_fn: field_from_context},
+        observed_version: {ctx_type: Option<usize>, ctx_fn: field_from_context},
     },
     // Decimal errors
     DecimalType {},
@@ -486,15 +523,20 @@ impl ErrorType {
             Self::JsonInvalid {..} => "Invalid JSON: {error}",
             Self::JsonType {..} => "JSON input should be string, bytes or bytearray",
             Self::NeedsPythonObject {..} => "Cannot check `{method_name}` when validating from json, use a JsonOrPython validator instead",
+            Self::JsonDuplicateKey {..} => "Duplicate key '{key}' in JSON object",
             Self::RecursionLoop {..} => "Recursion error - cyclic reference detected",
             Self::Missing {..} => "Field required",
             Self::FrozenField {..} => "Field is frozen",
             Self::FrozenInstance {..} => "Instance is frozen",
             Self::ExtraForbidden {..} => "Extra inputs are not permitted",
+            Self::AmbiguousKey {..} => "Multiple keys match this field when ignoring case",
+            Self::KeyCollision {..} => "Multiple keys are the same once coerced to a string",
             Self::InvalidKey {..} => "Keys should be strings",
             Self::GetAttributeError {..} => "Error extracting attribute: {error}",
             Self::ModelType {..} => "Input should be a valid dictionary or instance of {class_name}",
             Self::ModelAttributesType {..} => "Input should be a valid dictionary or object to extract fields from",
+            Self::ModelTupleLength {..} => "Input should have {expected_length} item{expected_plural} to match the model fields, not {actual_length}",
+            Self::ExactlyOneRequired {..} => "Exactly one of {fields} should be provided, not {actual_count}",
             Self::DataclassType {..} => "Input should be a dictionary or an instance of {class_name}",
             Self::DataclassExactType {..} => "Input should be an instance of {class_name}",
             Self::DefaultFactoryNotCalled {..} => "The default factory uses validated data, but at least one validation error occurred",
@@ -505,6 +547,7 @@ impl ErrorType {
             Self::LessThanEqual {..} => "Input should be less than or equal to {le}",
             Self::MultipleOf {..} => "Input should be a multiple of {multiple_of}",
             Self::FiniteNumber {..} => "Input should be a finite number",
+            Self::FloatSubnormal {..} => "Input should not be a subnormal float",
             Self::TooShort {..} => "{field_type} should have at least {min_length} item{expected_plural} after validation, not {actual_length}",
             Self::TooLong {..} => "{field_type} should have at most {max_length} item{expected_plural} after validation, not {actual_length}",
             Self::IterableType {..} => "Input should be iterable",
@@ -515,6 +558,9 @@ impl ErrorType {
             Self::StringTooShort {..} => "String should have at least {min_length} character{expected_plural}",
             Self::StringTooLong {..} => "String should have at most {max_length} character{expected_plural}",
             Self::StringPatternMismatch {..} => "String should match pattern '{pattern}'",
+            Self::StringNotInSet {..} => "String should be one of the allowed values",
+            Self::StringFormat {..} => "String should be a valid {format}, {error}",
+            Self::RegexTimeout {..} => "Matching the pattern would exceed the limit of {step_limit} steps",
             Self::Enum {..} => "Input should be {expected}",
             Self::DictType {..} => "Input should be a valid dictionary",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
@@ -533,7 +579,9 @@ impl ErrorType {
             Self::BytesType {..} => "Input should be a valid bytes",
             Self::BytesTooShort {..} => "Data should have at least {min_length} byte{expected_plural}",
             Self::BytesTooLong {..} => "Data should have at most {max_length} byte{expected_plural}",
+            Self::BytesEncodedTooLong {..} => "Encoded data should have at most {max_length} byte{expected_plural}",
             Self::BytesInvalidEncoding { .. } => "Data should be valid {encoding}: {encoding_error}",
+            Self::BytesInvalidUtf8 { .. } => "Data should be valid UTF-8: {utf8_error}",
             Self::ValueError {..} => "Value error, {error}",
             Self::AssertionError {..} => "Assertion failed, {error}",
             Self::CustomError {..} => "",  // custom errors are handled separately
@@ -637,6 +685,7 @@ impl ErrorType {
         };
         match self {
             Self::NoSuchAttribute { attribute, .. } => render!(tmpl, attribute),
+            Self::JsonDuplicateKey { key, .. } => render!(tmpl, key),
             Self::JsonInvalid { error, .. }
             | Self::GetAttributeError { error, .. }
             | Self::IterationError { error, .. }
@@ -655,6 +704,18 @@ impl ErrorType {
             Self::ModelType { class_name, .. }
             | Self::DataclassType { class_name, .. }
             | Self::DataclassExactType { class_name, .. } => render!(tmpl, class_name),
+            Self::RegexTimeout { step_limit, .. } => to_string_render!(tmpl, step_limit),
+            Self::ExactlyOneRequired {
+                fields, actual_count, ..
+            } => to_string_render!(tmpl, fields, actual_count),
+            Self::ModelTupleLength {
+                expected_length,
+                actual_length,
+                ..
+            } => {
+                let expected_plural = plural_s(*expected_length);
+                to_string_render!(tmpl, expected_length, actual_length, expected_plural,)
+            }
             Self::GreaterThan { gt, .. } => to_string_render!(tmpl, gt),
             Self::GreaterThanEqual { ge, .. } => to_string_render!(tmpl, ge),
             Self::LessThan { lt, .. } => to_string_render!(tmpl, lt),
@@ -685,17 +746,20 @@ impl ErrorType {
             }
             Self::StringTooLong { max_length, .. }
             | Self::BytesTooLong { max_length, .. }
+            | Self::BytesEncodedTooLong { max_length, .. }
             | Self::UrlTooLong { max_length, .. } => {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
             Self::StringPatternMismatch { pattern, .. } => render!(tmpl, pattern),
+            Self::StringFormat { format, error, .. } => render!(tmpl, format, error),
             Self::Enum { expected, .. } => to_string_render!(tmpl, expected),
             Self::BytesInvalidEncoding {
                 encoding,
                 encoding_error,
                 ..
             } => render!(tmpl, encoding, encoding_error),
+            Self::BytesInvalidUtf8 { utf8_error, .. } => render!(tmpl, utf8_error),
             Self::ValueError { error, .. } => {
                 let error = &error
                     .as_ref()
@@ -329,6 +329,35 @@ impl ValidationError {
         }
     }
 
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true))]
+    pub fn errors_tree<'py>(
+        &self,
+        py: Python<'py>,
+        include_url: bool,
+        include_context: bool,
+        include_input: bool,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let url_prefix = get_url_prefix(py, include_url);
+        let tree = PyDict::new(py);
+        for line_error in &self.line_errors {
+            let error = line_error.as_dict(py, url_prefix, include_context, self.input_type, include_input)?;
+            let loc: Vec<Bound<'_, PyAny>> = error
+                .get_as_req::<Bound<'_, PyTuple>>(intern!(py, "loc"))?
+                .iter()
+                .collect();
+            let mut node = tree.clone();
+            let Some((last, parents)) = loc.split_last() else {
+                push_tree_error(&node, intern!(py, ERRORS_TREE_ROOT).as_any(), error)?;
+                continue;
+            };
+            for key in parents {
+                node = tree_child_node(&node, key)?;
+            }
+            push_tree_error(&node, last, error)?;
+        }
+        Ok(tree)
+    }
+
     #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true))]
     pub fn json<'py>(
         &self,
@@ -406,6 +435,39 @@ impl ValidationError {
     }
 }
 
+/// Key used in `errors_tree` for errors at a location which also has errors nested below it
+const ERRORS_TREE_ROOT: &str = "__root__";
+
+/// Get the dict for `key` in `node`, moving any errors already stored at `key` under `ERRORS_TREE_ROOT`
+fn tree_child_node<'py>(node: &Bound<'py, PyDict>, key: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    let py = node.py();
+    let child = match node.get_item(key)? {
+        Some(existing) => match existing.downcast_into::<PyDict>() {
+            Ok(child) => return Ok(child),
+            Err(errors) => {
+                let child = PyDict::new(py);
+                child.set_item(intern!(py, ERRORS_TREE_ROOT), errors.into_inner())?;
+                child
+            }
+        },
+        None => PyDict::new(py),
+    };
+    node.set_item(key, &child)?;
+    Ok(child)
+}
+
+/// Append `error` to the list of errors at `key` in `node`
+fn push_tree_error<'py>(node: &Bound<'py, PyDict>, key: &Bound<'py, PyAny>, error: Bound<'py, PyDict>) -> PyResult<()> {
+    let py = node.py();
+    match node.get_item(key)? {
+        Some(existing) => match existing.downcast_into::<PyList>() {
+            Ok(errors) => errors.append(error),
+            Err(child) => push_tree_error(child.into_inner().downcast()?, intern!(py, ERRORS_TREE_ROOT), error),
+        },
+        None => node.set_item(key, PyList::new(py, [error])?),
+    }
+}
+
 pub fn pretty_py_line_errors<'a>(
     py: Python,
     input_type: InputType,
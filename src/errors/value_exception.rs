@@ -1,12 +1,13 @@
 use pyo3::exceptions::{PyException, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyString, PyTuple};
 
 use crate::input::InputType;
 use crate::tools::extract_i64;
 
 use super::line_error::ToErrorValue;
-use super::{ErrorType, ValError};
+use super::location::Location;
+use super::{ErrorType, ValError, ValLineError};
 
 #[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
@@ -60,18 +61,33 @@ pub struct PydanticCustomError {
     error_type: String,
     message_template: String,
     context: Option<Py<PyDict>>,
+    // location appended to the location the error is raised at
+    loc: Option<Py<PyTuple>>,
 }
 
 #[pymethods]
 impl PydanticCustomError {
     #[new]
-    #[pyo3(signature = (error_type, message_template, context = None, /))]
-    pub fn py_new(error_type: String, message_template: String, context: Option<Bound<'_, PyDict>>) -> Self {
-        Self {
+    #[pyo3(signature = (error_type, message_template, context = None, loc = None, /))]
+    pub fn py_new(
+        error_type: String,
+        message_template: String,
+        context: Option<Bound<'_, PyDict>>,
+        loc: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Self> {
+        let loc = loc
+            .map(|loc| {
+                // check the location is valid now, rather than when the error is raised
+                Location::try_from(Some(&loc))?;
+                Ok::<_, PyErr>(PyTuple::new(loc.py(), loc.try_iter()?.collect::<PyResult<Vec<_>>>()?)?.unbind())
+            })
+            .transpose()?;
+        Ok(Self {
             error_type,
             message_template,
             context: context.map(Bound::unbind),
-        }
+            loc,
+        })
     }
 
     #[getter(r#type)]
@@ -89,6 +105,11 @@ impl PydanticCustomError {
         self.context.as_ref().map(|c| c.clone_ref(py))
     }
 
+    #[getter]
+    pub fn loc(&self, py: Python) -> Option<Py<PyTuple>> {
+        self.loc.as_ref().map(|l| l.clone_ref(py))
+    }
+
     pub fn message(&self, py: Python) -> PyResult<String> {
         Self::format_message(&self.message_template, self.context.as_ref().map(|c| c.bind(py)))
     }
@@ -113,7 +134,15 @@ impl PydanticCustomError {
             message_template: self.message_template,
             context: self.context,
         };
-        ValError::new(error_type, input)
+        match self.loc {
+            Some(loc) => match Python::attach(|py| Location::try_from(Some(loc.bind(py).as_any()))) {
+                Ok(location) => {
+                    ValError::LineErrors(vec![ValLineError::new_with_full_loc(error_type, input, location)])
+                }
+                Err(err) => ValError::InternalErr(err),
+            },
+            None => ValError::new(error_type, input),
+        }
     }
 
     pub fn format_message(message_template: &str, context: Option<&Bound<'_, PyDict>>) -> PyResult<String> {
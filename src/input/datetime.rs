@@ -659,6 +659,111 @@ pub fn float_as_duration(input: impl ToErrorValue, total_seconds: f64) -> ValRes
         .map_err(|err| map_timedelta_err(input, err))
 }
 
+const MICROSECONDS_PER_SECOND: u128 = 1_000_000;
+const MICROSECONDS_PER_DAY: u128 = 86_400 * MICROSECONDS_PER_SECOND;
+
+/// Parse an ISO 8601 duration like `P1DT2H30M`, `-P1D` or `PT0.5S`, for `duration_format='iso8601'`.
+/// Unlike speedate each component may have its own sign, e.g. `PT-5M`, years and months are 365 and 30 days.
+pub fn iso8601_as_duration<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    value: &str,
+    microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+) -> ValResult<Duration> {
+    let duration_err = |error: String| {
+        ValError::new(
+            ErrorType::TimeDeltaParsing {
+                error: Cow::Owned(error),
+                context: None,
+            },
+            input,
+        )
+    };
+
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let Some(mut rest) = unsigned.strip_prefix(['P', 'p']) else {
+        return Err(duration_err(format!(
+            "ISO 8601 duration should start with 'P', got '{value}'"
+        )));
+    };
+    if rest.is_empty() {
+        return Err(duration_err(format!("ISO 8601 duration '{value}' has no components")));
+    }
+
+    let mut in_time = false;
+    let mut total: i128 = 0;
+    while !rest.is_empty() {
+        if !in_time && rest.starts_with(['T', 't']) {
+            in_time = true;
+            rest = &rest[1..];
+            if rest.is_empty() {
+                return Err(duration_err(format!(
+                    "ISO 8601 duration '{value}' has no components after 'T'"
+                )));
+            }
+            continue;
+        }
+        let component_len = rest
+            .find(|c: char| c.is_ascii_alphabetic())
+            .map_or(rest.len(), |unit_index| unit_index + 1);
+        let component = &rest[..component_len];
+        let invalid_component = || duration_err(format!("invalid component '{component}' in ISO 8601 duration"));
+        let (number, unit) = component.split_at(component_len - 1);
+        let unit_microseconds = match (in_time, unit) {
+            (false, "Y" | "y") => 365 * MICROSECONDS_PER_DAY,
+            (false, "M" | "m") => 30 * MICROSECONDS_PER_DAY,
+            (false, "W" | "w") => 7 * MICROSECONDS_PER_DAY,
+            (false, "D" | "d") => MICROSECONDS_PER_DAY,
+            (true, "H" | "h") => 3_600 * MICROSECONDS_PER_SECOND,
+            (true, "M" | "m") => 60 * MICROSECONDS_PER_SECOND,
+            (true, "S" | "s") => MICROSECONDS_PER_SECOND,
+            _ => return Err(invalid_component()),
+        };
+        let (component_negative, number) = match number.strip_prefix('-') {
+            Some(number) => (true, number),
+            None => (false, number.strip_prefix('+').unwrap_or(number)),
+        };
+        let (whole, fraction) = number.split_once(['.', ',']).unwrap_or((number, ""));
+        if whole.is_empty() || !whole.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(invalid_component());
+        }
+        let whole: u128 = whole.parse().map_err(|_| invalid_component())?;
+        // digits beyond this can't change the number of microseconds in even the longest unit
+        let fraction = &fraction[..fraction.len().min(18)];
+        let fraction_scale = 10u128.pow(u32::try_from(fraction.len()).unwrap_or_default());
+        let fraction_microseconds = fraction.parse::<u128>().unwrap_or(0) * unit_microseconds;
+        if fraction_microseconds % fraction_scale != 0
+            && matches!(
+                microseconds_overflow_behavior,
+                MicrosecondsPrecisionOverflowBehavior::Error
+            )
+        {
+            return Err(duration_err(format!(
+                "component '{component}' in ISO 8601 duration is more precise than a microsecond"
+            )));
+        }
+        total = whole
+            .checked_mul(unit_microseconds)
+            .and_then(|m| m.checked_add(fraction_microseconds / fraction_scale))
+            .and_then(|m| i128::try_from(m).ok())
+            .and_then(|m| total.checked_add(if component_negative { -m } else { m }))
+            .ok_or_else(|| duration_err(format!("ISO 8601 duration '{value}' is too large")))?;
+        rest = &rest[component_len..];
+    }
+
+    if negative {
+        total = -total;
+    }
+    let microseconds = total.unsigned_abs();
+    let day = u32::try_from(microseconds / MICROSECONDS_PER_DAY)
+        .map_err(|_| duration_err(format!("ISO 8601 duration '{value}' is too large")))?;
+    let second = (microseconds % MICROSECONDS_PER_DAY / MICROSECONDS_PER_SECOND) as u32;
+    let microsecond = (microseconds % MICROSECONDS_PER_SECOND) as u32;
+    Duration::new(total >= 0, day, second, microsecond).map_err(|err| map_timedelta_err(input, err))
+}
+
 #[pyclass(module = "pydantic_core._pydantic_core", extends = PyTzInfo, frozen)]
 #[derive(Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
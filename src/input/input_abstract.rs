@@ -1,6 +1,7 @@
 use std::convert::Infallible;
 use std::fmt;
 
+use jiter::JsonValue;
 use pyo3::exceptions::PyValueError;
 use pyo3::types::{PyDict, PyList, PyString};
 use pyo3::{intern, prelude::*, IntoPyObjectExt};
@@ -73,6 +74,10 @@ pub trait Input<'py>: fmt::Debug {
         None
     }
 
+    fn as_json(&self) -> Option<&JsonValue<'_>> {
+        None
+    }
+
     fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>>;
 
     type Arguments<'a>: Arguments<'py>
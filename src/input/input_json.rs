@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 use std::collections::HashSet;
 
-use jiter::{JsonArray, JsonObject, JsonValue};
+use ahash::AHashMap;
+use jiter::{Jiter, JiterResult, JsonArray, JsonObject, JsonValue};
 use num_traits::cast::ToPrimitive;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
@@ -12,7 +13,7 @@ use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError,
 use crate::input::return_enums::EitherComplex;
 use crate::lookup_key::{LookupKey, LookupPath};
 use crate::validators::complex::string_to_complex;
-use crate::validators::decimal::create_decimal;
+use crate::validators::decimal::{create_decimal, get_decimal_type};
 use crate::validators::{TemporalUnitMode, ValBytesMode};
 
 use super::datetime::{
@@ -59,6 +60,10 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         matches!(self, JsonValue::Null)
     }
 
+    fn as_json(&self) -> Option<&JsonValue<'_>> {
+        Some(self)
+    }
+
     fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         let JsonValue::Object(object) = self else {
             return None;
@@ -698,3 +703,86 @@ impl<'data> KeywordArgs<'_> for JsonObject<'data> {
         self.as_slice().iter().map(|(k, v)| Ok((k.as_ref(), v)))
     }
 }
+
+/// The source text of each float in a JSON document keyed by the address of its `JsonValue`, collected with
+/// `json_number_mode='decimal'` since `JsonValue` only holds an `f64`, so floats can become exact `Decimal`s
+#[derive(Debug, Default)]
+pub struct JsonDecimals<'j> {
+    texts: AHashMap<usize, &'j str>,
+}
+
+impl<'j> JsonDecimals<'j> {
+    /// Collect the float texts of `json_value`, which must have been parsed from `json_data` and not moved since
+    pub fn new(json_data: &'j [u8], json_value: &JsonValue<'_>) -> Self {
+        let mut decimals = Self::default();
+        let mut jiter = Jiter::new(json_data).with_allow_inf_nan();
+        // jiter can only fail where `json_value` was truncated by partial parsing, floats from there on are
+        // left as floats
+        let _ = decimals.collect(&mut jiter, json_value);
+        decimals
+    }
+
+    fn collect(&mut self, jiter: &mut Jiter<'j>, json_value: &JsonValue<'_>) -> JiterResult<()> {
+        match json_value {
+            JsonValue::Float(_) => {
+                jiter.peek()?;
+                let start = jiter.current_index();
+                jiter.next_number_bytes()?;
+                // jiter has checked this is a valid JSON number, so it's ASCII
+                if let Ok(text) = std::str::from_utf8(jiter.slice_to_current(start)) {
+                    self.texts.insert(address(json_value), text);
+                }
+            }
+            JsonValue::Array(array) => {
+                jiter.next_array()?;
+                for item in array.iter() {
+                    self.collect(jiter, item)?;
+                    jiter.array_step()?;
+                }
+            }
+            JsonValue::Object(object) => {
+                jiter.next_object()?;
+                for (_, value) in object.as_slice() {
+                    self.collect(jiter, value)?;
+                    jiter.next_key()?;
+                }
+            }
+            _ => jiter.next_skip()?,
+        }
+        Ok(())
+    }
+
+    /// Convert `json_value` to a python object like `to_object`, but with floats as `Decimal`s
+    pub fn to_object<'py>(&self, py: Python<'py>, json_value: &JsonValue<'_>) -> PyResult<Bound<'py, PyAny>> {
+        match json_value {
+            JsonValue::Float(_) => match self.float_text(json_value) {
+                Some(text) => get_decimal_type(py).call1((text,)),
+                None => json_value.into_pyobject(py),
+            },
+            JsonValue::Array(array) => {
+                let items = array
+                    .iter()
+                    .map(|item| self.to_object(py, item))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(PyList::new(py, items)?.into_any())
+            }
+            JsonValue::Object(object) => {
+                let dict = PyDict::new(py);
+                for (key, value) in object.as_slice() {
+                    dict.set_item(key, self.to_object(py, value)?)?;
+                }
+                Ok(dict.into_any())
+            }
+            _ => json_value.into_pyobject(py),
+        }
+    }
+
+    /// The source text of `json_value` if it's a float within the document
+    pub fn float_text(&self, json_value: &JsonValue<'_>) -> Option<&'j str> {
+        self.texts.get(&address(json_value)).copied()
+    }
+}
+
+fn address(json_value: &JsonValue<'_>) -> usize {
+    json_value as *const JsonValue<'_> as usize
+}
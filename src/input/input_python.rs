@@ -30,7 +30,7 @@ use super::datetime::{
 };
 use super::input_abstract::ValMatch;
 use super::return_enums::EitherComplex;
-use super::return_enums::{iterate_attributes, iterate_mapping_items, ValidationMatch};
+use super::return_enums::{collect_items_method, iterate_attributes, iterate_mapping_items, ValidationMatch};
 use super::shared::{
     decimal_as_int, float_as_int, fraction_as_int, get_enum_meta_object, int_as_bool, str_as_bool, str_as_float,
     str_as_int,
@@ -200,6 +200,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
                     Ok(self.str()?.into())
                 } else if let Some(enum_val) = maybe_as_enum(self) {
                     Ok(enum_val.str()?.into())
+                } else if let Some(path_str) = maybe_as_fspath(self) {
+                    Ok(path_str.into())
                 } else {
                     break 'lax;
                 }
@@ -409,6 +411,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
             Ok(GenericPyMapping::Dict(dict))
         } else if let Ok(mapping) = self.downcast::<PyMapping>() {
             Ok(GenericPyMapping::Mapping(mapping))
+        } else if let Some(dict) = collect_items_method(self)? {
+            Ok(GenericPyMapping::Items(dict))
         } else {
             Err(ValError::new(ErrorTypeDefaults::DictType, self))
         }
@@ -762,6 +766,14 @@ fn maybe_as_enum<'py>(v: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
     }
 }
 
+/// Utility for extracting the string path of an `os.PathLike` object (e.g. `pathlib.Path`), if possible,
+/// `__fspath__` is looked up on the type as `os.fspath` does, and paths returned as bytes are ignored.
+fn maybe_as_fspath<'py>(v: &Bound<'py, PyAny>) -> Option<Bound<'py, PyString>> {
+    let py = v.py();
+    let fspath = v.get_type().getattr(intern!(py, "__fspath__")).ok()?;
+    fspath.call1((v,)).ok()?.downcast_into::<PyString>().ok()
+}
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct PyArgs<'py> {
     pub args: Option<PyPosArgs<'py>>,
@@ -845,6 +857,8 @@ impl<'py> KeywordArgs<'py> for PyKwargs<'py> {
 pub enum GenericPyMapping<'a, 'py> {
     Dict(&'a Bound<'py, PyDict>),
     Mapping(&'a Bound<'py, PyMapping>),
+    /// an object with an `items()` method which isn't a mapping, with its items collected into a dict
+    Items(Bound<'py, PyDict>),
     GetAttr(Bound<'py, PyAny>, Option<Bound<'py, PyDict>>),
 }
 
@@ -865,6 +879,7 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
     ) -> ValResult<Option<(&'k crate::lookup_key::LookupPath, Self::Item<'_>)>> {
         match self {
             Self::Dict(dict) => key.py_get_dict_item(dict).map_err(Into::into),
+            Self::Items(dict) => key.py_get_dict_item(dict).map_err(Into::into),
             Self::Mapping(mapping) => key.py_get_mapping_item(mapping).map_err(Into::into),
             Self::GetAttr(obj, dict) => key.py_get_attr(obj, dict.as_ref()),
         }
@@ -880,6 +895,7 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
     ) -> ValResult<R> {
         match self {
             Self::Dict(dict) => Ok(consumer.consume_iterator(dict.iter().map(Ok))),
+            Self::Items(dict) => Ok(consumer.consume_iterator(dict.iter().map(Ok))),
             Self::Mapping(mapping) => Ok(consumer.consume_iterator(iterate_mapping_items(mapping)?)),
             Self::GetAttr(obj, _) => Ok(consumer.consume_iterator(iterate_attributes(obj)?)),
         }
@@ -888,6 +904,7 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
     fn last_key(&self) -> Option<Self::Key<'_>> {
         match self {
             Self::Dict(dict) => dict.keys().iter().last(),
+            Self::Items(dict) => dict.keys().iter().last(),
             // see https://github.com/pydantic/pydantic-core/pull/1512#discussion_r1826057970
             Self::Mapping(mapping) => mapping
                 .call_method0(intern!(mapping.py(), "keys"))
@@ -3,6 +3,7 @@ use std::cmp::Ordering;
 use std::convert::Infallible;
 use std::ops::Rem;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use jiter::{JsonArray, JsonValue, PartialMode, StringCacheMode};
 use num_bigint::BigInt;
@@ -12,7 +13,7 @@ use pyo3::ffi;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyFunction;
-use pyo3::types::{PyBytes, PyComplex, PyFloat, PyFrozenSet, PyIterator, PyMapping, PySet, PyString};
+use pyo3::types::{PyBytes, PyComplex, PyDict, PyFloat, PyFrozenSet, PyIterator, PyMapping, PySet, PyString};
 
 use pyo3::IntoPyObjectExt;
 use serde::{ser::Error, Serialize, Serializer};
@@ -124,11 +125,12 @@ pub(crate) fn validate_iter_to_vec<'py>(
     capacity: usize,
     mut max_length_check: MaxLengthCheck<'_, impl Input<'py> + ?Sized>,
     validator: &CombinedValidator,
+    prefix_validators: &[Arc<CombinedValidator>],
     state: &mut ValidationState<'_, 'py>,
     fail_fast: bool,
 ) -> ValResult<Vec<Py<PyAny>>> {
     let mut output: Vec<Py<PyAny>> = Vec::with_capacity(capacity);
-    let mut errors: Vec<ValLineError> = Vec::new();
+    let mut errors: Vec<ValLineError> = state.take_line_errors();
     let allow_partial = state.allow_partial;
 
     for (index, is_last_partial, item_result) in state.enumerate_last_partial(iter) {
@@ -137,16 +139,22 @@ pub(crate) fn validate_iter_to_vec<'py>(
             false => PartialMode::Off,
         };
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
-        match validator.validate(py, item.borrow_input(), state) {
+        let item_validator = prefix_validators.get(index).map_or(validator, AsRef::as_ref);
+        match state.validate_item(index, |state| item_validator.validate(py, item.borrow_input(), state)) {
             Ok(item) => {
                 max_length_check.incr()?;
                 output.push(item);
             }
-            Err(ValError::LineErrors(line_errors)) => {
+            Err(ValError::LineErrors(mut line_errors)) => {
                 max_length_check.incr()?;
                 if !is_last_partial {
-                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
-                    if fail_fast {
+                    if state.extra().track_locations {
+                        errors.extend(line_errors.drain(..).map(|err| err.with_outer_location(index)));
+                    } else {
+                        errors.append(&mut line_errors);
+                    }
+                    state.recycle_line_errors(line_errors);
+                    if fail_fast || state.fail_fast {
                         return Err(ValError::LineErrors(errors));
                     }
                 }
@@ -157,6 +165,7 @@ pub(crate) fn validate_iter_to_vec<'py>(
     }
 
     if errors.is_empty() {
+        state.recycle_line_errors(errors);
         Ok(output)
     } else {
         Err(ValError::LineErrors(errors))
@@ -224,7 +233,7 @@ pub(crate) fn validate_iter_to_set<'py>(
     state: &mut ValidationState<'_, 'py>,
     fail_fast: bool,
 ) -> ValResult<()> {
-    let mut errors: Vec<ValLineError> = Vec::new();
+    let mut errors: Vec<ValLineError> = state.take_line_errors();
 
     let allow_partial = state.allow_partial;
 
@@ -253,20 +262,24 @@ pub(crate) fn validate_iter_to_set<'py>(
                     }
                 }
             }
-            Err(ValError::LineErrors(line_errors)) => {
+            Err(ValError::LineErrors(mut line_errors)) => {
                 if !is_last_partial {
-                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                    // the position of an item in a set is meaningless, so it's left out of the location,
+                    // the error's input identifies the item instead
+                    errors.append(&mut line_errors);
+                    state.recycle_line_errors(line_errors);
                 }
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
-        if fail_fast && !errors.is_empty() {
+        if (fail_fast || state.fail_fast) && !errors.is_empty() {
             return Err(ValError::LineErrors(errors));
         }
     }
 
     if errors.is_empty() {
+        state.recycle_line_errors(errors);
         Ok(())
     } else {
         Err(ValError::LineErrors(errors))
@@ -278,12 +291,13 @@ pub(crate) fn no_validator_iter_to_vec<'py>(
     input: &(impl Input<'py> + ?Sized),
     iter: impl Iterator<Item = PyResult<impl BorrowInput<'py>>>,
     mut max_length_check: MaxLengthCheck<'_, impl Input<'py> + ?Sized>,
+    state: &ValidationState<'_, 'py>,
 ) -> ValResult<Vec<Py<PyAny>>> {
     iter.enumerate()
         .map(|(index, result)| {
             let v = result.map_err(|e| any_next_error!(py, e, input, index))?;
             max_length_check.incr()?;
-            Ok(v.borrow_input().to_object(py)?.unbind())
+            Ok(state.input_to_object(py, v.borrow_input())?.unbind())
         })
         .collect()
 }
@@ -311,6 +325,36 @@ pub(crate) fn iterate_mapping_items<'a, 'py>(
     Ok(iterator)
 }
 
+/// Collect the pairs from calling `items()` on an object which isn't a mapping, `None` if it has no `items()`
+pub(crate) fn collect_items_method<'py>(obj: &Bound<'py, PyAny>) -> ValResult<Option<Bound<'py, PyDict>>> {
+    let py = obj.py();
+    let Some(items) = obj.getattr_opt(intern!(py, "items"))? else {
+        return Ok(None);
+    };
+    if !items.is_callable() {
+        return Ok(None);
+    }
+    let dict = PyDict::new(py);
+    let pairs = items
+        .call0()
+        .and_then(|pairs| pairs.try_iter())
+        .map_err(|e| mapping_err(e, py, obj))?;
+    for pair in pairs {
+        let pair = pair.map_err(|e| mapping_err(e, py, obj))?;
+        let (key, value): (Bound<'py, PyAny>, Bound<'py, PyAny>) = pair.extract().map_err(|_| {
+            ValError::new(
+                ErrorType::MappingType {
+                    error: MAPPING_TUPLE_ERROR.into(),
+                    context: None,
+                },
+                obj,
+            )
+        })?;
+        dict.set_item(key, value)?;
+    }
+    Ok(Some(dict))
+}
+
 fn mapping_err<'py>(err: PyErr, py: Python<'py>, input: &'py (impl Input<'py> + ?Sized)) -> ValError {
     ValError::new(
         ErrorType::MappingType {
@@ -39,7 +39,7 @@ pub use serializers::{
     to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
     WarningsArg,
 };
-pub use validators::{PySome, SchemaValidator};
+pub use validators::{PySome, SchemaValidator, ValidationResult};
 
 use crate::input::Input;
 
@@ -116,7 +116,7 @@ pub mod _pydantic_core {
         from_json, list_all_errors, to_json, to_jsonable_python, ArgsKwargs, PyMultiHostUrl, PySome, PyUrl,
         PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticSerializationError,
         PydanticSerializationUnexpectedValue, PydanticUndefinedType, PydanticUseDefault, SchemaError, SchemaSerializer,
-        SchemaValidator, TzInfo, ValidationError,
+        SchemaValidator, TzInfo, ValidationError, ValidationResult,
     };
 
     #[pymodule_init]
@@ -9,7 +9,9 @@ use pyo3::IntoPyObjectExt;
 use jiter::{JsonObject, JsonValue};
 
 use crate::build_tools::py_schema_err;
-use crate::errors::{py_err_string, ErrorType, LocItem, Location, ToErrorValue, ValError, ValLineError, ValResult};
+use crate::errors::{
+    py_err_string, ErrorType, LocItem, LocItems, Location, ToErrorValue, ValError, ValLineError, ValResult,
+};
 use crate::input::StringMapping;
 use crate::tools::{extract_i64, mapping_get, py_err};
 
@@ -272,6 +274,31 @@ impl LookupKey {
         }
     }
 
+    /// The paths looked up, in order
+    pub fn paths(&self) -> Vec<&LookupPath> {
+        match self {
+            Self::Simple(path) => vec![path],
+            Self::Choice { path1, path2 } => vec![path1, path2],
+            Self::PathChoices(paths) => paths.iter().collect(),
+        }
+    }
+
+    /// A copy of the key with the first key of each path replaced by the key `replace` returns for it, if any
+    pub fn with_first_keys<'k>(&self, py: Python, mut replace: impl FnMut(&str) -> Option<&'k str>) -> Self {
+        let mut replace_path = |path: &LookupPath| match replace(path.first_key()) {
+            Some(key) => path.with_first_key(py, key),
+            None => path.clone(),
+        };
+        match self {
+            Self::Simple(path) => Self::Simple(replace_path(path)),
+            Self::Choice { path1, path2 } => Self::Choice {
+                path1: replace_path(path1),
+                path2: replace_path(path2),
+            },
+            Self::PathChoices(paths) => Self::PathChoices(paths.iter().map(replace_path).collect()),
+        }
+    }
+
     pub fn error(
         &self,
         error_type: ErrorType,
@@ -286,7 +313,7 @@ impl LookupKey {
                 Self::PathChoices(paths) => paths.first().unwrap(),
             };
 
-            let mut location = Vec::with_capacity(1 + lookup_path.rest.len());
+            let mut location = LocItems::with_capacity(1 + lookup_path.rest.len());
             for item in lookup_path.rest.iter().rev() {
                 location.push(item.to_loc_item());
             }
@@ -299,7 +326,7 @@ impl LookupKey {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct LookupPath {
     /// All paths must start with a string key
     first_item: PathItemString,
@@ -353,6 +380,16 @@ impl LookupPath {
         Ok(Self { first_item, rest })
     }
 
+    fn with_first_key(&self, py: Python, key: &str) -> Self {
+        Self {
+            first_item: PathItemString {
+                key: key.to_string(),
+                py_key: PyString::new(py, key).unbind(),
+            },
+            rest: self.rest.clone(),
+        }
+    }
+
     pub fn apply_error_loc(&self, mut line_error: ValLineError, loc_by_alias: bool, field_name: &str) -> ValLineError {
         if loc_by_alias {
             for path_item in self.rest.iter().rev() {
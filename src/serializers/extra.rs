@@ -6,7 +6,7 @@ use std::string::ToString;
 
 use pyo3::exceptions::{PyTypeError, PyUserWarning, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyString};
+use pyo3::types::{PyBool, PyDict, PyString};
 use pyo3::{intern, PyTypeInfo};
 
 use serde::ser::Error;
@@ -33,6 +33,8 @@ pub(crate) struct SerializationState<'a, 'py> {
     /// Inside unions, checks are applied to attempt to select a preferred branch
     pub check: SerCheck,
     pub include_exclude: (Option<Bound<'py, PyAny>>, Option<Bound<'py, PyAny>>),
+    /// Baseline dict for `diff_against`, fields equal to their baseline value are omitted
+    pub diff_against: Option<Bound<'py, PyDict>>,
     /// Global settings for the serialization process
     pub extra: Extra<'a, 'py>,
 }
@@ -76,6 +78,7 @@ impl<'a, 'py> SerializationState<'a, 'py> {
             field_name: None,
             check: SerCheck::None,
             include_exclude: (include, exclude),
+            diff_against: None,
             extra,
         })
     }
@@ -172,6 +175,8 @@ pub(crate) struct Extra<'a, 'py> {
     pub exclude_defaults: bool,
     pub exclude_none: bool,
     pub exclude_computed_fields: bool,
+    /// Known fields excluded by `include` or `exclude` are serialized as `None` rather than omitted
+    pub emit_null_for_excluded: bool,
     pub round_trip: bool,
     pub serialize_unknown: bool,
     pub fallback: Option<&'a Bound<'py, PyAny>>,
@@ -203,6 +208,7 @@ impl<'a, 'py> Extra<'a, 'py> {
             exclude_defaults,
             exclude_none,
             exclude_computed_fields,
+            emit_null_for_excluded: false,
             round_trip,
             serialize_unknown,
             fallback,
@@ -243,6 +249,7 @@ pub(crate) struct ExtraOwned {
     exclude_defaults: bool,
     exclude_none: bool,
     exclude_computed_fields: bool,
+    emit_null_for_excluded: bool,
     round_trip: bool,
     config: SerializationConfig,
     rec_guard: RecursionState,
@@ -291,6 +298,7 @@ impl ExtraOwned {
             exclude_defaults: extra.exclude_defaults,
             exclude_none: extra.exclude_none,
             exclude_computed_fields: extra.exclude_computed_fields,
+            emit_null_for_excluded: extra.emit_null_for_excluded,
             round_trip: extra.round_trip,
             config: state.config,
             rec_guard: state.rec_guard.clone(),
@@ -318,6 +326,7 @@ impl ExtraOwned {
             exclude_defaults: self.exclude_defaults,
             exclude_none: self.exclude_none,
             exclude_computed_fields: self.exclude_computed_fields,
+            emit_null_for_excluded: self.emit_null_for_excluded,
             round_trip: self.round_trip,
             serialize_unknown: self.serialize_unknown,
             fallback: self.fallback.as_ref().map(|m| m.bind(py)),
@@ -343,6 +352,7 @@ impl ExtraOwned {
                 self.include.as_ref().map(|m| m.bind(py).clone()),
                 self.exclude.as_ref().map(|m| m.bind(py).clone()),
             ),
+            diff_against: None,
             extra,
         }
     }
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::string::ToString;
 use std::sync::Arc;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
@@ -13,7 +14,8 @@ use crate::common::missing_sentinel::get_missing_sentinel_object;
 use crate::serializers::extra::{FieldName, SerCheck};
 use crate::serializers::type_serializers::any::AnySerializer;
 use crate::serializers::type_serializers::function::{FunctionPlainSerializer, FunctionWrapSerializer};
-use crate::serializers::SerializationState;
+use crate::serializers::{PydanticSerializationError, SerializationState};
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::computed_fields::ComputedFields;
@@ -34,6 +36,8 @@ pub(super) struct SerField {
     pub required: bool,
     pub serialize_by_alias: Option<bool>,
     pub serialization_exclude_if: Option<Py<PyAny>>,
+    // when set, the items of the serialized value are emitted in the parent with this prefix on their keys
+    pub flatten_prefix: Option<String>,
 }
 
 impl_py_gc_traverse!(SerField { serializer });
@@ -57,6 +61,7 @@ impl SerField {
             required,
             serialize_by_alias,
             serialization_exclude_if,
+            flatten_prefix: None,
         }
     }
 
@@ -106,6 +111,42 @@ fn exclude_default<'py>(
     Ok(false)
 }
 
+/// Whether `baseline` (from `diff_against`) holds a value equal to the serialized `value` under `key`
+fn equals_baseline(
+    baseline: Option<&Bound<'_, PyDict>>,
+    key: &Bound<'_, PyAny>,
+    value: &Bound<'_, PyAny>,
+) -> PyResult<bool> {
+    match baseline.map(|baseline| baseline.get_item(key)).transpose()?.flatten() {
+        Some(base_value) => base_value.eq(value),
+        None => Ok(false),
+    }
+}
+
+/// The keys written so far by a fields serializer with a field flattened by `serialization_flatten_prefix`,
+/// mapped to the name of the flattened field they came from, to error when a flattened key collides with another
+#[derive(Default)]
+struct OutputKeys(AHashMap<String, Option<String>>);
+
+impl OutputKeys {
+    fn insert(&mut self, key: &str, flattened_from: Option<&str>) -> PyResult<()> {
+        let previous = self.0.insert(key.to_string(), flattened_from.map(ToString::to_string));
+        match previous.map(|previous| previous.or_else(|| flattened_from.map(ToString::to_string))) {
+            Some(Some(field)) => Err(PydanticSerializationError::new_err(format!(
+                "Key `{key}` from flattening field `{field}` collides with another key"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Whether the schema allowlists extra keys with `allowed_extra_keys`, validation keeps those keys
+/// even with `extra_behavior='forbid'`
+pub(super) fn has_allowed_extra_keys(schema: &Bound<'_, PyDict>) -> PyResult<bool> {
+    let keys: Option<Vec<String>> = schema.get_as(intern!(schema.py(), "allowed_extra_keys"))?;
+    Ok(keys.is_some_and(|keys| !keys.is_empty()))
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub(super) enum FieldsMode {
     // typeddict with no extra items
@@ -126,6 +167,8 @@ pub struct GeneralFieldsSerializer {
     // isize because we look up filter via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
     required_fields: usize,
+    // whether any field has `flatten_prefix`, so output keys need checking for collisions
+    flatten: bool,
 }
 
 macro_rules! option_length {
@@ -145,6 +188,7 @@ impl GeneralFieldsSerializer {
         computed_fields: Option<ComputedFields>,
     ) -> Self {
         let required_fields = fields.values().filter(|f| f.required).count();
+        let flatten = fields.values().any(|f| f.flatten_prefix.is_some());
         Self {
             fields,
             mode,
@@ -152,6 +196,7 @@ impl GeneralFieldsSerializer {
             filter: SchemaFilter::default(),
             computed_fields,
             required_fields,
+            flatten,
         }
     }
 
@@ -178,6 +223,10 @@ impl GeneralFieldsSerializer {
         let output_dict = PyDict::new(py);
         let mut used_req_fields: usize = 0;
         let missing_sentinel = get_missing_sentinel_object(py);
+        // `diff_against` only applies to the outermost fields, not to nested models
+        let diff_against = state.diff_against.clone();
+        let state = &mut state.scoped_set(|s| &mut s.diff_against, None);
+        let mut output_keys = self.flatten.then(OutputKeys::default);
 
         // NOTE! we maintain the order of the input dict assuming that's right
         for result in main_iter {
@@ -227,16 +276,46 @@ impl GeneralFieldsSerializer {
                     continue;
                 };
 
+                if let Some((field, flatten_prefix)) =
+                    op_field.and_then(|field| Some((field, field.flatten_prefix.as_deref()?)))
+                {
+                    let value = Self::flattened_to_python(&value, field, serializer, state)?;
+                    if let Ok(value_dict) = value.bind(py).downcast::<PyDict>() {
+                        for (sub_key, sub_value) in value_dict {
+                            if state.extra.exclude_none && sub_value.is_none() {
+                                continue;
+                            }
+                            let flat_key = PyString::new(py, &format!("{flatten_prefix}{sub_key}"));
+                            if let Some(ref mut output_keys) = output_keys {
+                                output_keys.insert(flat_key.to_str()?, Some(key_str))?;
+                            }
+                            if !equals_baseline(diff_against.as_ref(), &flat_key, &sub_value)? {
+                                output_dict.set_item(flat_key, sub_value)?;
+                            }
+                        }
+                        continue;
+                    }
+                }
+                if let Some(ref mut output_keys) = output_keys {
+                    output_keys.insert(key.downcast::<PyString>()?.to_str()?, None)?;
+                }
                 // Use `no_infer` here because the `serialize_as_any` logic has been handled in `prepare_value`
                 let value = serializer.to_python_no_infer(&value, state)?;
+                if op_field.is_some() && equals_baseline(diff_against.as_ref(), key, value.bind(py))? {
+                    continue;
+                }
                 output_dict.set_item(key, value)?;
+            } else if let Some(field) =
+                op_field.filter(|field| state.extra.emit_null_for_excluded && field.serializer.is_some())
+            {
+                output_dict.set_item(field.get_key_py(py, &state.extra), py.None())?;
             }
         }
 
         let extra = &state.extra;
         if state.check.enabled()
             // If any of these are true we can't count fields
-            && !(extra.exclude_defaults || extra.exclude_unset || extra.exclude_none || extra.exclude_computed_fields || state.exclude().is_some())
+            && !(extra.exclude_defaults || extra.exclude_unset || extra.exclude_none || extra.exclude_computed_fields || state.exclude().is_some() || diff_against.is_some())
             // Check for missing fields, we can't have extra fields here
             && self.required_fields > used_req_fields
         {
@@ -264,6 +343,9 @@ impl GeneralFieldsSerializer {
         // NOTE! As above, we maintain the order of the input dict assuming that's right
         // we don't both with `used_req_fields` here because on unions, `to_python(..., mode='json')` is used
         let mut map = serializer.serialize_map(Some(expected_len))?;
+        let diff_against = state.diff_against.clone();
+        let state = &mut state.scoped_set(|s| &mut s.diff_against, None);
+        let mut output_keys = self.flatten.then(OutputKeys::default);
 
         for result in main_iter {
             let (key, value) = result.map_err(py_err_se_err)?;
@@ -288,17 +370,65 @@ impl GeneralFieldsSerializer {
                         continue;
                     };
 
+                    if let Some(ref flatten_prefix) = field.flatten_prefix {
+                        let py_value =
+                            Self::flattened_to_python(&value, field, serializer, state).map_err(py_err_se_err)?;
+                        if let Ok(value_dict) = py_value.bind(value.py()).downcast::<PyDict>() {
+                            for (sub_key, sub_value) in value_dict {
+                                if state.extra.exclude_none && sub_value.is_none() {
+                                    continue;
+                                }
+                                let flat_key = PyString::new(value.py(), &format!("{flatten_prefix}{sub_key}"));
+                                if let Some(ref mut output_keys) = output_keys {
+                                    let flat_key = flat_key.to_str().map_err(py_err_se_err)?;
+                                    output_keys.insert(flat_key, Some(key_str)).map_err(py_err_se_err)?;
+                                }
+                                if equals_baseline(diff_against.as_ref(), &flat_key, &sub_value)
+                                    .map_err(py_err_se_err)?
+                                {
+                                    continue;
+                                }
+                                map.serialize_entry(
+                                    flat_key.to_str().map_err(py_err_se_err)?,
+                                    &SerializeInfer::new(&sub_value, state),
+                                )?;
+                            }
+                            continue;
+                        }
+                    }
                     let output_key = field.get_key_json(key_str, &state.extra);
+                    if let Some(ref mut output_keys) = output_keys {
+                        output_keys.insert(&output_key, None).map_err(py_err_se_err)?;
+                    }
+                    if diff_against.is_some() {
+                        // compare the JSON-compatible python value against the baseline
+                        let py_value = serializer.to_python_no_infer(&value, state).map_err(py_err_se_err)?;
+                        let py_key = PyString::new(value.py(), &output_key);
+                        if equals_baseline(diff_against.as_ref(), &py_key, py_value.bind(value.py()))
+                            .map_err(py_err_se_err)?
+                        {
+                            continue;
+                        }
+                    }
                     // Use `no_infer` here because the `serialize_as_any` logic has been handled in `prepare_value`
                     let s = PydanticSerializer::new_no_infer(&value, serializer, state);
                     map.serialize_entry(&output_key, &s)?;
                 } else if self.mode == FieldsMode::TypedDictAllow {
                     // FIXME: why is `extra_serializer` not used here when `serialize_as_any` is not set?
                     let output_key = infer_json_key(&key, state).map_err(py_err_se_err)?;
+                    if let Some(ref mut output_keys) = output_keys {
+                        output_keys.insert(&output_key, None).map_err(py_err_se_err)?;
+                    }
                     let s = SerializeInfer::new(&value, state);
                     map.serialize_entry(&output_key, &s)?;
                 }
                 // no error case here since unions (which need the error case) use `to_python(..., mode='json')`
+            } else if let Some(field) = self
+                .fields
+                .get(key_str)
+                .filter(|field| state.extra.emit_null_for_excluded && field.serializer.is_some())
+            {
+                map.serialize_entry(&field.get_key_json(key_str, &state.extra), &None::<()>)?;
             }
         }
         Ok(map)
@@ -347,6 +477,19 @@ impl GeneralFieldsSerializer {
         ))
     }
 
+    /// Serialize the value of a field flattened with `flatten_prefix`, its keys end up in the parent so are
+    /// serialized by alias when the parent's fields are
+    fn flattened_to_python<'py>(
+        value: &Bound<'py, PyAny>,
+        field: &SerField,
+        serializer: &CombinedSerializer,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> PyResult<Py<PyAny>> {
+        let by_alias = state.extra.serialize_by_alias_or(field.serialize_by_alias);
+        let state = &mut state.scoped_set(|s| &mut s.extra.by_alias, Some(by_alias));
+        serializer.to_python_no_infer(value, state)
+    }
+
     pub(crate) fn add_computed_fields_python<'py>(
         &self,
         model: &Bound<'py, PyAny>,
@@ -25,7 +25,7 @@ use super::errors::SERIALIZATION_ERR_MARKER;
 use super::errors::{py_err_se_err, PydanticSerializationError};
 use super::extra::SerMode;
 use super::filter::{AnyFilter, SchemaFilter};
-use super::ob_type::ObType;
+use super::ob_type::{downcast_dict, ObType};
 use super::shared::any_dataclass_iter;
 use super::SchemaSerializer;
 
@@ -142,8 +142,8 @@ pub(crate) fn infer_to_python_known<'py>(
                 let elements = serialize_seq!(PyFrozenSet);
                 PyList::new(py, elements)?.into()
             }
-            ObType::Dict => {
-                let dict = value.downcast::<PyDict>()?;
+            ObType::Dict | ObType::MappingProxy => {
+                let dict = downcast_dict(value)?;
                 serialize_pairs_python(py, dict.iter().map(Ok), state, |k, state| {
                     Ok(PyString::new(py, &infer_json_key(&k, state)?).into_any())
                 })?
@@ -236,8 +236,8 @@ pub(crate) fn infer_to_python_known<'py>(
                 let elements = serialize_seq!(PyFrozenSet);
                 PyFrozenSet::new(py, &elements)?.into()
             }
-            ObType::Dict => {
-                let dict = value.downcast::<PyDict>()?;
+            ObType::Dict | ObType::MappingProxy => {
+                let dict = downcast_dict(value)?;
                 serialize_pairs_python(py, dict.iter().map(Ok), state, |k, _| Ok(k))?
             }
             ObType::PydanticSerializable => call_pydantic_serializer(value, state, serialize_to_python())?,
@@ -394,8 +394,8 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
                 state.config.bytes_mode.serialize_bytes(bytes, serializer)
             })
         }
-        ObType::Dict => {
-            let dict = value.downcast::<PyDict>().map_err(py_err_se_err)?;
+        ObType::Dict | ObType::MappingProxy => {
+            let dict = downcast_dict(value).map_err(py_err_se_err)?;
             serialize_pairs_json(dict.iter().map(Ok), dict.len(), serializer, state)
         }
         ObType::List => serialize_seq_filter!(PyList),
@@ -572,7 +572,7 @@ pub(crate) fn infer_json_key_known<'a, 'py>(
             }
             Ok(Cow::Owned(key_build.finish()))
         }
-        ObType::List | ObType::Set | ObType::Frozenset | ObType::Dict | ObType::Generator => {
+        ObType::List | ObType::Set | ObType::Frozenset | ObType::Dict | ObType::MappingProxy | ObType::Generator => {
             py_err!(PyTypeError; "`{}` not valid as object key", ob_type)
         }
         ObType::Dataclass | ObType::PydanticSerializable => {
@@ -626,6 +626,7 @@ pub(crate) fn call_pydantic_serializer<'py, T, E: From<PyErr>>(
         model: state.model.clone(),
         field_name: state.field_name.clone(),
         include_exclude: state.include_exclude.clone(),
+        diff_against: state.diff_against.clone(),
         check: state.check,
         extra: state.extra.clone(),
     };
@@ -13,6 +13,7 @@ use crate::py_gc::PyGcTraverse;
 pub(crate) use config::{BytesMode, SerializationConfig};
 pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
 pub(crate) use extra::{Extra, SerMode, SerializationState, WarningsMode};
+pub(crate) use ob_type::{downcast_dict, ObType, ObTypeLookup};
 use shared::to_json_bytes;
 pub use shared::CombinedSerializer;
 
@@ -78,7 +79,8 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = None,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, exclude_computed_fields = false,
-        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None,
+        diff_against = None, emit_null_for_excluded = false))]
     pub fn to_python(
         &self,
         py: Python,
@@ -96,13 +98,15 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        diff_against: Option<Bound<'_, PyDict>>,
+        emit_null_for_excluded: bool,
     ) -> PyResult<Py<PyAny>> {
         let mode: SerMode = mode.into();
         let warnings_mode = match warnings {
             WarningsArg::Bool(b) => b.into(),
             WarningsArg::Literal(mode) => mode,
         };
-        let extra = Extra::new(
+        let mut extra = Extra::new(
             py,
             &mode,
             by_alias,
@@ -116,7 +120,9 @@ impl SchemaSerializer {
             serialize_as_any,
             context,
         );
+        extra.emit_null_for_excluded = emit_null_for_excluded;
         let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        state.diff_against = diff_against;
         let v = self.serializer.to_python(value, &mut state)?;
         state.warnings.final_check(py)?;
         Ok(v)
@@ -125,7 +131,8 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, indent = None, ensure_ascii = false, include = None, exclude = None, by_alias = None,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, exclude_computed_fields = false,
-        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None,
+        diff_against = None, emit_null_for_excluded = false))]
     pub fn to_json(
         &self,
         py: Python,
@@ -144,12 +151,14 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        diff_against: Option<Bound<'_, PyDict>>,
+        emit_null_for_excluded: bool,
     ) -> PyResult<Py<PyAny>> {
         let warnings_mode = match warnings {
             WarningsArg::Bool(b) => b.into(),
             WarningsArg::Literal(mode) => mode,
         };
-        let extra = Extra::new(
+        let mut extra = Extra::new(
             py,
             &SerMode::Json,
             by_alias,
@@ -163,7 +172,9 @@ impl SchemaSerializer {
             serialize_as_any,
             context,
         );
+        extra.emit_null_for_excluded = emit_null_for_excluded;
         let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        state.diff_against = diff_against;
         let bytes = to_json_bytes(
             value,
             &self.serializer,
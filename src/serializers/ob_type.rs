@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 use pyo3::sync::PyOnceLock;
 use pyo3::types::{
     PyBool, PyByteArray, PyBytes, PyComplex, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyInt,
-    PyIterator, PyList, PyNone, PySet, PyString, PyTime, PyTuple, PyType,
+    PyIterator, PyList, PyMappingProxy, PyNone, PySet, PyString, PyTime, PyTuple, PyType,
 };
 use pyo3::{intern, PyTypeInfo};
 
@@ -21,6 +21,8 @@ pub struct ObTypeLookup {
     string: usize,
     list: usize,
     dict: usize,
+    // other mapping types
+    mappingproxy: usize,
     // other numeric types
     decimal_object: Py<PyAny>,
     // other string types
@@ -75,6 +77,7 @@ impl ObTypeLookup {
             float: PyFloat::type_object_raw(py) as usize,
             list: PyList::type_object_raw(py) as usize,
             dict: PyDict::type_object_raw(py) as usize,
+            mappingproxy: PyMappingProxy::type_object_raw(py) as usize,
             decimal_object: py.import("decimal").unwrap().getattr("Decimal").unwrap().unbind(),
             string: PyString::type_object_raw(py) as usize,
             bytes: PyBytes::type_object_raw(py) as usize,
@@ -147,6 +150,7 @@ impl ObTypeLookup {
             ObType::Str => self.string == ob_type,
             ObType::List => self.list == ob_type,
             ObType::Dict => self.dict == ob_type,
+            ObType::MappingProxy => self.mappingproxy == ob_type,
             ObType::Decimal => self.decimal_object.as_ptr() as usize == ob_type,
             ObType::StrSubclass => self.string == ob_type && op_value.is_none(),
             ObType::Tuple => self.tuple == ob_type,
@@ -237,6 +241,8 @@ impl ObTypeLookup {
             ObType::Set
         } else if ob_type == self.frozenset {
             ObType::Frozenset
+        } else if ob_type == self.mappingproxy {
+            ObType::MappingProxy
         } else if ob_type == self.datetime {
             ObType::Datetime
         } else if ob_type == self.date {
@@ -420,6 +426,7 @@ pub enum ObType {
     Frozenset,
     // mapping types
     Dict,
+    MappingProxy,
     // datetime types
     Datetime,
     Date,
@@ -473,6 +480,17 @@ impl PartialEq for ObType {
     }
 }
 
+/// `value` as a dict, including the read-only `mappingproxy` a dict is validated to with `deep_freeze`,
+/// which is copied to a new dict
+pub(crate) fn downcast_dict<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    match value.downcast::<PyMappingProxy>() {
+        Ok(proxy) => Ok(proxy
+            .call_method0(intern!(value.py(), "copy"))?
+            .downcast_into::<PyDict>()?),
+        Err(_) => Ok(value.downcast::<PyDict>()?.clone()),
+    }
+}
+
 fn get_base_type<'py>(py_type: &Bound<'py, PyType>) -> Option<Bound<'py, PyType>> {
     let base_type_ptr = unsafe { (*py_type.as_type_ptr()).tp_base };
     // Safety: `base_type_ptr` must be a valid pointer to a Python type object, or null.
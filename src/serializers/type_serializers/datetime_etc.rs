@@ -1,16 +1,21 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDate, PyDateTime, PyDict, PyTime};
+use pyo3::types::{PyDate, PyDateTime, PyDict, PyString, PyTime};
+use pyo3::IntoPyObjectExt;
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, SerMode, TypeSerializer,
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, SerMode,
+    TypeSerializer,
 };
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
 use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time};
 use crate::serializers::config::{FromConfig, TemporalMode};
 use crate::serializers::SerializationState;
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
 pub(crate) fn datetime_to_string(py_dt: &Bound<'_, PyDateTime>) -> PyResult<String> {
@@ -27,6 +32,51 @@ pub(crate) fn datetime_to_seconds(py_dt: &Bound<'_, PyDateTime>) -> PyResult<f64
     })
 }
 
+/// Seconds since the Unix epoch, an int when there are no fractional seconds
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum EpochSeconds {
+    Int(i64),
+    Float(f64),
+}
+
+impl EpochSeconds {
+    fn into_py_any(self, py: Python) -> PyResult<Py<PyAny>> {
+        match self {
+            Self::Int(seconds) => seconds.into_py_any(py),
+            Self::Float(seconds) => seconds.into_py_any(py),
+        }
+    }
+
+    fn to_json_key<'a>(self) -> Cow<'a, str> {
+        match self {
+            Self::Int(seconds) => seconds.to_string().into(),
+            Self::Float(seconds) => seconds.to_string().into(),
+        }
+    }
+
+    fn serialize<S: serde::ser::Serializer>(self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Int(seconds) => serializer.serialize_i64(seconds),
+            Self::Float(seconds) => serializer.serialize_f64(seconds),
+        }
+    }
+}
+
+/// Seconds since the Unix epoch, aware datetimes are converted to UTC and naive datetimes are taken to be UTC
+pub(crate) fn datetime_to_epoch(py_dt: &Bound<'_, PyDateTime>) -> PyResult<EpochSeconds> {
+    pydatetime_as_datetime(py_dt).map(|dt| {
+        let seconds = dt.date.timestamp()
+            + i64::from(dt.time.hour) * 3600
+            + i64::from(dt.time.minute) * 60
+            + i64::from(dt.time.second)
+            - i64::from(dt.time.tz_offset.unwrap_or(0));
+        match dt.time.microsecond {
+            0 => EpochSeconds::Int(seconds),
+            microsecond => EpochSeconds::Float(seconds as f64 + f64::from(microsecond) / 1_000_000.0),
+        }
+    })
+}
+
 pub(crate) fn datetime_to_milliseconds(py_dt: &Bound<'_, PyDateTime>) -> PyResult<f64> {
     pydatetime_as_datetime(py_dt).map(|dt| {
         dt.date.timestamp_ms() as f64
@@ -82,30 +132,88 @@ fn downcast_date_reject_datetime<'a, 'py>(py_date: &'a Bound<'py, PyAny>) -> PyR
     Err(PydanticSerializationUnexpectedValue::new_from_msg(None).to_py_err())
 }
 
+/// The JSON mode of date and time serializers, following `ser_json_temporal`
+fn temporal_mode_from_config(
+    _schema: &Bound<'_, PyDict>,
+    config: Option<&Bound<'_, PyDict>>,
+) -> PyResult<TemporalMode> {
+    TemporalMode::from_config(config)
+}
+
+/// How a datetime is serialized to JSON, `datetime_mode='epoch'` on the schema overrides `ser_json_temporal`
+#[derive(Debug, Clone, Copy)]
+enum DatetimeMode {
+    Temporal(TemporalMode),
+    /// seconds since the Unix epoch, see `datetime_to_epoch`
+    Epoch,
+}
+
+impl DatetimeMode {
+    fn from_schema(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        match schema.get_as::<Bound<'_, PyString>>(intern!(schema.py(), "datetime_mode"))? {
+            None => Ok(Self::Temporal(TemporalMode::from_config(config)?)),
+            Some(mode) => match mode.to_str()? {
+                "temporal" => Ok(Self::Temporal(TemporalMode::from_config(config)?)),
+                "epoch" => Ok(Self::Epoch),
+                s => py_schema_err!("Invalid datetime_mode: {:?}, expected 'temporal' or 'epoch'", s),
+            },
+        }
+    }
+
+    fn datetime_to_json(self, py: Python, datetime: &Bound<'_, PyDateTime>) -> PyResult<Py<PyAny>> {
+        match self {
+            Self::Temporal(temporal_mode) => temporal_mode.datetime_to_json(py, datetime),
+            Self::Epoch => datetime_to_epoch(datetime)?.into_py_any(py),
+        }
+    }
+
+    fn datetime_json_key<'py>(self, datetime: &Bound<'_, PyDateTime>) -> PyResult<Cow<'py, str>> {
+        match self {
+            Self::Temporal(temporal_mode) => temporal_mode.datetime_json_key(datetime),
+            Self::Epoch => Ok(datetime_to_epoch(datetime)?.to_json_key()),
+        }
+    }
+
+    fn datetime_serialize<S: serde::ser::Serializer>(
+        self,
+        datetime: &Bound<'_, PyDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Temporal(temporal_mode) => temporal_mode.datetime_serialize(datetime, serializer),
+            Self::Epoch => datetime_to_epoch(datetime)
+                .map_err(py_err_se_err)?
+                .serialize(serializer),
+        }
+    }
+}
+
 macro_rules! build_temporal_serializer {
     (
         $Struct:ident,
         $expected_type:literal,
         $downcast:path,
+        $Mode:ty,
+        $build_mode:path,
         $to_json:ident,
         $json_key_fn:ident,
         $serialize_fn:ident
     ) => {
         #[derive(Debug)]
         pub struct $Struct {
-            temporal_mode: TemporalMode,
+            mode: $Mode,
         }
 
         impl BuildSerializer for $Struct {
             const EXPECTED_TYPE: &'static str = $expected_type;
 
             fn build(
-                _schema: &Bound<'_, PyDict>,
+                schema: &Bound<'_, PyDict>,
                 config: Option<&Bound<'_, PyDict>>,
                 _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
             ) -> PyResult<Arc<CombinedSerializer>> {
-                let temporal_mode = TemporalMode::from_config(config)?;
-                Ok(Arc::new(Self { temporal_mode }.into()))
+                let mode = $build_mode(schema, config)?;
+                Ok(Arc::new(Self { mode }.into()))
             }
         }
 
@@ -119,7 +227,7 @@ macro_rules! build_temporal_serializer {
             ) -> PyResult<Py<PyAny>> {
                 match $downcast(value) {
                     Ok(py_value) => match state.extra.mode {
-                        SerMode::Json => Ok(self.temporal_mode.$to_json(value.py(), py_value)?),
+                        SerMode::Json => Ok(self.mode.$to_json(value.py(), py_value)?),
                         _ => Ok(value.clone().unbind()),
                     },
                     _ => {
@@ -135,7 +243,7 @@ macro_rules! build_temporal_serializer {
                 state: &mut SerializationState<'_, 'py>,
             ) -> PyResult<Cow<'a, str>> {
                 match $downcast(key) {
-                    Ok(py_value) => Ok(self.temporal_mode.$json_key_fn(py_value)?),
+                    Ok(py_value) => Ok(self.mode.$json_key_fn(py_value)?),
                     Err(_) => {
                         state.warn_fallback_py(self.get_name(), key)?;
                         infer_json_key(key, state)
@@ -150,7 +258,7 @@ macro_rules! build_temporal_serializer {
                 state: &mut SerializationState<'_, 'py>,
             ) -> Result<S::Ok, S::Error> {
                 match $downcast(value) {
-                    Ok(py_value) => self.temporal_mode.$serialize_fn(py_value, serializer),
+                    Ok(py_value) => self.mode.$serialize_fn(py_value, serializer),
                     Err(_) => {
                         state.warn_fallback_ser::<S>(self.get_name(), value)?;
                         infer_serialize(value, serializer, state)
@@ -169,6 +277,8 @@ build_temporal_serializer!(
     DatetimeSerializer,
     "datetime",
     PyAnyMethods::downcast::<PyDateTime>,
+    DatetimeMode,
+    DatetimeMode::from_schema,
     datetime_to_json,
     datetime_json_key,
     datetime_serialize
@@ -178,6 +288,8 @@ build_temporal_serializer!(
     DateSerializer,
     "date",
     downcast_date_reject_datetime,
+    TemporalMode,
+    temporal_mode_from_config,
     date_to_json,
     date_json_key,
     date_serialize
@@ -187,6 +299,8 @@ build_temporal_serializer!(
     TimeSerializer,
     "time",
     PyAnyMethods::downcast::<PyTime>,
+    TemporalMode,
+    temporal_mode_from_config,
     time_to_json,
     time_json_key,
     time_serialize
@@ -1,16 +1,18 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
+use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 
 use pyo3::IntoPyObjectExt;
 use serde::ser::SerializeMap;
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
-use crate::serializers::SerializationState;
-use crate::tools::SchemaDict;
+use crate::serializers::{downcast_dict, SerializationState};
+use crate::tools::{py_err, SchemaDict};
 
 use super::any::AnySerializer;
 use super::{
@@ -18,12 +20,37 @@ use super::{
     SchemaFilter, SerMode, TypeSerializer,
 };
 
+/// How keys which aren't strings are rendered as JSON object keys
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum KeyMode {
+    /// stringify the key with the key serializer
+    #[default]
+    Str,
+    /// raise an error for any key which isn't a string
+    Error,
+}
+
+impl KeyMode {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        match schema.get_as::<Bound<'_, PyString>>(intern!(py, "key_mode"))? {
+            None => Ok(Self::default()),
+            Some(mode) => match mode.to_str()? {
+                "str" => Ok(Self::Str),
+                "error" => Ok(Self::Error),
+                s => py_schema_err!("Invalid key_mode: {:?}, expected 'str' or 'error'", s),
+            },
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct DictSerializer {
     key_serializer: Arc<CombinedSerializer>,
     value_serializer: Arc<CombinedSerializer>,
     // isize because we look up include exclude via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
+    key_mode: KeyMode,
     name: String,
 }
 
@@ -62,6 +89,7 @@ impl BuildSerializer for DictSerializer {
             key_serializer,
             value_serializer,
             filter,
+            key_mode: KeyMode::from_schema(schema)?,
             name,
         })
         .into())
@@ -73,6 +101,20 @@ impl_py_gc_traverse!(DictSerializer {
     value_serializer
 });
 
+impl DictSerializer {
+    /// Render `key` as a JSON object key, respecting `key_mode`
+    fn json_key_str<'a, 'py>(
+        &self,
+        key: &'a Bound<'py, PyAny>,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> PyResult<Cow<'a, str>> {
+        if self.key_mode == KeyMode::Error && !key.is_instance_of::<PyString>() {
+            return py_err!(PyTypeError; "Dict key {} is not a string, and `key_mode` is 'error'", key.repr()?);
+        }
+        self.key_serializer.json_key(key, state)
+    }
+}
+
 impl TypeSerializer for DictSerializer {
     fn to_python<'py>(
         &self,
@@ -80,7 +122,7 @@ impl TypeSerializer for DictSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Py<PyAny>> {
         let py = value.py();
-        match value.downcast::<PyDict>() {
+        match downcast_dict(value) {
             Ok(py_dict) => {
                 let value_serializer = self.value_serializer.as_ref();
 
@@ -92,7 +134,7 @@ impl TypeSerializer for DictSerializer {
                             // disable include/exclude for keys
                             let state = &mut state.scoped_include_exclude(None, None);
                             match state.extra.mode {
-                                SerMode::Json => self.key_serializer.json_key(&key, state)?.into_py_any(py)?,
+                                SerMode::Json => self.json_key_str(&key, state)?.into_py_any(py)?,
                                 _ => self.key_serializer.to_python(&key, state)?,
                             }
                         };
@@ -124,17 +166,16 @@ impl TypeSerializer for DictSerializer {
         serializer: S,
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyDict>() {
+        match downcast_dict(value) {
             Ok(py_dict) => {
                 let mut map = serializer.serialize_map(Some(py_dict.len()))?;
-                let key_serializer = self.key_serializer.as_ref();
                 let value_serializer = self.value_serializer.as_ref();
 
                 for (key, value) in py_dict.iter() {
                     let op_next = self.filter.key_filter(&key, state).map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let state = &mut state.scoped_include_exclude(next_include, next_exclude);
-                        let key = key_serializer.json_key(&key, state).map_err(py_err_se_err)?;
+                        let key = self.json_key_str(&key, state).map_err(py_err_se_err)?;
                         let value_serialize = PydanticSerializer::new(&value, value_serializer, state);
                         map.serialize_entry(&key, &value_serialize)?;
                     }
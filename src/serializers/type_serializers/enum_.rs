@@ -21,6 +21,8 @@ use super::{BuildSerializer, CombinedSerializer, TypeSerializer};
 pub struct EnumSerializer {
     class: Py<PyType>,
     serializer: Option<Arc<CombinedSerializer>>,
+    // members mapped to the value they're serialized as in JSON mode, in place of `.value`
+    value_map: Option<Py<PyDict>>,
 }
 
 impl BuildSerializer for EnumSerializer {
@@ -43,12 +45,29 @@ impl BuildSerializer for EnumSerializer {
         Ok(CombinedSerializer::Enum(Self {
             class: schema.get_as_req(intern!(schema.py(), "cls"))?,
             serializer,
+            value_map: schema.get_as(intern!(schema.py(), "value_map"))?,
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(EnumSerializer { serializer });
+impl_py_gc_traverse!(EnumSerializer { serializer, value_map });
+
+impl EnumSerializer {
+    /// The value to serialize `member` as in JSON mode, and the serializer to use for it,
+    /// mapped values are arbitrary so they're always inferred
+    fn json_value<'py>(
+        &self,
+        member: &Bound<'py, PyAny>,
+    ) -> PyResult<(Bound<'py, PyAny>, Option<&Arc<CombinedSerializer>>)> {
+        if let Some(ref value_map) = self.value_map {
+            if let Some(mapped) = value_map.bind(member.py()).get_item(member)? {
+                return Ok((mapped, None));
+            }
+        }
+        Ok((member.getattr(intern!(member.py(), "value"))?, self.serializer.as_ref()))
+    }
+}
 
 impl TypeSerializer for EnumSerializer {
     fn to_python<'py>(
@@ -60,9 +79,9 @@ impl TypeSerializer for EnumSerializer {
         if value.is_exact_instance(self.class.bind(py)) {
             // if we're in JSON mode, we need to get the value attribute and serialize that
             if state.extra.mode.is_json() {
-                let dot_value = value.getattr(intern!(py, "value"))?;
-                match self.serializer {
-                    Some(ref s) => s.to_python(&dot_value, state),
+                let (dot_value, serializer) = self.json_value(value)?;
+                match serializer {
+                    Some(s) => s.to_python(&dot_value, state),
                     None => infer_to_python(&dot_value, state),
                 }
             } else {
@@ -82,9 +101,9 @@ impl TypeSerializer for EnumSerializer {
     ) -> PyResult<Cow<'a, str>> {
         let py = key.py();
         if key.is_exact_instance(self.class.bind(py)) {
-            let dot_value = key.getattr(intern!(py, "value"))?;
-            let k = match self.serializer {
-                Some(ref s) => s.json_key(&dot_value, state),
+            let (dot_value, serializer) = self.json_value(key)?;
+            let k = match serializer {
+                Some(s) => s.json_key(&dot_value, state),
                 None => infer_json_key(&dot_value, state),
             }?;
             // since dot_value is a local reference, we need to allocate it and returned an
@@ -103,9 +122,9 @@ impl TypeSerializer for EnumSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         if value.is_exact_instance(self.class.bind(value.py())) {
-            let dot_value = value.getattr(intern!(value.py(), "value")).map_err(py_err_se_err)?;
-            match self.serializer {
-                Some(ref s) => s.serde_serialize(&dot_value, serializer, state),
+            let (dot_value, value_serializer) = self.json_value(value).map_err(py_err_se_err)?;
+            match value_serializer {
+                Some(s) => s.serde_serialize(&dot_value, serializer, state),
                 None => infer_serialize(&dot_value, serializer, state),
             }
         } else {
@@ -22,39 +22,63 @@ use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 #[derive(Debug)]
 pub struct FloatSerializer {
     inf_nan_mode: InfNanMode,
+    round_to: Option<i32>,
 }
 
 static FLOAT_SERIALIZER_NULL: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(CombinedSerializer::Float(FloatSerializer {
         inf_nan_mode: InfNanMode::Null,
+        round_to: None,
     }))
 });
 
 static FLOAT_SERIALIZER_CONSTANTS: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(CombinedSerializer::Float(FloatSerializer {
         inf_nan_mode: InfNanMode::Constants,
+        round_to: None,
     }))
 });
 
 static FLOAT_SERIALIZER_STRINGS: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(CombinedSerializer::Float(FloatSerializer {
         inf_nan_mode: InfNanMode::Strings,
+        round_to: None,
     }))
 });
 
 impl FloatSerializer {
     pub fn get(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<&'static Arc<CombinedSerializer>> {
-        let inf_nan_mode = config
-            .and_then(|c| c.get_as(intern!(py, "ser_json_inf_nan")).transpose())
-            .transpose()?
-            .unwrap_or_default();
-
-        match inf_nan_mode {
+        match Self::inf_nan_mode(py, config)? {
             InfNanMode::Null => Ok(&FLOAT_SERIALIZER_NULL),
             InfNanMode::Constants => Ok(&FLOAT_SERIALIZER_CONSTANTS),
             InfNanMode::Strings => Ok(&FLOAT_SERIALIZER_STRINGS),
         }
     }
+
+    fn inf_nan_mode(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<InfNanMode> {
+        Ok(config
+            .and_then(|c| c.get_as(intern!(py, "ser_json_inf_nan")).transpose())
+            .transpose()?
+            .unwrap_or_default())
+    }
+
+    fn round(&self, v: f64) -> f64 {
+        match self.round_to {
+            Some(places) => round_f64(v, places),
+            None => v,
+        }
+    }
+}
+
+/// Round `v` to `places` decimal places, leaving it untouched if scaling would overflow.
+fn round_f64(v: f64, places: i32) -> f64 {
+    let factor = 10f64.powi(places);
+    let scaled = v * factor;
+    if scaled.is_finite() {
+        scaled.round() / factor
+    } else {
+        v
+    }
 }
 
 pub fn serialize_f64<S: Serializer>(v: f64, serializer: S, inf_nan_mode: InfNanMode) -> Result<S::Ok, S::Error> {
@@ -83,7 +107,14 @@ impl BuildSerializer for FloatSerializer {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
     ) -> PyResult<Arc<CombinedSerializer>> {
-        Self::get(schema.py(), config).cloned()
+        let py = schema.py();
+        match schema.get_as::<i32>(intern!(py, "round_to"))? {
+            Some(places) => Ok(Arc::new(CombinedSerializer::Float(Self {
+                inf_nan_mode: Self::inf_nan_mode(py, config)?,
+                round_to: Some(places),
+            }))),
+            None => Self::get(py, config).cloned(),
+        }
     }
 }
 
@@ -97,11 +128,12 @@ impl TypeSerializer for FloatSerializer {
     ) -> PyResult<Py<PyAny>> {
         let py = value.py();
         match state.extra.ob_type_lookup.is_type(value, ObType::Float) {
+            IsType::Exact if self.round_to.is_some() => self.round(value.extract::<f64>()?).into_py_any(py),
             IsType::Exact => Ok(value.clone().unbind()),
             IsType::Subclass => match state.check {
                 SerCheck::Strict => Err(PydanticSerializationUnexpectedValue::new_from_msg(None).to_py_err()),
                 SerCheck::Lax | SerCheck::None => match state.extra.mode {
-                    SerMode::Json => value.extract::<f64>()?.into_py_any(py),
+                    SerMode::Json => self.round(value.extract::<f64>()?).into_py_any(py),
                     _ => infer_to_python(value, state),
                 },
             },
@@ -133,7 +165,7 @@ impl TypeSerializer for FloatSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         match value.extract::<f64>() {
-            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode),
+            Ok(v) => serialize_f64(self.round(v), serializer, self.inf_nan_mode),
             Err(_) => {
                 state.warn_fallback_ser::<S>(self.get_name(), value)?;
                 infer_serialize(value, serializer, state)
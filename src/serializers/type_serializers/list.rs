@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 
 use pyo3::IntoPyObjectExt;
 use serde::ser::SerializeSeq;
@@ -11,6 +11,7 @@ use serde::ser::SerializeSeq;
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
+use crate::validators::list::get_deque_type;
 
 use super::any::AnySerializer;
 use super::{
@@ -21,6 +22,10 @@ use super::{
 #[derive(Debug)]
 pub struct ListSerializer {
     item_serializer: Arc<CombinedSerializer>,
+    // serializers for the first items by position, from `prefix_items`, `item_serializer` applies to the rest
+    prefix_serializers: Vec<Arc<CombinedSerializer>>,
+    // whether the list is validated to a `collections.deque` with `collection='deque'`
+    deque: bool,
     filter: SchemaFilter<usize>,
     name: String,
 }
@@ -38,10 +43,22 @@ impl BuildSerializer for ListSerializer {
             Some(items_schema) => CombinedSerializer::build(&items_schema, config, definitions)?,
             None => AnySerializer::build(schema, config, definitions)?,
         };
+        let prefix_serializers = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "prefix_items"))? {
+            Some(items) => items
+                .iter()
+                .map(|item| CombinedSerializer::build(item.downcast()?, config, definitions))
+                .collect::<PyResult<_>>()?,
+            None => Vec::new(),
+        };
+        let deque = schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "collection"))?
+            .is_some_and(|collection| collection == "deque");
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, item_serializer.get_name());
         Ok(Arc::new(
             Self {
                 item_serializer,
+                prefix_serializers,
+                deque,
                 filter: SchemaFilter::from_schema(schema)?,
                 name,
             }
@@ -50,7 +67,37 @@ impl BuildSerializer for ListSerializer {
     }
 }
 
-impl_py_gc_traverse!(ListSerializer { item_serializer });
+/// `value` as a list, including the tuple a list is validated to with `deep_freeze`, which is copied to a new list
+fn downcast_list<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyList>> {
+    match value.downcast_exact::<PyTuple>() {
+        Ok(tuple) => Ok(tuple.as_sequence().to_list()?),
+        Err(_) => Ok(value.downcast::<PyList>()?.clone()),
+    }
+}
+
+impl_py_gc_traverse!(ListSerializer {
+    item_serializer,
+    prefix_serializers
+});
+
+impl ListSerializer {
+    /// `value` as a list like `downcast_list`, including the `collections.deque` a list is validated to with
+    /// `collection='deque'`, which is copied to a new list
+    fn downcast<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyList>> {
+        if self.deque && value.is_instance(get_deque_type(value.py())?)? {
+            return PyList::new(value.py(), value.try_iter()?.collect::<PyResult<Vec<_>>>()?);
+        }
+        downcast_list(value)
+    }
+
+    /// The serializer for the item at `index`
+    fn item_serializer(&self, index: usize) -> &CombinedSerializer {
+        self.prefix_serializers
+            .get(index)
+            .unwrap_or(&self.item_serializer)
+            .as_ref()
+    }
+}
 
 impl TypeSerializer for ListSerializer {
     fn to_python<'py>(
@@ -58,19 +105,23 @@ impl TypeSerializer for ListSerializer {
         value: &Bound<'py, PyAny>,
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Py<PyAny>> {
-        match value.downcast::<PyList>() {
+        match self.downcast(value) {
             Ok(py_list) => {
                 let py = value.py();
-                let item_serializer = self.item_serializer.as_ref();
 
                 let mut items = Vec::with_capacity(py_list.len());
                 for (index, element) in py_list.iter().enumerate() {
                     let op_next = self.filter.index_filter(index, state, value.len().ok())?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let state = &mut state.scoped_include_exclude(next_include, next_exclude);
-                        items.push(item_serializer.to_python(&element, state)?);
+                        items.push(self.item_serializer(index).to_python(&element, state)?);
                     }
                 }
+                // in python mode a deque stays a deque, with its `maxlen`, like a list stays a list
+                if self.deque && !state.extra.mode.is_json() && value.is_instance(get_deque_type(py)?)? {
+                    let maxlen = value.getattr(intern!(py, "maxlen"))?;
+                    return Ok(get_deque_type(py)?.call1((items, maxlen))?.unbind());
+                }
                 items.into_py_any(py)
             }
             Err(_) => {
@@ -94,10 +145,9 @@ impl TypeSerializer for ListSerializer {
         serializer: S,
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyList>() {
+        match self.downcast(value) {
             Ok(py_list) => {
                 let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
-                let item_serializer = self.item_serializer.as_ref();
 
                 for (index, element) in py_list.iter().enumerate() {
                     let op_next = self
@@ -106,7 +156,7 @@ impl TypeSerializer for ListSerializer {
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let state = &mut state.scoped_include_exclude(next_include, next_exclude);
-                        let item_serialize = PydanticSerializer::new(&element, item_serializer, state);
+                        let item_serialize = PydanticSerializer::new(&element, self.item_serializer(index), state);
                         seq.serialize_element(&item_serialize)?;
                     }
                 }
@@ -125,5 +175,9 @@ impl TypeSerializer for ListSerializer {
 
     fn retry_with_lax_check(&self) -> bool {
         self.item_serializer.retry_with_lax_check()
+            || self
+                .prefix_serializers
+                .iter()
+                .any(|serializer| serializer.retry_with_lax_check())
     }
 }
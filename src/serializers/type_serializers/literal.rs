@@ -24,6 +24,8 @@ pub struct LiteralSerializer {
     expected_int: AHashSet<i64>,
     expected_str: AHashSet<String>,
     expected_py: Option<Py<PyList>>,
+    // check values are expected even outside of unions, warning about those that aren't
+    check_serialization: bool,
     name: String,
 }
 
@@ -66,6 +68,7 @@ impl BuildSerializer for LiteralSerializer {
                     true => None,
                     false => Some(expected_py.into()),
                 },
+                check_serialization: schema.get_as(intern!(py, "check_serialization"))?.unwrap_or(false),
                 name: format!("{}[{}]", Self::EXPECTED_TYPE, repr_args.join(",")),
             }
             .into(),
@@ -82,7 +85,7 @@ enum OutputValue<'py> {
 
 impl LiteralSerializer {
     fn check<'py>(&self, value: &Bound<'py, PyAny>, state: &SerializationState<'_, 'py>) -> PyResult<OutputValue<'py>> {
-        if state.check.enabled() {
+        if state.check.enabled() || self.check_serialization {
             if !self.expected_int.is_empty() && !value.is_instance_of::<PyBool>() {
                 if let Some(int) = extract_i64(value) {
                     if self.expected_int.contains(&int) {
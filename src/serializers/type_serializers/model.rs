@@ -3,14 +3,14 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PySet, PyString, PyType};
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyType};
 
 use ahash::AHashMap;
 use pyo3::IntoPyObjectExt;
 
 use super::{
-    infer_json_key, infer_json_key_known, BuildSerializer, CombinedSerializer, ComputedFields, Extra, FieldsMode,
-    GeneralFieldsSerializer, ObType, SerCheck, SerField, TypeSerializer,
+    has_allowed_extra_keys, infer_json_key, infer_json_key_known, BuildSerializer, CombinedSerializer, ComputedFields,
+    Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField, TypeSerializer,
 };
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{py_schema_error_type, ExtraBehavior};
@@ -39,7 +39,7 @@ impl BuildSerializer for ModelFieldsBuilder {
     ) -> PyResult<Arc<CombinedSerializer>> {
         let py = schema.py();
 
-        let fields_mode = match has_extra(schema, config)? {
+        let fields_mode = match has_extra(schema, schema, config)? {
             true => FieldsMode::ModelExtra,
             false => FieldsMode::SimpleDict,
         };
@@ -75,18 +75,17 @@ impl BuildSerializer for ModelFieldsBuilder {
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
 
-                fields.insert(
-                    key,
-                    SerField::new(
-                        py,
-                        key_py,
-                        alias,
-                        Some(serializer),
-                        true,
-                        serialize_by_alias,
-                        serialization_exclude_if,
-                    ),
+                let mut field = SerField::new(
+                    py,
+                    key_py,
+                    alias,
+                    Some(serializer),
+                    true,
+                    serialize_by_alias,
+                    serialization_exclude_if,
                 );
+                field.flatten_prefix = field_info.get_as(intern!(py, "serialization_flatten_prefix"))?;
+                fields.insert(key, field);
             }
         }
 
@@ -105,6 +104,10 @@ pub struct ModelSerializer {
     has_extra: bool,
     root_model: bool,
     name: String,
+    // the attribute extras are stored under, `__pydantic_extra__` unless set with `extra_field_name`
+    extra_field_name: Py<PyString>,
+    // the model has `lazy_fields`, whose pending values are resolved before serializing
+    has_lazy_fields: bool,
 }
 
 impl BuildSerializer for ModelSerializer {
@@ -125,22 +128,38 @@ impl BuildSerializer for ModelSerializer {
         let serializer = CombinedSerializer::build(&sub_schema, config.as_ref(), definitions)?;
         let root_model = schema.get_as(intern!(py, "root_model"))?.unwrap_or(false);
         let name = class.bind(py).getattr(intern!(py, "__name__"))?.extract()?;
+        let extra_field_name = schema
+            .get_as(intern!(py, "extra_field_name"))?
+            .unwrap_or_else(|| intern!(py, "__pydantic_extra__").clone().unbind());
+        let has_lazy_fields = schema
+            .get_as::<Bound<'_, PyList>>(intern!(py, "lazy_fields"))?
+            .is_some_and(|names| !names.is_empty());
 
         Ok(CombinedSerializer::Model(Self {
             class,
             serializer,
-            has_extra: has_extra(schema, config.as_ref())?,
+            has_extra: has_extra(schema, &sub_schema, config.as_ref())?,
             root_model,
             name,
+            extra_field_name,
+            has_lazy_fields,
         })
         .into())
     }
 }
 
-fn has_extra(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<bool> {
+/// Whether the model stores extras, `fields_schema` is the model fields schema which may allowlist extra keys
+fn has_extra(
+    schema: &Bound<'_, PyDict>,
+    fields_schema: &Bound<'_, PyDict>,
+    config: Option<&Bound<'_, PyDict>>,
+) -> PyResult<bool> {
     let py = schema.py();
-    let extra_behaviour = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
-    Ok(matches!(extra_behaviour, ExtraBehavior::Allow))
+    match ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)? {
+        ExtraBehavior::Allow => Ok(true),
+        ExtraBehavior::Forbid => has_allowed_extra_keys(fields_schema),
+        ExtraBehavior::Ignore => Ok(false),
+    }
 }
 
 impl ModelSerializer {
@@ -222,6 +241,14 @@ impl ModelSerializer {
 
     fn get_inner_value<'py>(&self, model: &Bound<'py, PyAny>, extra: &Extra) -> PyResult<Bound<'py, PyAny>> {
         let py: Python<'_> = model.py();
+        if self.has_lazy_fields {
+            // accessing each pending field has the model validate it and store the result in `__dict__`
+            if let Ok(pending) = model.getattr(intern!(py, "__pydantic_lazy__")) {
+                for field_name in pending.downcast::<PyDict>()?.keys() {
+                    model.getattr(field_name.downcast::<PyString>()?)?;
+                }
+            }
+        }
         let mut attrs = model.getattr(intern!(py, "__dict__"))?.downcast_into::<PyDict>()?;
 
         if extra.exclude_unset {
@@ -239,7 +266,7 @@ impl ModelSerializer {
         }
 
         if self.has_extra {
-            let model_extra = model.getattr(intern!(py, "__pydantic_extra__"))?;
+            let model_extra = model.getattr(self.extra_field_name.bind(py))?;
             (attrs, model_extra).into_bound_py_any(py)
         } else {
             Ok(attrs.into_any())
@@ -17,7 +17,7 @@ use super::{
 };
 
 macro_rules! build_serializer {
-    ($struct_name:ident, $expected_type:literal, $py_type:ty) => {
+    ($struct_name:ident, $expected_type:literal, $py_type:ty, $downcast:path) => {
         #[derive(Debug)]
         pub struct $struct_name {
             item_serializer: Arc<CombinedSerializer>,
@@ -57,7 +57,7 @@ macro_rules! build_serializer {
                 state: &mut SerializationState<'_, 'py>,
             ) -> PyResult<Py<PyAny>> {
                 let py = value.py();
-                match value.downcast::<$py_type>() {
+                match $downcast(value) {
                     Ok(py_set) => {
                         let item_serializer = self.item_serializer.as_ref();
 
@@ -91,7 +91,7 @@ macro_rules! build_serializer {
                 serializer: S,
                 state: &mut SerializationState<'_, 'py>,
             ) -> Result<S::Ok, S::Error> {
-                match value.downcast::<$py_type>() {
+                match $downcast(value) {
                     Ok(py_set) => {
                         let mut seq = serializer.serialize_seq(Some(py_set.len()))?;
                         let item_serializer = self.item_serializer.as_ref();
@@ -116,5 +116,17 @@ macro_rules! build_serializer {
     };
 }
 
-build_serializer!(SetSerializer, "set", PySet);
-build_serializer!(FrozenSetSerializer, "frozenset", PyFrozenSet);
+/// `value` as a set, including the frozenset a set is validated to with `deep_freeze`, which is copied to a new set
+fn downcast_set<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PySet>> {
+    match value.downcast_exact::<PyFrozenSet>() {
+        Ok(frozenset) => Ok(PySet::new(value.py(), frozenset)?),
+        Err(_) => Ok(value.downcast::<PySet>()?.clone()),
+    }
+}
+
+fn downcast_frozenset<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyFrozenSet>> {
+    Ok(value.downcast::<PyFrozenSet>()?.clone())
+}
+
+build_serializer!(SetSerializer, "set", PySet, downcast_set);
+build_serializer!(FrozenSetSerializer, "frozenset", PyFrozenSet, downcast_frozenset);
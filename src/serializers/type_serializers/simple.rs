@@ -1,3 +1,4 @@
+use num_bigint::BigUint;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use pyo3::IntoPyObjectExt;
@@ -8,6 +9,7 @@ use std::sync::Arc;
 use serde::Serialize;
 
 use crate::build_tools::LazyLock;
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 use crate::{definitions::DefinitionsBuilder, input::Int};
 
@@ -93,26 +95,66 @@ impl TypeSerializer for NoneSerializer {
 }
 
 macro_rules! build_simple_serializer {
-    ($struct_name:ident, $expected_type:literal, $rust_type:ty, $ob_type:expr, $key_method:ident, $subtypes_allowed:expr) => {
+    (
+        $struct_name:ident, $expected_type:literal, $rust_type:ty, $ob_type:expr, $key_method:ident, $subtypes_allowed:expr
+        $(, $threshold_field:ident => $as_string:ident)?
+    ) => {
         #[derive(Debug)]
-        pub struct $struct_name;
+        pub struct $struct_name {
+            $(
+                // in JSON, values with a magnitude above this are serialized as strings
+                $threshold_field: Option<u64>,
+            )?
+        }
 
         impl $struct_name {
             pub fn get() -> &'static std::sync::Arc<CombinedSerializer> {
                 static INSTANCE: $crate::build_tools::LazyLock<std::sync::Arc<CombinedSerializer>> =
-                    $crate::build_tools::LazyLock::new(|| std::sync::Arc::new($struct_name.into()));
+                    $crate::build_tools::LazyLock::new(|| {
+                        std::sync::Arc::new($struct_name { $($threshold_field: None)? }.into())
+                    });
                 &INSTANCE
             }
+
+            /// The value as a string, if it should be serialized as one in JSON
+            #[allow(unused_variables)]
+            fn json_string(&self, value: &$rust_type) -> Option<String> {
+                $(
+                    if let Some(threshold) = self.$threshold_field {
+                        return $as_string(value, threshold);
+                    }
+                )?
+                None
+            }
+
+            fn json_to_python(&self, value: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+                let v = value.extract::<$rust_type>()?;
+                match self.json_string(&v) {
+                    Some(s) => s.into_py_any(value.py()),
+                    None => v.into_py_any(value.py()),
+                }
+            }
         }
 
         impl BuildSerializer for $struct_name {
             const EXPECTED_TYPE: &'static str = $expected_type;
 
+            #[allow(unused_variables)]
             fn build(
-                _schema: &Bound<'_, PyDict>,
+                schema: &Bound<'_, PyDict>,
                 _config: Option<&Bound<'_, PyDict>>,
                 _definitions: &mut DefinitionsBuilder<std::sync::Arc<CombinedSerializer>>,
             ) -> PyResult<std::sync::Arc<CombinedSerializer>> {
+                $(
+                    if let Some(threshold) = schema.get_as(pyo3::intern!(schema.py(), stringify!($threshold_field)))? {
+                        return Ok(std::sync::Arc::new(
+                            $struct_name {
+                                $threshold_field: Some(threshold),
+                            }
+                            .into(),
+                        ));
+                    }
+                )?
                 Ok(Self::get().clone())
             }
         }
@@ -125,13 +167,15 @@ macro_rules! build_simple_serializer {
                 value: &Bound<'py, PyAny>,
                 state: &mut SerializationState<'_, 'py>,
             ) -> PyResult<Py<PyAny>> {
-                let py = value.py();
                 match state.extra.ob_type_lookup.is_type(value, $ob_type) {
-                    IsType::Exact => Ok(value.clone().unbind()),
+                    IsType::Exact => match state.extra.mode {
+                        $(SerMode::Json if self.$threshold_field.is_some() => self.json_to_python(value),)?
+                        _ => Ok(value.clone().unbind()),
+                    },
                     IsType::Subclass => match state.check {
                         SerCheck::Strict => Err(PydanticSerializationUnexpectedValue::new_from_msg(None).to_py_err()),
                         SerCheck::Lax | SerCheck::None => match state.extra.mode {
-                            SerMode::Json => value.extract::<$rust_type>()?.into_py_any(py),
+                            SerMode::Json => self.json_to_python(value),
                             _ => infer_to_python(value, state),
                         },
                     },
@@ -163,7 +207,10 @@ macro_rules! build_simple_serializer {
                 state: &mut SerializationState<'_, 'py>,
             ) -> Result<S::Ok, S::Error> {
                 match value.extract::<$rust_type>() {
-                    Ok(v) => v.serialize(serializer),
+                    Ok(v) => match self.json_string(&v) {
+                        Some(s) => serializer.serialize_str(&s),
+                        None => v.serialize(serializer),
+                    },
                     Err(_) => {
                         state.warn_fallback_ser::<S>(self.get_name(), value)?;
                         infer_serialize(value, serializer, state)
@@ -186,7 +233,26 @@ pub(crate) fn to_str_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a,
     Ok(Cow::Owned(key.str()?.to_string_lossy().into_owned()))
 }
 
-build_simple_serializer!(IntSerializer, "int", Int, ObType::Int, to_str_json_key, true);
+/// `int` as a string if its magnitude is above `threshold`
+fn int_string_above(int: &Int, threshold: u64) -> Option<String> {
+    match int {
+        Int::I64(i) if i.unsigned_abs() <= threshold => None,
+        Int::I64(i) => Some(i.to_string()),
+        // big ints are beyond the range of an `i64`, but a threshold above `i64::MAX` may still exceed them
+        Int::Big(b) if *b.magnitude() <= BigUint::from(threshold) => None,
+        Int::Big(b) => Some(b.to_string()),
+    }
+}
+
+build_simple_serializer!(
+    IntSerializer,
+    "int",
+    Int,
+    ObType::Int,
+    to_str_json_key,
+    true,
+    int_as_string_threshold => int_string_above
+);
 
 pub(crate) fn bool_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a, str>> {
     let v = if key.is_truthy().unwrap_or(false) {
@@ -1,13 +1,16 @@
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::IntoPyObjectExt;
 use std::borrow::Cow;
 use std::sync::Arc;
 
 use crate::definitions::DefinitionsBuilder;
 use crate::input::EitherTimedelta;
 use crate::serializers::config::{FromConfig, TemporalMode, TimedeltaMode};
+use crate::serializers::errors::py_err_se_err;
 use crate::serializers::SerializationState;
+use crate::tools::SchemaDict;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, SerMode, TypeSerializer,
@@ -16,13 +19,15 @@ use super::{
 #[derive(Debug)]
 pub struct TimeDeltaSerializer {
     temporal_mode: TemporalMode,
+    // when serializing as seconds, serialize timedeltas with no fractional seconds as an int
+    seconds_int: bool,
 }
 
 impl BuildSerializer for TimeDeltaSerializer {
     const EXPECTED_TYPE: &'static str = "timedelta";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
     ) -> PyResult<Arc<CombinedSerializer>> {
@@ -36,12 +41,37 @@ impl BuildSerializer for TimeDeltaSerializer {
             td_mode.into()
         };
 
-        Ok(Arc::new(Self { temporal_mode }.into()))
+        let seconds_int = schema
+            .get_as(intern!(schema.py(), "timedelta_seconds_int"))?
+            .unwrap_or(false);
+
+        Ok(Arc::new(
+            Self {
+                temporal_mode,
+                seconds_int,
+            }
+            .into(),
+        ))
     }
 }
 
 impl_py_gc_traverse!(TimeDeltaSerializer {});
 
+impl TimeDeltaSerializer {
+    /// The total seconds of the timedelta if it should be serialized as an int, see `seconds_int`
+    fn whole_seconds(&self, either_timedelta: &EitherTimedelta) -> PyResult<Option<i64>> {
+        if !self.seconds_int || !matches!(self.temporal_mode, TemporalMode::Seconds) {
+            return Ok(None);
+        }
+        let duration = either_timedelta.to_duration()?;
+        if duration.microsecond != 0 {
+            return Ok(None);
+        }
+        let seconds = i64::from(duration.day) * 86_400 + i64::from(duration.second);
+        Ok(Some(if duration.positive { seconds } else { -seconds }))
+    }
+}
+
 impl TypeSerializer for TimeDeltaSerializer {
     fn to_python<'py>(
         &self,
@@ -50,7 +80,10 @@ impl TypeSerializer for TimeDeltaSerializer {
     ) -> PyResult<Py<PyAny>> {
         match EitherTimedelta::try_from(value) {
             Ok(either_timedelta) => match state.extra.mode {
-                SerMode::Json => Ok(self.temporal_mode.timedelta_to_json(value.py(), either_timedelta)?),
+                SerMode::Json => match self.whole_seconds(&either_timedelta)? {
+                    Some(seconds) => seconds.into_py_any(value.py()),
+                    None => self.temporal_mode.timedelta_to_json(value.py(), either_timedelta),
+                },
                 _ => Ok(value.clone().unbind()),
             },
             _ => {
@@ -66,7 +99,10 @@ impl TypeSerializer for TimeDeltaSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Cow<'a, str>> {
         match EitherTimedelta::try_from(key) {
-            Ok(either_timedelta) => self.temporal_mode.timedelta_json_key(&either_timedelta),
+            Ok(either_timedelta) => match self.whole_seconds(&either_timedelta)? {
+                Some(seconds) => Ok(seconds.to_string().into()),
+                None => self.temporal_mode.timedelta_json_key(&either_timedelta),
+            },
             Err(_) => {
                 state.warn_fallback_py(self.get_name(), key)?;
                 infer_json_key(key, state)
@@ -81,7 +117,10 @@ impl TypeSerializer for TimeDeltaSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         match EitherTimedelta::try_from(value) {
-            Ok(either_timedelta) => self.temporal_mode.timedelta_serialize(either_timedelta, serializer),
+            Ok(either_timedelta) => match self.whole_seconds(&either_timedelta).map_err(py_err_se_err)? {
+                Some(seconds) => serializer.serialize_i64(seconds),
+                None => self.temporal_mode.timedelta_serialize(either_timedelta, serializer),
+            },
             Err(_) => {
                 state.warn_fallback_ser::<S>(self.get_name(), value)?;
                 infer_serialize(value, serializer, state)
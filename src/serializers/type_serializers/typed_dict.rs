@@ -14,7 +14,10 @@ use crate::serializers::shared::TypeSerializer;
 use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
 
-use super::{BuildSerializer, CombinedSerializer, ComputedFields, FieldsMode, GeneralFieldsSerializer, SerField};
+use super::{
+    has_allowed_extra_keys, BuildSerializer, CombinedSerializer, ComputedFields, FieldsMode, GeneralFieldsSerializer,
+    SerField,
+};
 
 #[derive(Debug)]
 pub struct TypedDictSerializer {
@@ -38,6 +41,7 @@ impl BuildSerializer for TypedDictSerializer {
 
         let fields_mode = match ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)? {
             ExtraBehavior::Allow => FieldsMode::TypedDictAllow,
+            ExtraBehavior::Forbid if has_allowed_extra_keys(schema)? => FieldsMode::TypedDictAllow,
             _ => FieldsMode::SimpleDict,
         };
 
@@ -1,17 +1,18 @@
-use ahash::AHashMap as HashMap;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 use smallvec::SmallVec;
 use std::borrow::Cow;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::build_tools::py_schema_err;
-use crate::common::union::{Discriminator, SMALL_UNION_THRESHOLD};
+use crate::common::union::{Discriminator, UnionMode, SMALL_UNION_THRESHOLD};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::PydanticSerializationUnexpectedValue;
 use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
+use crate::validators::LiteralLookup;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, SerCheck, TypeSerializer,
@@ -19,6 +20,7 @@ use super::{
 
 #[derive(Debug)]
 pub struct UnionSerializer {
+    mode: UnionMode,
     choices: Vec<Arc<CombinedSerializer>>,
     name: String,
 }
@@ -43,19 +45,23 @@ impl BuildSerializer for UnionSerializer {
                 CombinedSerializer::build(choice.downcast()?, config, definitions)
             })
             .collect::<PyResult<_>>()?;
+        let mode = schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "union_serialization"))?
+            .map_or(Ok(UnionMode::Smart), |mode| mode.to_str().and_then(UnionMode::from_str))?;
 
-        Self::from_choices(choices)
+        Self::from_choices(mode, choices)
     }
 }
 
 impl UnionSerializer {
-    fn from_choices(choices: Vec<Arc<CombinedSerializer>>) -> PyResult<Arc<CombinedSerializer>> {
+    fn from_choices(mode: UnionMode, choices: Vec<Arc<CombinedSerializer>>) -> PyResult<Arc<CombinedSerializer>> {
         match choices.len() {
             0 => py_schema_err!("One or more union choices required"),
             1 => Ok(choices.into_iter().next().unwrap()),
             _ => {
                 let descr = choices.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(", ");
                 Ok(CombinedSerializer::Union(Self {
+                    mode,
                     choices,
                     name: format!("Union[{descr}]"),
                 })
@@ -74,14 +80,23 @@ fn union_serialize<'py, S>(
     mut selector: impl FnMut(&CombinedSerializer, &mut SerializationState<'_, 'py>) -> PyResult<S>,
     state: &mut SerializationState<'_, 'py>,
     choices: &[Arc<CombinedSerializer>],
+    mode: UnionMode,
     retry_with_lax_check: bool,
     py: Python<'_>,
 ) -> PyResult<Option<S>> {
-    // try the serializers in left to right order with strict checking
+    // If state.check is SerCheck::Strict, we're in a nested union
+    let lax_allowed = state.check != SerCheck::Strict && retry_with_lax_check;
+
+    // in smart mode, try the serializers in left to right order with strict checking first, so the choice
+    // which exactly matches the value wins, in left to right mode, the first choice which accepts the value wins
+    let first_check = match mode {
+        UnionMode::LeftToRight if lax_allowed => SerCheck::Lax,
+        _ => SerCheck::Strict,
+    };
     let mut errors: SmallVec<[PyErr; SMALL_UNION_THRESHOLD]> = SmallVec::new();
 
     {
-        let state = &mut state.scoped_set(|s| &mut s.check, SerCheck::Strict);
+        let state = &mut state.scoped_set(|s| &mut s.check, first_check);
         for comb_serializer in choices {
             match selector(comb_serializer, state) {
                 Ok(v) => return Ok(Some(v)),
@@ -90,8 +105,7 @@ fn union_serialize<'py, S>(
         }
     }
 
-    // If state.check is SerCheck::Strict, we're in a nested union
-    if state.check != SerCheck::Strict && retry_with_lax_check {
+    if first_check == SerCheck::Strict && lax_allowed {
         let state = &mut state.scoped_set(|s| &mut s.check, SerCheck::Lax);
         for comb_serializer in choices {
             if let Ok(v) = selector(comb_serializer, state) {
@@ -135,6 +149,7 @@ impl TypeSerializer for UnionSerializer {
             |comb_serializer, state| comb_serializer.to_python(value, state),
             state,
             &self.choices,
+            self.mode,
             self.retry_with_lax_check(),
             value.py(),
         )?
@@ -150,6 +165,7 @@ impl TypeSerializer for UnionSerializer {
             |comb_serializer, state| comb_serializer.json_key(key, state),
             state,
             &self.choices,
+            self.mode,
             self.retry_with_lax_check(),
             key.py(),
         )?
@@ -166,6 +182,7 @@ impl TypeSerializer for UnionSerializer {
             |comb_serializer, state| comb_serializer.to_python(value, state),
             state,
             &self.choices,
+            self.mode,
             self.retry_with_lax_check(),
             value.py(),
         ) {
@@ -190,8 +207,7 @@ impl TypeSerializer for UnionSerializer {
 #[derive(Debug)]
 pub struct TaggedUnionSerializer {
     discriminator: Discriminator,
-    lookup: HashMap<String, usize>,
-    choices: Vec<Arc<CombinedSerializer>>,
+    lookup: LiteralLookup<Arc<CombinedSerializer>>,
     name: String,
 }
 
@@ -208,28 +224,33 @@ impl BuildSerializer for TaggedUnionSerializer {
 
         // TODO: guarantee at least 1 choice
         let choices_map: Bound<PyDict> = schema.get_as_req(intern!(py, "choices"))?;
-        let mut lookup = HashMap::with_capacity(choices_map.len());
-        let mut choices = Vec::with_capacity(choices_map.len());
+        let mut lookup_map = Vec::with_capacity(choices_map.len());
 
-        for (idx, (choice_key, choice_schema)) in choices_map.into_iter().enumerate() {
+        for (choice_key, choice_schema) in choices_map {
             let serializer = CombinedSerializer::build(choice_schema.downcast()?, config, definitions)?;
-            choices.push(serializer);
-            lookup.insert(choice_key.to_string(), idx);
+            lookup_map.push((choice_key, serializer));
         }
 
-        let descr = choices.iter().map(|s| s.get_name()).collect::<Vec<_>>().join(", ");
+        // use the same lookup as tagged union validation, so tags are matched by value rather than by
+        // their string representation, e.g. `MyEnum.a` matches the choice `'a'` for a `str` enum
+        let lookup = LiteralLookup::new(py, lookup_map.into_iter())?;
+        let descr = lookup
+            .values
+            .iter()
+            .map(|s| s.get_name())
+            .collect::<Vec<_>>()
+            .join(", ");
 
         Ok(CombinedSerializer::TaggedUnion(Self {
             discriminator,
             lookup,
-            choices,
             name: format!("TaggedUnion[{descr}]"),
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(TaggedUnionSerializer { discriminator, choices });
+impl_py_gc_traverse!(TaggedUnionSerializer { discriminator, lookup });
 
 impl TypeSerializer for TaggedUnionSerializer {
     fn to_python<'py>(
@@ -283,7 +304,7 @@ impl TypeSerializer for TaggedUnionSerializer {
     }
 
     fn retry_with_lax_check(&self) -> bool {
-        self.choices.iter().any(|c| c.retry_with_lax_check())
+        self.lookup.values.iter().any(|c| c.retry_with_lax_check())
     }
 }
 
@@ -317,10 +338,7 @@ impl TaggedUnionSerializer {
         if let Some(tag) = self.get_discriminator_value(value) {
             let state = &mut state.scoped_set(|s| &mut s.check, SerCheck::Strict);
 
-            let tag_str = tag.to_string();
-            if let Some(&serializer_index) = self.lookup.get(&tag_str) {
-                let selected_serializer = &self.choices[serializer_index];
-
+            if let Ok(Some((_, selected_serializer))) = self.lookup.validate(value.py(), &tag) {
                 match selector(selected_serializer, state) {
                     Ok(v) => return Ok(Some(v)),
                     Err(_) => {
@@ -349,6 +367,13 @@ impl TaggedUnionSerializer {
         // if we haven't returned at this point, we should fallback to the union serializer
         // which preserves the historical expectation that we do our best with serialization
         // even if that means we resort to inference
-        union_serialize(selector, state, &self.choices, self.retry_with_lax_check(), value.py())
+        union_serialize(
+            selector,
+            state,
+            &self.lookup.values,
+            UnionMode::Smart,
+            self.retry_with_lax_check(),
+            value.py(),
+        )
     }
 }
@@ -192,6 +192,22 @@ pub fn write_truncated_to_limited_bytes<F: fmt::Write>(f: &mut F, val: &str, max
     }
 }
 
+/// Levenshtein edit distance between two strings, counted in chars
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b_chars.len()]
+}
+
 /// Implementation of `mapping.get(key, PydanticUndefined)` which returns `None` if the key is not found
 pub fn mapping_get<'py>(
     mapping: &Bound<'py, PyMapping>,
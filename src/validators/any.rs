@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyFrozenSet, PyList, PyMappingProxy, PySet, PyTuple};
 
 use crate::input::Input;
 use crate::{build_tools::LazyLock, errors::ValResult};
@@ -39,10 +39,44 @@ impl Validator for AnyValidator {
     ) -> ValResult<Py<PyAny>> {
         // in a union, Any should be preferred to doing lax coercions
         state.floor_exactness(Exactness::Strict);
-        Ok(input.to_object(py)?.unbind())
+        let output = state.input_to_object(py, input)?;
+        if state.extra().deep_freeze {
+            return Ok(deep_freeze(&output)?.unbind());
+        }
+        Ok(output.unbind())
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
+
+/// `value` with every list, set and dict within it replaced by a tuple, frozenset or read-only `mappingproxy`,
+/// used with `deep_freeze` for values which aren't validated by a collection validator
+pub(crate) fn deep_freeze<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    if let Ok(list) = value.downcast_exact::<PyList>() {
+        let items = list
+            .iter()
+            .map(|item| deep_freeze(&item))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyTuple::new(py, items)?.into_any())
+    } else if let Ok(tuple) = value.downcast_exact::<PyTuple>() {
+        let items = tuple
+            .iter()
+            .map(|item| deep_freeze(&item))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyTuple::new(py, items)?.into_any())
+    } else if let Ok(set) = value.downcast_exact::<PySet>() {
+        // set members are hashable so can't themselves be mutable containers
+        Ok(PyFrozenSet::new(py, set)?.into_any())
+    } else if let Ok(dict) = value.downcast_exact::<PyDict>() {
+        let frozen = PyDict::new(py);
+        for (key, value) in dict {
+            frozen.set_item(key, deep_freeze(&value)?)?;
+        }
+        Ok(PyMappingProxy::new(py, frozen.as_mapping()).into_any())
+    } else {
+        Ok(value.clone())
+    }
+}
@@ -719,7 +719,7 @@ impl ArgumentsV3Validator {
                                         // Save to the remaining kwargs, we will validate as a single dict:
                                         remaining_kwargs.set_item(
                                             either_str.as_py_string(py, state.cache_str()),
-                                            value.borrow_input().to_object(py)?,
+                                            state.input_to_object(py, value.borrow_input())?,
                                         )?;
                                     }
                                     _ => unreachable!(),
@@ -1,24 +1,41 @@
 use std::sync::Arc;
 
 use pyo3::types::PyDict;
-use pyo3::{prelude::*, IntoPyObjectExt};
+use pyo3::{intern, prelude::*, IntoPyObjectExt};
 
 use crate::build_tools::{is_strict, LazyLock};
-use crate::errors::ValResult;
-use crate::input::Input;
+use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{str_as_bool, Input, ValidationMatch};
+use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct BoolValidator {
     strict: bool,
+    // when set, decides whether strings are coerced regardless of strictness
+    str_coercion: Option<bool>,
 }
 
-static STRICT_BOOL_VALIDATOR: LazyLock<Arc<CombinedValidator>> =
-    LazyLock::new(|| Arc::new(BoolValidator { strict: true }.into()));
+static STRICT_BOOL_VALIDATOR: LazyLock<Arc<CombinedValidator>> = LazyLock::new(|| {
+    Arc::new(
+        BoolValidator {
+            strict: true,
+            str_coercion: None,
+        }
+        .into(),
+    )
+});
 
-static LAX_BOOL_VALIDATOR: LazyLock<Arc<CombinedValidator>> =
-    LazyLock::new(|| Arc::new(BoolValidator { strict: false }.into()));
+static LAX_BOOL_VALIDATOR: LazyLock<Arc<CombinedValidator>> = LazyLock::new(|| {
+    Arc::new(
+        BoolValidator {
+            strict: false,
+            str_coercion: None,
+        }
+        .into(),
+    )
+});
 
 impl BuildValidator for BoolValidator {
     const EXPECTED_TYPE: &'static str = "bool";
@@ -28,10 +45,17 @@ impl BuildValidator for BoolValidator {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
     ) -> PyResult<Arc<CombinedValidator>> {
-        if is_strict(schema, config)? {
-            Ok(STRICT_BOOL_VALIDATOR.clone())
-        } else {
-            Ok(LAX_BOOL_VALIDATOR.clone())
+        let strict = is_strict(schema, config)?;
+        match schema.get_as(intern!(schema.py(), "str_coercion"))? {
+            Some(str_coercion) => Ok(Arc::new(
+                Self {
+                    strict,
+                    str_coercion: Some(str_coercion),
+                }
+                .into(),
+            )),
+            None if strict => Ok(STRICT_BOOL_VALIDATOR.clone()),
+            None => Ok(LAX_BOOL_VALIDATOR.clone()),
         }
     }
 }
@@ -45,6 +69,15 @@ impl Validator for BoolValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if let Some(str_coercion) = self.str_coercion {
+            if let Ok(either_str) = input.exact_str() {
+                if !str_coercion {
+                    return Err(ValError::new(ErrorTypeDefaults::BoolType, input));
+                }
+                let val_match = str_as_bool(input, either_str.as_cow()?.as_ref()).map(ValidationMatch::lax)?;
+                return Ok(val_match.unpack(state).into_py_any(py)?);
+            }
+        }
         // TODO in theory this could be quicker if we used PyBool rather than going to a bool
         // and back again, might be worth profiling?
         input
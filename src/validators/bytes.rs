@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyByteArray, PyDict};
 use pyo3::IntoPyObjectExt;
 
 use crate::build_tools::is_strict;
@@ -18,6 +18,8 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 pub struct BytesValidator {
     strict: bool,
     bytes_mode: ValBytesMode,
+    keep_bytearray: bool,
+    require_utf8: bool,
 }
 
 impl BuildValidator for BytesValidator {
@@ -30,13 +32,16 @@ impl BuildValidator for BytesValidator {
     ) -> PyResult<Arc<CombinedValidator>> {
         let py = schema.py();
         let use_constrained = schema.get_item(intern!(py, "max_length"))?.is_some()
-            || schema.get_item(intern!(py, "min_length"))?.is_some();
+            || schema.get_item(intern!(py, "min_length"))?.is_some()
+            || schema.get_item(intern!(py, "encoded_max_length"))?.is_some();
         if use_constrained {
             BytesConstrainedValidator::build(schema, config)
         } else {
             Ok(CombinedValidator::Bytes(Self {
                 strict: is_strict(schema, config)?,
                 bytes_mode: ValBytesMode::from_config(config)?,
+                keep_bytearray: schema.get_as(intern!(py, "keep_bytearray"))?.unwrap_or(false),
+                require_utf8: schema.get_as(intern!(py, "require_utf8"))?.unwrap_or(false),
             })
             .into())
         }
@@ -52,9 +57,16 @@ impl Validator for BytesValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        input
-            .validate_bytes(state.strict_or(self.strict), self.bytes_mode)
-            .and_then(|m| Ok(m.unpack(state).into_py_any(py)?))
+        let either_bytes = input
+            .validate_bytes(state.strict_or(self.strict), self.bytes_mode)?
+            .unpack(state);
+        if self.require_utf8 {
+            check_utf8(input, either_bytes.as_slice())?;
+        }
+        match kept_bytearray(input, self.keep_bytearray) {
+            Some(byte_array) => Ok(byte_array),
+            None => Ok(either_bytes.into_py_any(py)?),
+        }
     }
 
     fn get_name(&self) -> &str {
@@ -68,6 +80,10 @@ pub struct BytesConstrainedValidator {
     bytes_mode: ValBytesMode,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    keep_bytearray: bool,
+    require_utf8: bool,
+    // limit on the size of string input before it's decoded, `max_length` applies to the decoded bytes
+    encoded_max_length: Option<usize>,
 }
 
 impl_py_gc_traverse!(BytesConstrainedValidator {});
@@ -79,6 +95,21 @@ impl Validator for BytesConstrainedValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if let Some(encoded_max_length) = self.encoded_max_length {
+            // only string input is encoded, bytes input is checked by `max_length` below
+            if let Ok(str_match) = input.validate_str(true, false) {
+                if str_match.into_inner().as_cow()?.len() > encoded_max_length {
+                    return Err(ValError::new(
+                        ErrorType::BytesEncodedTooLong {
+                            max_length: encoded_max_length,
+                            context: None,
+                        },
+                        input,
+                    ));
+                }
+            }
+        }
+
         let either_bytes = input
             .validate_bytes(state.strict_or(self.strict), self.bytes_mode)?
             .unpack(state);
@@ -106,7 +137,13 @@ impl Validator for BytesConstrainedValidator {
                 ));
             }
         }
-        Ok(either_bytes.into_py_any(py)?)
+        if self.require_utf8 {
+            check_utf8(input, either_bytes.as_slice())?;
+        }
+        match kept_bytearray(input, self.keep_bytearray) {
+            Some(byte_array) => Ok(byte_array),
+            None => Ok(either_bytes.into_py_any(py)?),
+        }
     }
 
     fn get_name(&self) -> &'static str {
@@ -122,7 +159,34 @@ impl BytesConstrainedValidator {
             bytes_mode: ValBytesMode::from_config(config)?,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            keep_bytearray: schema.get_as(intern!(py, "keep_bytearray"))?.unwrap_or(false),
+            require_utf8: schema.get_as(intern!(py, "require_utf8"))?.unwrap_or(false),
+            encoded_max_length: schema.get_as(intern!(py, "encoded_max_length"))?,
         })
         .into())
     }
 }
+
+/// The input itself if it's a `bytearray` which should be passed through rather than copied to `bytes`
+fn kept_bytearray<'py>(input: &(impl Input<'py> + ?Sized), keep_bytearray: bool) -> Option<Py<PyAny>> {
+    if !keep_bytearray {
+        return None;
+    }
+    input
+        .as_python()
+        .filter(|py_input| py_input.is_instance_of::<PyByteArray>())
+        .map(|py_input| py_input.clone().unbind())
+}
+
+/// Check `bytes` are valid UTF-8, without decoding them to a string
+fn check_utf8<'py>(input: &(impl Input<'py> + ?Sized), bytes: &[u8]) -> ValResult<()> {
+    std::str::from_utf8(bytes).map(drop).map_err(|err| {
+        ValError::new(
+            ErrorType::BytesInvalidUtf8 {
+                utf8_error: err.to_string(),
+                context: None,
+            },
+            input,
+        )
+    })
+}
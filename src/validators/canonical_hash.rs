@@ -0,0 +1,198 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyFloat, PyFrozenSet, PyList, PySet, PyString, PyTuple};
+
+use crate::input::Int;
+use crate::serializers::{downcast_dict, ObType, ObTypeLookup};
+
+/// A 64-bit hash of a validated value which is stable between processes, platforms and Rust versions,
+/// unlike python's `hash()` which is randomized for strings and bytes.
+///
+/// The hash is computed from the canonical structure of the value: dicts and sets hash the same
+/// regardless of their iteration order, model and dataclass instances hash their class name and fields,
+/// enums their class name and value. Other types are only hashed if their `str()` is canonical
+/// (e.g. datetimes, decimals and UUIDs), anything else raises a `TypeError`.
+pub(crate) fn canonical_hash(value: &Bound<'_, PyAny>) -> PyResult<u64> {
+    let mut hasher = StableHasher::new();
+    hash_value(value, &mut hasher)?;
+    Ok(hasher.finish())
+}
+
+fn hash_value(value: &Bound<'_, PyAny>, hasher: &mut StableHasher) -> PyResult<()> {
+    let py = value.py();
+    match ObTypeLookup::cached(py).get_type(value) {
+        ObType::None => hasher.write_tag(0),
+        ObType::Bool => {
+            hasher.write_tag(1);
+            hasher.write_u64(u64::from(value.is_truthy()?));
+        }
+        ObType::Int | ObType::IntSubclass => {
+            hasher.write_tag(2);
+            match value.extract::<Int>()? {
+                Int::I64(i) => hasher.write_bytes(&i.to_le_bytes()),
+                Int::Big(b) => hasher.write_bytes(&b.to_signed_bytes_le()),
+            }
+        }
+        ObType::Float | ObType::FloatSubclass => {
+            hasher.write_tag(3);
+            // normalise `-0.0` so it hashes the same as `0.0`, to which it's equal
+            let f = value.downcast::<PyFloat>()?.value();
+            hasher.write_u64(if f == 0.0 { 0.0f64 } else { f }.to_bits());
+        }
+        ObType::Str | ObType::StrSubclass => {
+            hasher.write_tag(4);
+            hasher.write_bytes(value.downcast::<PyString>()?.to_cow()?.as_bytes());
+        }
+        ObType::Bytes => {
+            hasher.write_tag(5);
+            hasher.write_bytes(value.downcast::<PyBytes>()?.as_bytes());
+        }
+        ObType::Bytearray => {
+            hasher.write_tag(5);
+            hasher.write_bytes(&value.downcast::<PyByteArray>()?.to_vec());
+        }
+        ObType::List => {
+            hasher.write_tag(6);
+            let list = value.downcast::<PyList>()?;
+            hasher.write_u64(list.len() as u64);
+            for item in list {
+                hash_value(&item, hasher)?;
+            }
+        }
+        ObType::Tuple => {
+            hasher.write_tag(7);
+            let tuple = value.downcast::<PyTuple>()?;
+            hasher.write_u64(tuple.len() as u64);
+            for item in tuple {
+                hash_value(&item, hasher)?;
+            }
+        }
+        ObType::Dict | ObType::MappingProxy => {
+            hasher.write_tag(8);
+            hash_dict(&downcast_dict(value)?, hasher)?;
+        }
+        ObType::Set => {
+            hasher.write_tag(9);
+            hash_unordered(
+                value.downcast::<PySet>()?.iter().map(|item| canonical_hash(&item)),
+                hasher,
+            )?;
+        }
+        ObType::Frozenset => {
+            hasher.write_tag(9);
+            hash_unordered(
+                value
+                    .downcast::<PyFrozenSet>()?
+                    .iter()
+                    .map(|item| canonical_hash(&item)),
+                hasher,
+            )?;
+        }
+        ObType::Decimal
+        | ObType::Datetime
+        | ObType::Date
+        | ObType::Time
+        | ObType::Timedelta
+        | ObType::Url
+        | ObType::MultiHostUrl
+        | ObType::Path
+        | ObType::Pattern
+        | ObType::Uuid
+        | ObType::Complex
+        | ObType::Ipv4Address
+        | ObType::Ipv6Address
+        | ObType::Ipv4Network
+        | ObType::Ipv6Network => {
+            hasher.write_tag(10);
+            hash_class_name(value, hasher)?;
+            hasher.write_bytes(value.str()?.to_cow()?.as_bytes());
+        }
+        ObType::Enum => {
+            hasher.write_tag(11);
+            hash_class_name(value, hasher)?;
+            hash_value(&value.getattr(intern!(py, "value"))?, hasher)?;
+        }
+        // models and dataclasses, `__dict__` holds the validated fields
+        ObType::PydanticSerializable | ObType::Dataclass => hash_fields(value, hasher)?,
+        _ if value.hasattr(intern!(py, "__pydantic_fields_set__"))? => hash_fields(value, hasher)?,
+        _ => {
+            return Err(PyTypeError::new_err(format!(
+                "Unable to compute a stable hash of a `{}` object",
+                value.get_type().qualname()?
+            )))
+        }
+    }
+    Ok(())
+}
+
+fn hash_class_name(value: &Bound<'_, PyAny>, hasher: &mut StableHasher) -> PyResult<()> {
+    hasher.write_bytes(value.get_type().qualname()?.to_cow()?.as_bytes());
+    Ok(())
+}
+
+fn hash_fields(value: &Bound<'_, PyAny>, hasher: &mut StableHasher) -> PyResult<()> {
+    hasher.write_tag(12);
+    hash_class_name(value, hasher)?;
+    hash_dict(value.getattr(intern!(value.py(), "__dict__"))?.downcast()?, hasher)
+}
+
+fn hash_dict(dict: &Bound<'_, PyDict>, hasher: &mut StableHasher) -> PyResult<()> {
+    hash_unordered(
+        dict.iter().map(|(key, value)| {
+            let mut entry_hasher = StableHasher::new();
+            hash_value(&key, &mut entry_hasher)?;
+            hash_value(&value, &mut entry_hasher)?;
+            Ok(entry_hasher.finish())
+        }),
+        hasher,
+    )
+}
+
+/// Combine the hashes of items commutatively, so the result doesn't depend on their order
+fn hash_unordered(items: impl Iterator<Item = PyResult<u64>>, hasher: &mut StableHasher) -> PyResult<()> {
+    let mut count: u64 = 0;
+    let mut sum: u64 = 0;
+    for item_hash in items {
+        count += 1;
+        sum = sum.wrapping_add(item_hash?);
+    }
+    hasher.write_u64(count);
+    hasher.write_u64(sum);
+    Ok(())
+}
+
+/// 64-bit FNV-1a, unlike `DefaultHasher` its output is fully specified; values are written as little-endian
+/// bytes rather than through `Hash` which uses the platform's endianness and `usize` width
+struct StableHasher(u64);
+
+impl StableHasher {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_tag(&mut self, tag: u8) {
+        self.write(&[tag]);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    /// Length-prefixed, so consecutive values can't be confused
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_u64(bytes.len() as u64);
+        self.write(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
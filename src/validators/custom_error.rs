@@ -46,7 +46,8 @@ impl CustomError {
                 error_type,
                 schema.get_as_req::<String>(intern!(py, "custom_error_message"))?,
                 context,
-            );
+                None,
+            )?;
             Ok(Some(Self::Custom(error)))
         }
     }
@@ -231,7 +231,9 @@ impl Validator for DataclassArgsValidator {
                     ));
                 }
                 // found a positional argument, validate it
-                (Some(pos_value), None) => match field.validator.validate(py, pos_value.borrow_input(), state) {
+                (Some(pos_value), None) => match state.validate_field(&field.name, |state| {
+                    field.validator.validate(py, pos_value.borrow_input(), state)
+                }) {
                     Ok(value) => {
                         set_item!(field, value);
                         fields_set_count += 1;
@@ -243,21 +245,23 @@ impl Validator for DataclassArgsValidator {
                     Err(err) => return Err(err),
                 },
                 // found a keyword argument, validate it
-                (None, Some((lookup_path, kw_value))) => match field.validator.validate(py, kw_value, state) {
-                    Ok(value) => {
-                        set_item!(field, value);
-                        fields_set_count += 1;
-                    }
-                    Err(ValError::LineErrors(line_errors)) => {
-                        state.has_field_error = true;
-                        errors.extend(
-                            line_errors
-                                .into_iter()
-                                .map(|err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)),
-                        );
+                (None, Some((lookup_path, kw_value))) => {
+                    match state.validate_field(&field.name, |state| field.validator.validate(py, kw_value, state)) {
+                        Ok(value) => {
+                            set_item!(field, value);
+                            fields_set_count += 1;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            state.has_field_error = true;
+                            errors.extend(
+                                line_errors
+                                    .into_iter()
+                                    .map(|err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)),
+                            );
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 // found neither, check if there is a default value, otherwise error
                 (None, None) => {
                     match field.validator.default_value(py, Some(field.name.as_str()), state) {
@@ -344,7 +348,7 @@ impl Validator for DataclassArgsValidator {
                                         } else {
                                             output_dict.set_item(
                                                 either_str.as_py_string(py, state.cache_str()),
-                                                value.borrow_input().to_object(py)?,
+                                                state.input_to_object(py, value.borrow_input())?,
                                             )?;
                                         }
                                     }
@@ -4,14 +4,15 @@ use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
-use speedate::{Date, Time};
+use speedate::{Date, MicrosecondsPrecisionOverflowBehavior, Time};
 use strum::EnumMessage;
 
 use crate::build_tools::{is_strict, py_schema_error_type};
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDate, Input};
+use crate::errors::{comparison_context, ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{EitherDate, EitherDateTime, Input};
+use crate::tools::SchemaDict;
 
-use crate::validators::datetime::{NowConstraint, NowOp};
+use crate::validators::datetime::{is_timestamp, shift_epoch, NowConstraint, NowOp};
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 use super::{Exactness, TemporalUnitMode};
@@ -21,6 +22,8 @@ pub struct DateValidator {
     strict: bool,
     constraints: Option<DateConstraints>,
     val_temporal_unit: TemporalUnitMode,
+    // seconds added to numeric timestamps, so they count from a custom epoch
+    epoch_offset_seconds: Option<i64>,
 }
 
 impl BuildValidator for DateValidator {
@@ -35,6 +38,7 @@ impl BuildValidator for DateValidator {
             strict: is_strict(schema, config)?,
             constraints: DateConstraints::from_py(schema)?,
             val_temporal_unit: TemporalUnitMode::from_config(config)?,
+            epoch_offset_seconds: schema.get_as(intern!(schema.py(), "epoch_offset_seconds"))?,
         })
         .into())
     }
@@ -50,6 +54,19 @@ impl Validator for DateValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
         let strict = state.strict_or(self.strict);
+        if let Some(offset) = self.epoch_offset_seconds.filter(|_| !strict && is_timestamp(input)) {
+            // the shifted timestamp rather than the input has to be an exact date
+            state.floor_exactness(Exactness::Lax);
+            let datetime = input
+                .validate_datetime(
+                    false,
+                    MicrosecondsPrecisionOverflowBehavior::Truncate,
+                    self.val_temporal_unit,
+                )?
+                .into_inner();
+            let date = exact_date(input, &shift_epoch(input, datetime, offset)?)?;
+            return self.validate_constraints(py, input, date);
+        }
         let date = match input.validate_date(strict, self.val_temporal_unit) {
             Ok(val_match) => val_match.unpack(state),
             // if the error was a parsing error, in lax mode we allow datetimes at midnight
@@ -59,6 +76,21 @@ impl Validator for DateValidator {
             }
             Err(otherwise) => return Err(otherwise),
         };
+        self.validate_constraints(py, input, date)
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl DateValidator {
+    fn validate_constraints<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        date: EitherDate<'py>,
+    ) -> ValResult<Py<PyAny>> {
         if let Some(constraints) = &self.constraints {
             let raw_date = date.as_raw()?;
 
@@ -69,7 +101,7 @@ impl Validator for DateValidator {
                             return Err(ValError::new(
                                 ErrorType::$error {
                                     $constraint: constraint.to_string().into(),
-                                    context: None,
+                                    context: Some(comparison_context(py, raw_date.to_string())?.unbind()),
                                 },
                                 input,
                             ));
@@ -103,10 +135,6 @@ impl Validator for DateValidator {
         }
         date.try_into_py(py, input)
     }
-
-    fn get_name(&self) -> &str {
-        Self::EXPECTED_TYPE
-    }
 }
 
 /// In lax mode, if the input is not a date, we try parsing the input as a datetime, then check it is an
@@ -117,30 +145,34 @@ fn date_from_datetime<'py>(
     input: &(impl Input<'py> + ?Sized),
     mode: TemporalUnitMode,
 ) -> Result<Option<EitherDate<'py>>, ValError> {
-    let either_dt =
-        match input.validate_datetime(false, speedate::MicrosecondsPrecisionOverflowBehavior::Truncate, mode) {
-            Ok(val_match) => val_match.into_inner(),
-            // if the error was a parsing error, update the error type from DatetimeParsing to DateFromDatetimeParsing
-            // and return it
-            Err(ValError::LineErrors(mut line_errors)) => {
-                if line_errors.iter_mut().fold(false, |has_parsing_error, line_error| {
-                    if let ErrorType::DatetimeParsing { error, .. } = &mut line_error.error_type {
-                        line_error.error_type = ErrorType::DateFromDatetimeParsing {
-                            error: std::mem::take(error),
-                            context: None,
-                        };
-                        true
-                    } else {
-                        has_parsing_error
-                    }
-                }) {
-                    return Err(ValError::LineErrors(line_errors));
+    let either_dt = match input.validate_datetime(false, MicrosecondsPrecisionOverflowBehavior::Truncate, mode) {
+        Ok(val_match) => val_match.into_inner(),
+        // if the error was a parsing error, update the error type from DatetimeParsing to DateFromDatetimeParsing
+        // and return it
+        Err(ValError::LineErrors(mut line_errors)) => {
+            if line_errors.iter_mut().fold(false, |has_parsing_error, line_error| {
+                if let ErrorType::DatetimeParsing { error, .. } = &mut line_error.error_type {
+                    line_error.error_type = ErrorType::DateFromDatetimeParsing {
+                        error: std::mem::take(error),
+                        context: None,
+                    };
+                    true
+                } else {
+                    has_parsing_error
                 }
-                return Ok(None);
+            }) {
+                return Err(ValError::LineErrors(line_errors));
             }
-            // for any other error, don't return it
-            Err(_) => return Ok(None),
-        };
+            return Ok(None);
+        }
+        // for any other error, don't return it
+        Err(_) => return Ok(None),
+    };
+    exact_date(input, &either_dt).map(Some)
+}
+
+/// The date of a datetime with a zero time component, an error for any other datetime
+fn exact_date<'py>(input: &(impl Input<'py> + ?Sized), either_dt: &EitherDateTime<'py>) -> ValResult<EitherDate<'py>> {
     let dt = either_dt.as_raw()?;
     let zero_time = Time {
         hour: 0,
@@ -150,7 +182,7 @@ fn date_from_datetime<'py>(
         tz_offset: dt.time.tz_offset,
     };
     if dt.time == zero_time {
-        Ok(Some(EitherDate::Raw(dt.date)))
+        Ok(EitherDate::Raw(dt.date))
     } else {
         Err(ValError::new(ErrorTypeDefaults::DateFromDatetimeInexact, input))
     }
@@ -11,8 +11,8 @@ use strum::EnumMessage;
 use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::build_tools::{py_schema_err, schema_or_config_same};
 use crate::errors::ToErrorValue;
-use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::errors::{comparison_context, py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{int_as_datetime, EitherDateTime, Input};
 
 use super::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -25,6 +25,12 @@ pub struct DateTimeValidator {
     constraints: Option<DateTimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
     val_temporal_unit: TemporalUnitMode,
+    // whether to accept RFC 2822 strings in lax mode
+    rfc2822: bool,
+    // UTC offset in seconds given to parsed datetimes which have no timezone
+    assume_tz: Option<i32>,
+    // seconds added to numeric timestamps, so they count from a custom epoch
+    epoch_offset_seconds: Option<i64>,
 }
 
 pub(crate) fn extract_microseconds_precision(
@@ -54,6 +60,12 @@ impl BuildValidator for DateTimeValidator {
             constraints: DateTimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
             val_temporal_unit: TemporalUnitMode::from_config(config)?,
+            rfc2822: schema.get_as(intern!(schema.py(), "rfc2822"))?.unwrap_or(false),
+            assume_tz: schema
+                .get_as::<Bound<'_, PyString>>(intern!(schema.py(), "assume_tz"))?
+                .map(|tz| parse_assume_tz(tz.to_str()?))
+                .transpose()?,
+            epoch_offset_seconds: schema.get_as(intern!(schema.py(), "epoch_offset_seconds"))?,
         })
         .into())
     }
@@ -74,10 +86,25 @@ impl Validator for DateTimeValidator {
             // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
             Err(line_errors @ ValError::LineErrors(..)) if !strict => {
                 state.floor_exactness(Exactness::Lax);
-                datetime_from_date(input)?.ok_or(line_errors)?
+                match self.rfc2822.then(|| datetime_from_rfc2822(input)).flatten() {
+                    Some(datetime) => datetime,
+                    None => datetime_from_date(input)?.ok_or(line_errors)?,
+                }
             }
             Err(otherwise) => return Err(otherwise),
         };
+        let datetime = match self.epoch_offset_seconds {
+            // numeric timestamps, including numeric strings, count from the custom epoch
+            Some(offset) if is_timestamp(input) => shift_epoch(input, datetime, offset)?,
+            _ => datetime,
+        };
+        let datetime = match (datetime, self.assume_tz) {
+            (EitherDateTime::Raw(mut dt), Some(offset)) if dt.time.tz_offset.is_none() => {
+                dt.time.tz_offset = Some(offset);
+                EitherDateTime::Raw(dt)
+            }
+            (datetime, _) => datetime,
+        };
         if let Some(constraints) = &self.constraints {
             // if we get an error from as_speedate, it's probably because the input datetime was invalid
             // specifically had an invalid tzinfo, hence here we return a validation error
@@ -98,7 +125,7 @@ impl Validator for DateTimeValidator {
                             return Err(ValError::new(
                                 ErrorType::$error {
                                     $constraint: constraint.to_string().into(),
-                                    context: None,
+                                    context: Some(comparison_context(py, speedate_dt.to_string())?.unbind()),
                                 },
                                 input,
                             ));
@@ -142,6 +169,59 @@ impl Validator for DateTimeValidator {
     }
 }
 
+/// Whether the input is a numeric timestamp, a number or a string of one, rather than a date or datetime
+pub(crate) fn is_timestamp<'py>(input: &(impl Input<'py> + ?Sized)) -> bool {
+    input.validate_float(false).is_ok()
+}
+
+/// Move a datetime parsed from a numeric timestamp by `offset` seconds, keeping its microseconds and timezone
+pub(crate) fn shift_epoch<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    datetime: EitherDateTime<'py>,
+    offset: i64,
+) -> ValResult<EitherDateTime<'py>> {
+    let EitherDateTime::Raw(dt) = datetime else {
+        return Ok(datetime);
+    };
+    let timestamp = dt.timestamp().checked_add(offset).ok_or_else(|| {
+        ValError::new(
+            ErrorType::DatetimeParsing {
+                error: "timestamp is too large".into(),
+                context: None,
+            },
+            input,
+        )
+    })?;
+    int_as_datetime(input, timestamp, dt.time.microsecond, TemporalUnitMode::Seconds)
+}
+
+/// Parse `assume_tz`, either `"utc"` or a UTC offset like `"+05:30"`, into an offset in seconds
+fn parse_assume_tz(tz: &str) -> PyResult<i32> {
+    if tz.eq_ignore_ascii_case("utc") || tz == "Z" {
+        return Ok(0);
+    }
+    let offset = (|| {
+        let sign = match tz.as_bytes().first()? {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        let (hours, minutes) = tz[1..].split_once(':')?;
+        if hours.len() != 2 || minutes.len() != 2 {
+            return None;
+        }
+        let hours: i32 = hours.parse().ok().filter(|h| *h < 24)?;
+        let minutes: i32 = minutes.parse().ok().filter(|m| *m < 60)?;
+        Some(sign * (hours * 3600 + minutes * 60))
+    })();
+    offset.ok_or_else(|| {
+        py_schema_error_type!(
+            "Invalid `assume_tz` {:?}, must be \"utc\" or an offset like \"+05:30\"",
+            tz
+        )
+    })
+}
+
 /// In lax mode, if the input is not a datetime, we try parsing the input as a date and add the "00:00:00" time.
 /// Ok(None) means that this is not relevant to datetimes (the input was not a date nor a string)
 fn datetime_from_date<'py>(input: &(impl Input<'py> + ?Sized)) -> Result<Option<EitherDateTime<'py>>, ValError> {
@@ -183,6 +263,48 @@ fn datetime_from_date<'py>(input: &(impl Input<'py> + ?Sized)) -> Result<Option<
     Ok(Some(EitherDateTime::Raw(datetime)))
 }
 
+/// In lax mode with `rfc2822`, try parsing string input as an RFC 2822 datetime, e.g. "Wed, 02 Oct 2002 13:00:00 GMT".
+/// None means the input was not a string in RFC 2822 format.
+fn datetime_from_rfc2822<'py>(input: &(impl Input<'py> + ?Sized)) -> Option<EitherDateTime<'py>> {
+    let either_str = input.validate_str(true, false).ok()?.into_inner();
+    let iso = rfc2822_to_iso(either_str.as_cow().ok()?.as_ref())?;
+    DateTime::parse_str(&iso).ok().map(EitherDateTime::Raw)
+}
+
+/// Rewrite an RFC 2822 datetime as ISO 8601 so speedate can parse and check it, the (optional) day of the week is ignored
+fn rfc2822_to_iso(s: &str) -> Option<String> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let mut parts = s.split_ascii_whitespace().peekable();
+    if parts.peek()?.ends_with(',') {
+        parts.next();
+    }
+    let day: u8 = parts.next().filter(|d| d.len() <= 2)?.parse().ok()?;
+    let month_str = parts.next()?.to_ascii_lowercase();
+    let month = MONTHS.iter().position(|m| *m == month_str)? + 1;
+    let year = parts
+        .next()
+        .filter(|y| y.len() == 4 && y.bytes().all(|b| b.is_ascii_digit()))?;
+    let time = parts.next().filter(|t| matches!(t.len(), 5 | 8))?;
+    let offset = match parts.next()? {
+        "GMT" | "UT" | "UTC" | "Z" => "Z".to_string(),
+        "EDT" => "-04:00".to_string(),
+        "EST" | "CDT" => "-05:00".to_string(),
+        "CST" | "MDT" => "-06:00".to_string(),
+        "MST" | "PDT" => "-07:00".to_string(),
+        "PST" => "-08:00".to_string(),
+        zone if zone.len() == 5 && zone.starts_with(['+', '-']) && zone[1..].bytes().all(|b| b.is_ascii_digit()) => {
+            format!("{}:{}", &zone[..3], &zone[3..])
+        }
+        _ => return None,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(format!("{year}-{month:02}-{day:02}T{time}{offset}"))
+}
+
 #[derive(Debug, Clone)]
 struct DateTimeConstraints {
     le: Option<DateTime>,
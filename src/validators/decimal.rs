@@ -9,9 +9,9 @@ use pyo3::{prelude::*, PyTypeInfo};
 use crate::build_tools::{is_strict, schema_or_config_same};
 use crate::errors::ErrorType;
 use crate::errors::ValResult;
-use crate::errors::{ErrorTypeDefaults, Number};
+use crate::errors::{comparison_context, ErrorTypeDefaults, Number};
 use crate::errors::{ToErrorValue, ValError};
-use crate::input::Input;
+use crate::input::{Input, ValidationMatch};
 use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -142,7 +142,17 @@ impl Validator for DecimalValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let decimal = input.validate_decimal(state.strict_or(self.strict), py)?.unpack(state);
+        let json_float_text = state
+            .extra()
+            .json_decimals
+            .zip(input.as_json())
+            .and_then(|(decimals, json_value)| decimals.float_text(json_value));
+        let decimal = match json_float_text {
+            // with `json_number_mode='decimal'` use the float as written rather than its nearest `f64`
+            Some(text) => ValidationMatch::strict(create_decimal(PyString::new(py, text).as_any(), input)?),
+            None => input.validate_decimal(state.strict_or(self.strict), py)?,
+        }
+        .unpack(state);
 
         if !self.allow_inf_nan || self.check_digits {
             if !decimal.call_method0(intern!(py, "is_finite"))?.extract()? {
@@ -201,10 +211,11 @@ impl Validator for DecimalValidator {
         }
 
         if let Some(multiple_of) = &self.multiple_of {
-            // fraction = (decimal / multiple_of) % 1
-            let fraction = (decimal.div(multiple_of)?).rem(1)?;
+            // the remainder is exact, unlike `(decimal / multiple_of) % 1` where the quotient is rounded
+            // to the context precision first
+            let remainder = decimal.rem(multiple_of)?;
             let zero = 0u8.into_pyobject(py)?;
-            if !fraction.eq(&zero)? {
+            if !remainder.eq(&zero)? {
                 return Err(ValError::new(
                     ErrorType::MultipleOf {
                         multiple_of: multiple_of.to_string().into(),
@@ -231,7 +242,11 @@ impl Validator for DecimalValidator {
                 return Err(ValError::new(
                     ErrorType::LessThanEqual {
                         le: Number::String(le.to_string()),
-                        context: Some([("le", le)].into_py_dict(py)?.into()),
+                        context: Some({
+                            let context = comparison_context(py, &decimal)?;
+                            context.set_item("le", le)?;
+                            context.unbind()
+                        }),
                     },
                     input,
                 ));
@@ -242,7 +257,11 @@ impl Validator for DecimalValidator {
                 return Err(ValError::new(
                     ErrorType::LessThan {
                         lt: Number::String(lt.to_string()),
-                        context: Some([("lt", lt)].into_py_dict(py)?.into()),
+                        context: Some({
+                            let context = comparison_context(py, &decimal)?;
+                            context.set_item("lt", lt)?;
+                            context.unbind()
+                        }),
                     },
                     input,
                 ));
@@ -253,7 +272,11 @@ impl Validator for DecimalValidator {
                 return Err(ValError::new(
                     ErrorType::GreaterThanEqual {
                         ge: Number::String(ge.to_string()),
-                        context: Some([("ge", ge)].into_py_dict(py)?.into()),
+                        context: Some({
+                            let context = comparison_context(py, &decimal)?;
+                            context.set_item("ge", ge)?;
+                            context.unbind()
+                        }),
                     },
                     input,
                 ));
@@ -264,7 +287,11 @@ impl Validator for DecimalValidator {
                 return Err(ValError::new(
                     ErrorType::GreaterThan {
                         gt: Number::String(gt.to_string()),
-                        context: Some([("gt", gt)].into_py_dict(py)?.into()),
+                        context: Some({
+                            let context = comparison_context(py, &decimal)?;
+                            context.set_item("gt", gt)?;
+                            context.unbind()
+                        }),
                     },
                     input,
                 ));
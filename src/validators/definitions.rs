@@ -126,6 +126,10 @@ impl Validator for DefinitionRefValidator {
     fn get_name(&self) -> &str {
         self.definition.get_or_init_name(|v| v.get_name().into())
     }
+
+    fn get_title(&self) -> Option<String> {
+        self.definition.read(|validator| validator.and_then(|v| v.get_title()))
+    }
 }
 
 fn py_identity(obj: &Bound<'_, PyAny>) -> usize {
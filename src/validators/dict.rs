@@ -1,20 +1,24 @@
 use std::sync::Arc;
 
+use ahash::AHashMap;
+use jiter::JsonValue;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyMappingProxy, PyString};
 
-use crate::build_tools::is_strict;
-use crate::errors::{LocItem, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorTypeDefaults, LocItem, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
-use crate::input::{Input, ValidatedDict};
+use crate::input::{Input, InputType, ValidatedDict};
 
 use crate::tools::SchemaDict;
 
-use super::any::AnyValidator;
+use super::any::{deep_freeze, AnyValidator};
 use super::list::length_check;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct DictValidator {
@@ -24,6 +28,14 @@ pub struct DictValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     fail_fast: bool,
+    // validate keys in strict mode regardless of `strict`, so e.g. JSON keys aren't coerced
+    strict_keys: bool,
+    // with `enum_keys_by_name`, enum members by name, for keys which aren't valid enum values
+    enum_keys: Option<AHashMap<String, Py<PyAny>>>,
+    // with `nan_key_mode='forbid'`, validated keys which are float NaN are errors
+    forbid_nan_keys: bool,
+    // set when keys and values are both simple scalars, so dicts of them can be validated in a tight loop
+    scalar_items: Option<(ScalarItem, ScalarItem)>,
     name: String,
 }
 
@@ -44,6 +56,29 @@ impl BuildValidator for DictValidator {
             Some(d) => build_validator(&d, config, definitions)?,
             None => AnyValidator::build(schema, config, definitions)?,
         };
+        let enum_keys = if schema.get_as(intern!(py, "enum_keys_by_name"))?.unwrap_or(false) {
+            Some(enum_members_by_name(schema)?)
+        } else {
+            None
+        };
+        let strict_keys = schema.get_as(intern!(py, "strict_keys"))?.unwrap_or(false);
+        let forbid_nan_keys = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "nan_key_mode"))? {
+            Some(mode) => match mode.to_str()? {
+                "allow" => false,
+                "forbid" => true,
+                s => return py_schema_err!("Invalid nan_key_mode: {}, should be 'allow' or 'forbid'", s),
+            },
+            None => false,
+        };
+        let scalar_items = match (
+            ScalarItem::from_validator(&key_validator),
+            ScalarItem::from_validator(&value_validator),
+        ) {
+            (Some(key_item), Some(value_item)) if !strict_keys && !forbid_nan_keys && enum_keys.is_none() => {
+                Some((key_item, value_item))
+            }
+            _ => None,
+        };
         let name = format!(
             "{}[{},{}]",
             Self::EXPECTED_TYPE,
@@ -57,15 +92,39 @@ impl BuildValidator for DictValidator {
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
             fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
+            strict_keys,
+            enum_keys,
+            forbid_nan_keys,
+            scalar_items,
             name,
         })
         .into())
     }
 }
 
+/// Map the names of the members of the enum in `keys_schema` to the members
+fn enum_members_by_name(schema: &Bound<'_, PyDict>) -> PyResult<AHashMap<String, Py<PyAny>>> {
+    let py = schema.py();
+    let keys_schema: Option<Bound<'_, PyDict>> = schema.get_as(intern!(py, "keys_schema"))?;
+    let Some(keys_schema) = keys_schema.filter(|s| {
+        s.get_as::<Bound<'_, PyString>>(intern!(py, "type"))
+            .ok()
+            .flatten()
+            .is_some_and(|t| t.to_str().is_ok_and(|t| t == "enum"))
+    }) else {
+        return py_schema_err!("`enum_keys_by_name` requires `keys_schema` to be an enum schema");
+    };
+    let members: Bound<'_, PyList> = keys_schema.get_as_req(intern!(py, "members"))?;
+    members
+        .iter()
+        .map(|member| Ok((member.getattr(intern!(py, "name"))?.extract()?, member.unbind())))
+        .collect()
+}
+
 impl_py_gc_traverse!(DictValidator {
     key_validator,
-    value_validator
+    value_validator,
+    enum_keys
 });
 
 impl Validator for DictValidator {
@@ -75,6 +134,16 @@ impl Validator for DictValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if let Some((key_item, value_item)) = self.scalar_items {
+            if let Some(output) = validate_scalar_items(input, key_item, value_item, state)? {
+                length_check!(input, "Dictionary", self.min_length, self.max_length, output);
+                if state.extra().deep_freeze {
+                    // `Any` keys and values are unvalidated so have to be frozen here
+                    return Ok(deep_freeze(output.as_any())?.unbind());
+                }
+                return Ok(output.into());
+            }
+        }
         let strict = state.strict_or(self.strict);
         let dict = input.validate_dict(strict)?;
         dict.iterate(ValidateToDict {
@@ -83,6 +152,9 @@ impl Validator for DictValidator {
             min_length: self.min_length,
             max_length: self.max_length,
             fail_fast: self.fail_fast,
+            strict_keys: self.strict_keys,
+            enum_keys: self.enum_keys.as_ref(),
+            forbid_nan_keys: self.forbid_nan_keys,
             key_validator: &self.key_validator,
             value_validator: &self.value_validator,
             state,
@@ -94,12 +166,101 @@ impl Validator for DictValidator {
     }
 }
 
+/// The validated dict, wrapped in a read-only `mappingproxy` with `deep_freeze`
+fn dict_output(dict: Bound<'_, PyDict>, deep_freeze: bool) -> Py<PyAny> {
+    if deep_freeze {
+        return PyMappingProxy::new(dict.py(), dict.as_mapping()).into_any().unbind();
+    }
+    dict.into_any().unbind()
+}
+
+/// A scalar schema whose validator returns exact instances of its type unchanged
+#[derive(Debug, Clone, Copy)]
+enum ScalarItem {
+    Any,
+    Str,
+    Int,
+    Float,
+    Bool,
+}
+
+impl ScalarItem {
+    fn from_validator(validator: &CombinedValidator) -> Option<Self> {
+        match validator {
+            CombinedValidator::Any(_) => Some(Self::Any),
+            CombinedValidator::Str(_) => Some(Self::Str),
+            CombinedValidator::Int(_) => Some(Self::Int),
+            CombinedValidator::Float(_) => Some(Self::Float),
+            CombinedValidator::Bool(_) => Some(Self::Bool),
+            _ => None,
+        }
+    }
+
+    /// The validated value if `value` passes validation unchanged, `None` if it needs the full validator
+    fn validate<'py>(
+        self,
+        value: Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> Option<Bound<'py, PyAny>> {
+        match self {
+            Self::Any => Some(value),
+            Self::Str => value
+                .downcast_exact::<PyString>()
+                .ok()
+                .map(|py_string| state.intern_str(py_string.clone()).into_any()),
+            Self::Int => value.is_exact_instance_of::<PyInt>().then_some(value),
+            // non-finite floats may be rejected, depending on `allow_inf_nan`
+            Self::Float => value
+                .downcast_exact::<PyFloat>()
+                .is_ok_and(|f| f.value().is_finite())
+                .then_some(value),
+            Self::Bool => value.is_exact_instance_of::<PyBool>().then_some(value),
+        }
+    }
+}
+
+/// Validate a Python dict of scalars without calling the key and value validators for each item.
+///
+/// Returns `None` if `input` isn't a dict, or any key or value needs the full validator,
+/// in which case the dict should be validated as normal.
+fn validate_scalar_items<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    key_item: ScalarItem,
+    value_item: ScalarItem,
+    state: &mut ValidationState<'_, 'py>,
+) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let Some(dict) = input
+        .as_python()
+        .and_then(|py_input| py_input.downcast_exact::<PyDict>().ok())
+    else {
+        return Ok(None);
+    };
+    let output = PyDict::new(dict.py());
+    for (key, value) in dict {
+        let Some(key) = key_item.validate(key, state) else {
+            return Ok(None);
+        };
+        let Some(value) = value_item.validate(value, state) else {
+            return Ok(None);
+        };
+        output.set_item(key, value)?;
+    }
+    if !output.is_empty() && (matches!(key_item, ScalarItem::Any) || matches!(value_item, ScalarItem::Any)) {
+        // in a union, Any should be preferred to doing lax coercions, as in `AnyValidator`
+        state.floor_exactness(Exactness::Strict);
+    }
+    Ok(Some(output))
+}
+
 struct ValidateToDict<'a, 's, 'py, I: Input<'py> + ?Sized> {
     py: Python<'py>,
     input: &'a I,
     min_length: Option<usize>,
     max_length: Option<usize>,
     fail_fast: bool,
+    strict_keys: bool,
+    enum_keys: Option<&'a AHashMap<String, Py<PyAny>>>,
+    forbid_nan_keys: bool,
     key_validator: &'a CombinedValidator,
     value_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
@@ -119,14 +280,53 @@ where
 
         macro_rules! should_fail_fast {
             () => {
-                self.fail_fast && !errors.is_empty()
+                (self.fail_fast || self.state.fail_fast) && !errors.is_empty()
             };
         }
 
         for (_, is_last_partial, item_result) in self.state.enumerate_last_partial(iterator) {
             self.state.allow_partial = false.into();
             let (key, value) = item_result?;
-            let output_key = match self.key_validator.validate(self.py, key.borrow_input(), self.state) {
+            let key_result = if self.strict_keys {
+                let state = &mut self.state.rebind_extra(|extra| extra.strict = Some(true));
+                if state.extra().input_type == InputType::Json {
+                    // JSON object keys are parsed from strings even in strict mode, validating the key as
+                    // a JSON string value instead means it isn't coerced
+                    let key_str = key.borrow_input().validate_str(true, false)?.into_inner();
+                    let json_key = JsonValue::Str(key_str.as_cow()?.into_owned().into());
+                    self.key_validator.validate(self.py, &json_key, state)
+                } else {
+                    self.key_validator.validate(self.py, key.borrow_input(), state)
+                }
+            } else {
+                self.key_validator.validate(self.py, key.borrow_input(), self.state)
+            };
+            let key_result = match (key_result, self.enum_keys) {
+                (Err(ValError::LineErrors(line_errors)), Some(enum_keys)) => {
+                    match key.borrow_input().validate_str(true, false) {
+                        Ok(key_str) => match enum_keys.get(key_str.into_inner().as_cow()?.as_ref()) {
+                            Some(member) => Ok(member.clone_ref(self.py)),
+                            None => Err(ValError::LineErrors(line_errors)),
+                        },
+                        Err(_) => Err(ValError::LineErrors(line_errors)),
+                    }
+                }
+                (key_result, _) => key_result,
+            };
+            let key_result = match key_result {
+                // a NaN key is never equal to itself, so it can't be looked up and isn't deduplicated
+                Ok(output_key)
+                    if self.forbid_nan_keys
+                        && output_key
+                            .bind(self.py)
+                            .downcast::<PyFloat>()
+                            .is_ok_and(|f| f.value().is_nan()) =>
+                {
+                    Err(ValError::new(ErrorTypeDefaults::FiniteNumber, key.borrow_input()))
+                }
+                key_result => key_result,
+            };
+            let output_key = match key_result {
                 Ok(value) => Some(value),
                 Err(ValError::LineErrors(line_errors)) => {
                     for err in line_errors {
@@ -147,11 +347,17 @@ where
                 break;
             }
 
-            let output_value = match self.value_validator.validate(self.py, value.borrow_input(), self.state) {
+            let output_value = match self.state.validate_item(key.clone(), |state| {
+                self.value_validator.validate(self.py, value.borrow_input(), state)
+            }) {
                 Ok(value) => value,
                 Err(ValError::LineErrors(line_errors)) => {
                     if !is_last_partial {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(key.clone())));
+                        if self.state.extra().track_locations {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(key.clone())));
+                        } else {
+                            errors.extend(line_errors);
+                        }
                     }
                     continue;
                 }
@@ -171,7 +377,7 @@ where
         if errors.is_empty() {
             let input = self.input;
             length_check!(input, "Dictionary", self.min_length, self.max_length, output);
-            Ok(output.into())
+            Ok(dict_output(output, self.state.extra().deep_freeze))
         } else {
             Err(ValError::LineErrors(errors))
         }
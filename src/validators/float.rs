@@ -1,16 +1,20 @@
 use std::cmp::Ordering;
 use std::sync::Arc;
 
+use jiter::JsonValue;
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, ToPrimitive};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 use pyo3::IntoPyObjectExt;
 
 use crate::build_tools::{is_strict, schema_or_config_same};
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::Input;
+use crate::errors::{comparison_context, ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{EitherFloat, Input, Int};
 use crate::tools::SchemaDict;
 
+use super::int::warn_lossy_coercion;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 pub struct FloatBuilder;
@@ -27,23 +31,58 @@ impl BuildValidator for FloatBuilder {
             || schema.get_item(intern!(py, "le"))?.is_some()
             || schema.get_item(intern!(py, "lt"))?.is_some()
             || schema.get_item(intern!(py, "ge"))?.is_some()
-            || schema.get_item(intern!(py, "gt"))?.is_some();
+            || schema.get_item(intern!(py, "gt"))?.is_some()
+            || schema.get_item(intern!(py, "le_context_key"))?.is_some()
+            || schema.get_item(intern!(py, "ge_context_key"))?.is_some();
         if use_constrained {
             ConstrainedFloatValidator::build(schema, config, definitions)
         } else {
             Ok(CombinedValidator::Float(FloatValidator {
                 strict: is_strict(schema, config)?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                warn_lossy: schema.get_as(intern!(py, "warn_lossy"))?.unwrap_or(false),
+                reject_subnormal: schema.get_as(intern!(py, "reject_subnormal"))?.unwrap_or(false),
             })
             .into())
         }
     }
 }
 
+/// Whether lax validation of `input` as a float discards information, i.e. the input was an int
+/// which can't be represented exactly as a float
+fn is_lossy_float<'py>(input: &(impl Input<'py> + ?Sized)) -> bool {
+    match input
+        .validate_int(true)
+        .map(|val_match| val_match.into_inner().as_int())
+    {
+        Ok(Ok(Int::I64(i))) => i128::from(i) != (i as f64) as i128,
+        Ok(Ok(Int::Big(b))) => b.to_f64().and_then(BigInt::from_f64) != Some(b),
+        _ => false,
+    }
+}
+
+/// The validated float, or with `preserve_number_type` an integer JSON number as an int,
+/// python ints (e.g. returned by a before validator) are always converted
+fn float_output<'py>(
+    py: Python<'py>,
+    input: &(impl Input<'py> + ?Sized),
+    either_float: EitherFloat<'_>,
+    state: &ValidationState<'_, 'py>,
+) -> ValResult<Py<PyAny>> {
+    if state.extra().preserve_number_type {
+        if let Some(json_int @ (JsonValue::Int(_) | JsonValue::BigInt(_))) = input.as_json() {
+            return Ok(json_int.into_py_any(py)?);
+        }
+    }
+    Ok(either_float.into_py_any(py)?)
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    warn_lossy: bool,
+    reject_subnormal: bool,
 }
 
 impl BuildValidator for FloatValidator {
@@ -58,6 +97,8 @@ impl BuildValidator for FloatValidator {
         Ok(CombinedValidator::Float(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            warn_lossy: schema.get_as(intern!(py, "warn_lossy"))?.unwrap_or(false),
+            reject_subnormal: schema.get_as(intern!(py, "reject_subnormal"))?.unwrap_or(false),
         })
         .into())
     }
@@ -76,7 +117,13 @@ impl Validator for FloatValidator {
         if !self.allow_inf_nan && !either_float.as_f64().is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
-        Ok(either_float.into_py_any(py)?)
+        if self.reject_subnormal && either_float.as_f64().is_subnormal() {
+            return Err(ValError::new(ErrorTypeDefaults::FloatSubnormal, input));
+        }
+        if self.warn_lossy && is_lossy_float(input) {
+            warn_lossy_coercion(py, input, "float", state)?;
+        }
+        float_output(py, input, either_float, state)
     }
 
     fn get_name(&self) -> &str {
@@ -93,9 +140,31 @@ pub struct ConstrainedFloatValidator {
     lt: Option<f64>,
     ge: Option<f64>,
     gt: Option<f64>,
+    // keys to look up `le` and `ge` bounds from the validation context
+    le_context_key: Option<Py<PyString>>,
+    ge_context_key: Option<Py<PyString>>,
+    warn_lossy: bool,
+    reject_subnormal: bool,
 }
 
-impl_py_gc_traverse!(ConstrainedFloatValidator {});
+impl ConstrainedFloatValidator {
+    /// Get the bound stored under `key` in the validation context, if any
+    fn context_bound<'py>(
+        py: Python<'py>,
+        key: Option<&Py<PyString>>,
+        state: &ValidationState<'_, 'py>,
+    ) -> PyResult<Option<f64>> {
+        match key {
+            Some(key) => state.context_item(key.bind(py))?.map(|v| v.extract()).transpose(),
+            None => Ok(None),
+        }
+    }
+}
+
+impl_py_gc_traverse!(ConstrainedFloatValidator {
+    le_context_key,
+    ge_context_key
+});
 
 impl Validator for ConstrainedFloatValidator {
     fn validate<'py>(
@@ -109,6 +178,9 @@ impl Validator for ConstrainedFloatValidator {
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
+        if self.reject_subnormal && float.is_subnormal() {
+            return Err(ValError::new(ErrorTypeDefaults::FloatSubnormal, input));
+        }
         if let Some(multiple_of) = self.multiple_of {
             let tolerance = 1e-9;
             let rounded_div = (float / multiple_of).round();
@@ -128,7 +200,7 @@ impl Validator for ConstrainedFloatValidator {
                 return Err(ValError::new(
                     ErrorType::LessThanEqual {
                         le: le.into(),
-                        context: None,
+                        context: Some(comparison_context(py, float)?.unbind()),
                     },
                     input,
                 ));
@@ -139,7 +211,7 @@ impl Validator for ConstrainedFloatValidator {
                 return Err(ValError::new(
                     ErrorType::LessThan {
                         lt: lt.into(),
-                        context: None,
+                        context: Some(comparison_context(py, float)?.unbind()),
                     },
                     input,
                 ));
@@ -150,7 +222,7 @@ impl Validator for ConstrainedFloatValidator {
                 return Err(ValError::new(
                     ErrorType::GreaterThanEqual {
                         ge: ge.into(),
-                        context: None,
+                        context: Some(comparison_context(py, float)?.unbind()),
                     },
                     input,
                 ));
@@ -161,13 +233,38 @@ impl Validator for ConstrainedFloatValidator {
                 return Err(ValError::new(
                     ErrorType::GreaterThan {
                         gt: gt.into(),
-                        context: None,
+                        context: Some(comparison_context(py, float)?.unbind()),
                     },
                     input,
                 ));
             }
         }
-        Ok(either_float.into_py_any(py)?)
+        if let Some(le) = Self::context_bound(py, self.le_context_key.as_ref(), state)? {
+            if !matches!(float.partial_cmp(&le), Some(Ordering::Less | Ordering::Equal)) {
+                return Err(ValError::new(
+                    ErrorType::LessThanEqual {
+                        le: le.into(),
+                        context: Some(comparison_context(py, float)?.unbind()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ge) = Self::context_bound(py, self.ge_context_key.as_ref(), state)? {
+            if !matches!(float.partial_cmp(&ge), Some(Ordering::Greater | Ordering::Equal)) {
+                return Err(ValError::new(
+                    ErrorType::GreaterThanEqual {
+                        ge: ge.into(),
+                        context: Some(comparison_context(py, float)?.unbind()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if self.warn_lossy && is_lossy_float(input) {
+            warn_lossy_coercion(py, input, "float", state)?;
+        }
+        float_output(py, input, either_float, state)
     }
 
     fn get_name(&self) -> &'static str {
@@ -191,6 +288,10 @@ impl BuildValidator for ConstrainedFloatValidator {
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
             gt: schema.get_as(intern!(py, "gt"))?,
+            le_context_key: schema.get_as(intern!(py, "le_context_key"))?,
+            ge_context_key: schema.get_as(intern!(py, "ge_context_key"))?,
+            warn_lossy: schema.get_as(intern!(py, "warn_lossy"))?.unwrap_or(false),
+            reject_subnormal: schema.get_as(intern!(py, "reject_subnormal"))?.unwrap_or(false),
         })
         .into())
     }
@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
-use pyo3::types::{PyDict, PyFrozenSet};
+use pyo3::types::{PyDict, PyFrozenSet, PySet};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
-use crate::errors::ValResult;
+use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
@@ -20,11 +20,13 @@ pub struct FrozenSetValidator {
     max_length: Option<usize>,
     name: String,
     fail_fast: bool,
+    // reject `set` input, even in lax mode
+    reject_mutable_input: bool,
 }
 
 impl BuildValidator for FrozenSetValidator {
     const EXPECTED_TYPE: &'static str = "frozenset";
-    set_build!();
+    set_build!(reject_mutable_input);
 }
 
 impl_py_gc_traverse!(FrozenSetValidator { item_validator });
@@ -36,6 +38,9 @@ impl Validator for FrozenSetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if self.reject_mutable_input && input.as_python().is_some_and(PyAnyMethods::is_instance_of::<PySet>) {
+            return Err(ValError::new(ErrorTypeDefaults::FrozenSetType, input));
+        }
         let collection = input.validate_frozenset(state.strict_or(self.strict))?.unpack(state);
         let f_set = PyFrozenSet::empty(py)?;
         collection.iterate(ValidateToFrozenSet {
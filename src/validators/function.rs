@@ -109,9 +109,9 @@ impl FunctionBeforeValidator {
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
             let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
-            self.func.call1(py, (input.to_object(py)?, info))
+            self.func.call1(py, (state.input_to_object(py, input)?, info))
         } else {
-            self.func.call1(py, (input.to_object(py)?,))
+            self.func.call1(py, (state.input_to_object(py, input)?,))
         };
         let value = r.map_err(|e| convert_err(py, e, input))?;
         call(value.into_bound(py), state)
@@ -279,9 +279,9 @@ impl Validator for FunctionPlainValidator {
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
             let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
-            self.func.call1(py, (input.to_object(py)?, info))
+            self.func.call1(py, (state.input_to_object(py, input)?, info))
         } else {
-            self.func.call1(py, (input.to_object(py)?,))
+            self.func.call1(py, (state.input_to_object(py, input)?,))
         };
         r.map_err(|e| convert_err(py, e, input))
     }
@@ -349,9 +349,9 @@ impl FunctionWrapValidator {
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
             let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
-            self.func.call1(py, (input.to_object(py)?, handler, info))
+            self.func.call1(py, (state.input_to_object(py, input)?, handler, info))
         } else {
-            self.func.call1(py, (input.to_object(py)?, handler))
+            self.func.call1(py, (state.input_to_object(py, input)?, handler))
         };
         r.map_err(|e| convert_err(py, e, input))
     }
@@ -290,6 +290,10 @@ impl InternalValidator {
             cache_str: self.cache_str,
             by_alias: None,
             by_name: None,
+            json_decimals: None,
+            preserve_number_type: false,
+            deep_freeze: false,
+            track_locations: true,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard, false.into());
         state.exactness = self.exactness;
@@ -329,6 +333,10 @@ impl InternalValidator {
             cache_str: self.cache_str,
             by_alias: None,
             by_name: None,
+            json_decimals: None,
+            preserve_number_type: false,
+            deep_freeze: false,
+            track_locations: true,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard, false.into());
         state.exactness = self.exactness;
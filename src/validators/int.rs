@@ -9,8 +9,9 @@ use pyo3::IntoPyObjectExt;
 
 use crate::build_tools::is_strict;
 use crate::build_tools::LazyLock;
-use crate::errors::{ErrorType, ValError, ValResult};
+use crate::errors::{comparison_context, ErrorType, Number, ValError, ValResult};
 use crate::input::{Input, Int};
+use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -31,16 +32,56 @@ fn validate_as_int(schema: &Bound<'_, PyDict>, key: &Bound<'_, PyString>) -> PyR
     }
 }
 
+/// Warn that `input` lost information when coerced to `type_name`, for `warn_lossy`
+pub(super) fn warn_lossy_coercion<'py>(
+    py: Python<'py>,
+    input: &(impl Input<'py> + ?Sized),
+    type_name: &str,
+    state: &mut ValidationState<'_, 'py>,
+) -> PyResult<()> {
+    let message = format!("Lossy coercion to `{type_name}` from {}", input.to_object(py)?.repr()?);
+    state.warn(py, message)
+}
+
+/// Whether lax validation of `input` as an int discards information, i.e. the input was a float, decimal
+/// or a string with a fractional part, even if that fractional part was zero
+fn is_lossy_int<'py>(input: &(impl Input<'py> + ?Sized)) -> ValResult<bool> {
+    if input.validate_int(true).is_ok() || input.validate_bool(true).is_ok() {
+        return Ok(false);
+    }
+    match input.validate_str(true, false) {
+        Ok(val_match) => Ok(val_match.into_inner().as_cow()?.contains('.')),
+        // not a string, so a float or decimal
+        Err(ValError::LineErrors(_)) => Ok(true),
+        Err(err) => Err(err),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    warn_lossy: bool,
 }
 
-static STRICT_INT_VALIDATOR: LazyLock<Arc<CombinedValidator>> =
-    LazyLock::new(|| Arc::new(IntValidator { strict: true }.into()));
+static STRICT_INT_VALIDATOR: LazyLock<Arc<CombinedValidator>> = LazyLock::new(|| {
+    Arc::new(
+        IntValidator {
+            strict: true,
+            warn_lossy: false,
+        }
+        .into(),
+    )
+});
 
-static LAX_INT_VALIDATOR: LazyLock<Arc<CombinedValidator>> =
-    LazyLock::new(|| Arc::new(IntValidator { strict: false }.into()));
+static LAX_INT_VALIDATOR: LazyLock<Arc<CombinedValidator>> = LazyLock::new(|| {
+    Arc::new(
+        IntValidator {
+            strict: false,
+            warn_lossy: false,
+        }
+        .into(),
+    )
+});
 
 impl BuildValidator for IntValidator {
     const EXPECTED_TYPE: &'static str = "int";
@@ -55,10 +96,20 @@ impl BuildValidator for IntValidator {
             || schema.get_item(intern!(py, "le"))?.is_some()
             || schema.get_item(intern!(py, "lt"))?.is_some()
             || schema.get_item(intern!(py, "ge"))?.is_some()
-            || schema.get_item(intern!(py, "gt"))?.is_some();
+            || schema.get_item(intern!(py, "gt"))?.is_some()
+            || schema.get_item(intern!(py, "le_context_key"))?.is_some()
+            || schema.get_item(intern!(py, "ge_context_key"))?.is_some();
 
         if use_constrained {
             ConstrainedIntValidator::build(schema, config)
+        } else if schema.get_as(intern!(py, "warn_lossy"))?.unwrap_or(false) {
+            Ok(Arc::new(
+                IntValidator {
+                    strict: is_strict(schema, config)?,
+                    warn_lossy: true,
+                }
+                .into(),
+            ))
         } else if is_strict(schema, config)? {
             Ok(STRICT_INT_VALIDATOR.clone())
         } else {
@@ -76,9 +127,11 @@ impl Validator for IntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        input
-            .validate_int(state.strict_or(self.strict))
-            .and_then(|val_match| Ok(val_match.unpack(state).into_py_any(py)?))
+        let either_int = input.validate_int(state.strict_or(self.strict))?.unpack(state);
+        if self.warn_lossy && is_lossy_int(input)? {
+            warn_lossy_coercion(py, input, Self::EXPECTED_TYPE, state)?;
+        }
+        Ok(either_int.into_py_any(py)?)
     }
 
     fn get_name(&self) -> &str {
@@ -94,6 +147,10 @@ pub struct ConstrainedIntValidator {
     lt: Option<Int>,
     ge: Option<Int>,
     gt: Option<Int>,
+    // keys to look up `le` and `ge` bounds from the validation context
+    le_context_key: Option<Py<PyString>>,
+    ge_context_key: Option<Py<PyString>>,
+    warn_lossy: bool,
 }
 
 impl ConstrainedIntValidator {
@@ -106,12 +163,30 @@ impl ConstrainedIntValidator {
             lt: validate_as_int(schema, intern!(py, "lt"))?,
             ge: validate_as_int(schema, intern!(py, "ge"))?,
             gt: validate_as_int(schema, intern!(py, "gt"))?,
+            le_context_key: schema.get_as(intern!(py, "le_context_key"))?,
+            ge_context_key: schema.get_as(intern!(py, "ge_context_key"))?,
+            warn_lossy: schema.get_as(intern!(py, "warn_lossy"))?.unwrap_or(false),
         })
         .into())
     }
+
+    /// Get the bound stored under `key` in the validation context, if any
+    fn context_bound<'py>(
+        py: Python<'py>,
+        key: Option<&Py<PyString>>,
+        state: &ValidationState<'_, 'py>,
+    ) -> PyResult<Option<Int>> {
+        match key {
+            Some(key) => state.context_item(key.bind(py))?.map(|v| v.extract()).transpose(),
+            None => Ok(None),
+        }
+    }
 }
 
-impl_py_gc_traverse!(ConstrainedIntValidator {});
+impl_py_gc_traverse!(ConstrainedIntValidator {
+    le_context_key,
+    ge_context_key
+});
 
 impl Validator for ConstrainedIntValidator {
     fn validate<'py>(
@@ -139,7 +214,7 @@ impl Validator for ConstrainedIntValidator {
                 return Err(ValError::new(
                     ErrorType::LessThanEqual {
                         le: le.clone().into(),
-                        context: None,
+                        context: Some(comparison_context(py, Number::from(int_value.clone()))?.unbind()),
                     },
                     input,
                 ));
@@ -150,7 +225,7 @@ impl Validator for ConstrainedIntValidator {
                 return Err(ValError::new(
                     ErrorType::LessThan {
                         lt: lt.clone().into(),
-                        context: None,
+                        context: Some(comparison_context(py, Number::from(int_value.clone()))?.unbind()),
                     },
                     input,
                 ));
@@ -161,7 +236,7 @@ impl Validator for ConstrainedIntValidator {
                 return Err(ValError::new(
                     ErrorType::GreaterThanEqual {
                         ge: ge.clone().into(),
-                        context: None,
+                        context: Some(comparison_context(py, Number::from(int_value.clone()))?.unbind()),
                     },
                     input,
                 ));
@@ -172,12 +247,37 @@ impl Validator for ConstrainedIntValidator {
                 return Err(ValError::new(
                     ErrorType::GreaterThan {
                         gt: gt.clone().into(),
-                        context: None,
+                        context: Some(comparison_context(py, Number::from(int_value.clone()))?.unbind()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(le) = Self::context_bound(py, self.le_context_key.as_ref(), state)? {
+            if int_value > le {
+                return Err(ValError::new(
+                    ErrorType::LessThanEqual {
+                        le: le.into(),
+                        context: Some(comparison_context(py, Number::from(int_value.clone()))?.unbind()),
                     },
                     input,
                 ));
             }
         }
+        if let Some(ge) = Self::context_bound(py, self.ge_context_key.as_ref(), state)? {
+            if int_value < ge {
+                return Err(ValError::new(
+                    ErrorType::GreaterThanEqual {
+                        ge: ge.into(),
+                        context: Some(comparison_context(py, Number::from(int_value.clone()))?.unbind()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if self.warn_lossy && is_lossy_int(input)? {
+            warn_lossy_coercion(py, input, "int", state)?;
+        }
         Ok(either_int.into_py_any(py)?)
     }
 
@@ -1,10 +1,18 @@
+use std::borrow::Cow;
+use std::ffi::CString;
 use std::sync::Arc;
 
+use ahash::AHashSet;
+
+use pyo3::exceptions::PyUserWarning;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::PyTypeInfo;
 
-use jiter::{FloatMode, JsonValue, PythonParse};
+use jiter::{
+    FloatMode, Jiter, JiterResult, JsonError, JsonErrorType, JsonValue, LinePosition, PartialMode, Peek, PythonParse,
+};
 
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{EitherBytes, Input, InputType, ValidationMatch};
@@ -113,9 +121,205 @@ fn map_bytes_error(line_error: ValLineError) -> ValLineError {
 pub fn map_json_err<'py>(input: &(impl Input<'py> + ?Sized), error: jiter::JsonError, json_bytes: &[u8]) -> ValError {
     ValError::new(
         ErrorType::JsonInvalid {
-            error: error.description(json_bytes),
+            error: format!("{} at {}", error.error_type, char_position(json_bytes, error.index)),
             context: None,
         },
         input,
     )
 }
+
+/// The line and column of the byte `index` in `json_bytes` as given by `LinePosition::find`, except the column
+/// counts characters rather than bytes so it's the same whether the JSON was given as `str` or UTF-8 `bytes`
+fn char_position(json_bytes: &[u8], index: usize) -> LinePosition {
+    // like `LinePosition::find`, a position past the end of the input is reported at the last character
+    let data = &json_bytes[..json_bytes.len().min(index.saturating_add(1))];
+    let mut line = 1;
+    let mut line_start = 0;
+    for (i, b) in data.iter().enumerate() {
+        if *b == b'\n' {
+            line += 1;
+            line_start = i + 1;
+        }
+    }
+    // count the bytes which start a UTF-8 sequence, i.e. aren't continuation bytes
+    let column = data[line_start..]
+        .iter()
+        .filter(|b| (**b & 0b1100_0000) != 0b1000_0000)
+        .count();
+    LinePosition::new(line, column)
+}
+
+// more significant digits than this can't always be represented exactly by an `f64`
+const FLOAT_SIGNIFICANT_DIGITS: usize = 17;
+
+/// Emit a `UserWarning` for each anomaly in `json_bytes` which jiter would reject or silently lose information on,
+/// see `json_warnings`: lone surrogate escapes in strings are replaced with `\ufffd` so the JSON can be parsed,
+/// and numbers decoded as floats with more significant digits than a float can hold are reported
+pub fn warn_json_anomalies<'a>(py: Python<'_>, json_bytes: &'a [u8], float_mode: FloatMode) -> PyResult<Cow<'a, [u8]>> {
+    let mut output = Cow::Borrowed(json_bytes);
+    let mut warnings = Vec::new();
+    // jiter stops at the first lone surrogate, so patch each one it finds and parse again, any other error is
+    // left for the real parse to report
+    while let Err(error) = JsonValue::parse(&output, true) {
+        let Some((index, code)) = lone_surrogate(&output, &error) else {
+            break;
+        };
+        warnings.push(format!(
+            "JSON string has a lone surrogate escape `\\u{code:04x}` at {}, replaced with U+FFFD",
+            char_position(&output, index)
+        ));
+        output.to_mut()[index..index + 6].copy_from_slice(b"\\ufffd");
+    }
+    if matches!(float_mode, FloatMode::Float) {
+        let mut jiter = Jiter::new(&output).with_allow_inf_nan();
+        // as above, errors are reported by the real parse
+        let _ = find_imprecise_floats(&mut jiter, &output, &mut warnings);
+    }
+
+    let user_warning_type = PyUserWarning::type_object(py);
+    for warning in warnings {
+        PyErr::warn(py, &user_warning_type, &CString::new(warning)?, 0)?;
+    }
+    Ok(output)
+}
+
+/// The start and code unit of the lone surrogate escape jiter failed to parse with `error`, if that's why it failed,
+/// jiter reports the end of a lone trailing surrogate escape, or of the escape after a leading surrogate
+fn lone_surrogate(json_bytes: &[u8], error: &JsonError) -> Option<(usize, u32)> {
+    let escape = |offset| {
+        let index = error.index.checked_sub(offset)?;
+        hex_escape(json_bytes, index).map(|code| (index, code))
+    };
+    match error.error_type {
+        JsonErrorType::LoneLeadingSurrogateInHexEscape => match escape(5) {
+            Some(trailing @ (_, 0xDC00..=0xDFFF)) => Some(trailing),
+            _ => escape(11),
+        },
+        JsonErrorType::UnexpectedEndOfHexEscape => escape(6).or_else(|| escape(7)),
+        _ => None,
+    }
+    .filter(|(_, code)| (0xD800..=0xDFFF).contains(code))
+}
+
+/// Warn about each number in the value at `jiter` which is decoded as a float but has more significant digits
+/// than a float can hold
+fn find_imprecise_floats(jiter: &mut Jiter<'_>, json_bytes: &[u8], warnings: &mut Vec<String>) -> JiterResult<()> {
+    let peek = jiter.peek()?;
+    match peek {
+        Peek::Array => {
+            let mut item = jiter.known_array()?;
+            while item.is_some() {
+                find_imprecise_floats(jiter, json_bytes, warnings)?;
+                item = jiter.array_step()?;
+            }
+        }
+        Peek::Object => {
+            let mut key = jiter.known_object()?.is_some();
+            while key {
+                find_imprecise_floats(jiter, json_bytes, warnings)?;
+                key = jiter.next_key()?.is_some();
+            }
+        }
+        peek if peek.is_num() => {
+            let start = jiter.current_index();
+            let number = jiter.next_number_bytes()?;
+            if significant_digits(number) > FLOAT_SIGNIFICANT_DIGITS {
+                warnings.push(format!(
+                    "JSON number `{}` at {} has more significant digits than a float can hold, so may lose precision",
+                    String::from_utf8_lossy(number),
+                    char_position(json_bytes, start)
+                ));
+            }
+        }
+        peek => jiter.known_skip(peek)?,
+    }
+    Ok(())
+}
+
+/// The code unit of the `\uXXXX` escape starting at `index`, if there is one
+fn hex_escape(json_bytes: &[u8], index: usize) -> Option<u32> {
+    let escape = json_bytes.get(index..index + 6)?;
+    if !escape.starts_with(b"\\u") {
+        return None;
+    }
+    u32::from_str_radix(std::str::from_utf8(&escape[2..]).ok()?, 16).ok()
+}
+
+/// The number of significant digits in the mantissa of a JSON number with a fractional part or exponent,
+/// integers are decoded exactly so are always `0`
+fn significant_digits(number: &[u8]) -> usize {
+    if !number.iter().any(|b| matches!(b, b'.' | b'e' | b'E')) {
+        return 0;
+    }
+    let mantissa_end = number
+        .iter()
+        .position(|b| matches!(b, b'e' | b'E'))
+        .unwrap_or(number.len());
+    let digits: Vec<u8> = number[..mantissa_end]
+        .iter()
+        .copied()
+        .filter(u8::is_ascii_digit)
+        .collect();
+    let start = digits.iter().position(|d| *d != b'0').unwrap_or(digits.len());
+    let end = digits.iter().rposition(|d| *d != b'0').map_or(start, |i| i + 1);
+    end - start
+}
+
+/// Parse `json_bytes` and check no object in it has duplicate keys, see `forbid_duplicate_keys`
+pub fn check_duplicate_keys<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    json_bytes: &[u8],
+    allow_partial: PartialMode,
+) -> ValResult<()> {
+    let json_value = JsonValue::parse_with_config(json_bytes, true, allow_partial)
+        .map_err(|e| map_json_err(input, e, json_bytes))?;
+    forbid_duplicate_keys(&json_value)
+}
+
+/// Error for each key which appears more than once in an object within `json_value`,
+/// jiter keeps every key of an object, later values win when the object is validated
+pub fn forbid_duplicate_keys(json_value: &JsonValue<'_>) -> ValResult<()> {
+    let errors = duplicate_key_errors(json_value);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValError::LineErrors(errors))
+    }
+}
+
+fn duplicate_key_errors(json_value: &JsonValue<'_>) -> Vec<ValLineError> {
+    let mut errors = Vec::new();
+    match json_value {
+        JsonValue::Object(object) => {
+            let mut seen = AHashSet::with_capacity(object.len());
+            let mut reported = AHashSet::new();
+            for (key, value) in object.iter() {
+                if !seen.insert(key.as_ref()) && reported.insert(key.as_ref()) {
+                    errors.push(ValLineError::new(
+                        ErrorType::JsonDuplicateKey {
+                            key: key.to_string(),
+                            context: None,
+                        },
+                        json_value,
+                    ));
+                }
+                errors.extend(
+                    duplicate_key_errors(value)
+                        .into_iter()
+                        .map(|err| err.with_outer_location(key.as_ref())),
+                );
+            }
+        }
+        JsonValue::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                errors.extend(
+                    duplicate_key_errors(value)
+                        .into_iter()
+                        .map(|err| err.with_outer_location(index)),
+                );
+            }
+        }
+        _ => {}
+    }
+    errors
+}
@@ -1,24 +1,51 @@
 use std::sync::{Arc, OnceLock};
 
-use pyo3::types::PyDict;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
-use crate::errors::ValResult;
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{
     no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, ValidatedList,
 };
 use crate::tools::SchemaDict;
 
+use super::any::{deep_freeze, AnyValidator};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
 pub struct ListValidator {
     strict: bool,
     item_validator: Option<Arc<CombinedValidator>>,
+    // validators for the first items by position, `item_validator` applies to the rest
+    prefix_validators: Vec<Arc<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
     name: OnceLock<String>,
     fail_fast: bool,
+    // whether tuples are accepted (and converted to lists) in lax mode
+    tuple_coercion: bool,
+    // lists shorter than this are padded with `pad_value`, after validation and the length checks
+    pad_to: Option<usize>,
+    pad_value: Py<PyAny>,
+    // `pad_value` validated against the items schema, set once the whole validator is built
+    valid_pad_value: OnceLock<Py<PyAny>>,
+    // lists longer than this have their extra items dropped, after validation and the length checks
+    truncate_to: Option<usize>,
+    // output a `collections.deque` with this `maxlen` instead of a list, after the length checks
+    deque: Option<DequeOutput>,
+}
+
+#[derive(Debug)]
+struct DequeOutput {
+    maxlen: Option<usize>,
+}
+
+static DEQUE_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+pub(crate) fn get_deque_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    DEQUE_TYPE.import(py, "collections", "deque")
 }
 
 pub fn get_items_schema(
@@ -103,20 +130,109 @@ impl BuildValidator for ListValidator {
         definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
     ) -> PyResult<Arc<CombinedValidator>> {
         let py = schema.py();
-        let item_validator = get_items_schema(schema, config, definitions)?;
-        Ok(CombinedValidator::List(Self {
+        let mut item_validator = get_items_schema(schema, config, definitions)?;
+        let prefix_validators: Vec<Arc<CombinedValidator>> =
+            match schema.get_as::<Bound<'_, PyList>>(pyo3::intern!(py, "prefix_items"))? {
+                Some(items) => items
+                    .iter()
+                    .map(|item| build_validator(&item, config, definitions))
+                    .collect::<PyResult<_>>()?,
+                None => Vec::new(),
+            };
+        let maxlen: Option<usize> = schema.get_as(pyo3::intern!(py, "maxlen"))?;
+        let deque = match schema.get_as::<Bound<'_, PyString>>(pyo3::intern!(py, "collection"))? {
+            Some(collection) if collection.to_str()? == "deque" => Some(DequeOutput { maxlen }),
+            Some(collection) if collection.to_str()? != "list" => {
+                return py_schema_err!("Invalid collection: {}, should be 'list' or 'deque'", collection)
+            }
+            _ if maxlen.is_some() => return py_schema_err!("`maxlen` can only be used with collection='deque'"),
+            _ => None,
+        };
+        let pad_to: Option<usize> = schema.get_as(pyo3::intern!(py, "pad_to"))?;
+        let max_length: Option<usize> = schema.get_as(pyo3::intern!(py, "max_length"))?;
+        if let Some(pad_to) = pad_to {
+            if max_length.is_some_and(|max_length| pad_to > max_length) {
+                return py_schema_err!("`pad_to` ({}) can't be greater than `max_length`", pad_to);
+            }
+            if !prefix_validators.is_empty() {
+                return py_schema_err!("`pad_to` can't be used with `prefix_items`");
+            }
+        }
+        let pad_value = schema
+            .get_as(pyo3::intern!(py, "pad_value"))?
+            .unwrap_or_else(|| py.None());
+        let valid_pad_value = OnceLock::new();
+        if item_validator.is_none() {
+            valid_pad_value.get_or_init(|| pad_value.clone_ref(py));
+        }
+        if !prefix_validators.is_empty() && item_validator.is_none() {
+            // items after the prefix still need validating, so they're passed through unchanged
+            item_validator = Some(AnyValidator::build(schema, config, definitions)?);
+        }
+        // the items validator might reference definitions which aren't filled yet, so checking `pad_value`
+        // has to wait until the whole validator is built
+        let validate_pad_value = pad_to.is_some() && item_validator.is_some();
+        let validator: Arc<CombinedValidator> = CombinedValidator::List(Self {
             strict: crate::build_tools::is_strict(schema, config)?,
             item_validator,
+            prefix_validators,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
-            max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
+            max_length,
             name: OnceLock::new(),
             fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+            tuple_coercion: schema.get_as(pyo3::intern!(py, "tuple_coercion"))?.unwrap_or(true),
+            pad_to,
+            pad_value,
+            valid_pad_value,
+            truncate_to: schema.get_as(pyo3::intern!(py, "truncate_to"))?,
+            deque,
         })
-        .into())
+        .into();
+        if validate_pad_value {
+            definitions.defer(validator.clone());
+        }
+        Ok(validator)
     }
 }
 
-impl_py_gc_traverse!(ListValidator { item_validator });
+impl_py_gc_traverse!(ListValidator {
+    item_validator,
+    prefix_validators,
+    pad_value,
+    valid_pad_value
+});
+
+impl ListValidator {
+    // whether the output may differ from a copy of the input list other than by validating items
+    fn transforms(&self) -> bool {
+        self.pad_to.is_some() || self.truncate_to.is_some() || self.deque.is_some()
+    }
+
+    /// Apply `truncate_to` and `pad_to` to the validated items
+    fn resize(&self, py: Python<'_>, mut output: Vec<Py<PyAny>>) -> Vec<Py<PyAny>> {
+        if let Some(truncate_to) = self.truncate_to {
+            output.truncate(truncate_to);
+        }
+        if let Some(pad_to) = self.pad_to {
+            let pad_value = self.valid_pad_value.get().unwrap_or(&self.pad_value);
+            output.resize_with(pad_to.max(output.len()), || pad_value.clone_ref(py));
+        }
+        output
+    }
+
+    /// Validate `pad_value` against the items schema, used once the whole validator is built
+    pub fn validate_pad_value_on_build<'py>(
+        &self,
+        py: Python<'py>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<()> {
+        if let Some(item_validator) = &self.item_validator {
+            let pad_value = item_validator.validate(py, self.pad_value.bind(py), state)?;
+            self.valid_pad_value.get_or_init(|| pad_value);
+        }
+        Ok(())
+    }
+}
 
 impl Validator for ListValidator {
     fn validate<'py>(
@@ -125,6 +241,9 @@ impl Validator for ListValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if !self.tuple_coercion && input.as_python().is_some_and(PyAnyMethods::is_instance_of::<PyTuple>) {
+            return Err(ValError::new(ErrorTypeDefaults::ListType, input));
+        }
         let seq = input.validate_list(state.strict_or(self.strict))?.unpack(state);
 
         let actual_length = seq.len();
@@ -136,26 +255,49 @@ impl Validator for ListValidator {
                 max_length: self.max_length,
                 field_type: "List",
                 item_validator: v,
+                prefix_validators: &self.prefix_validators,
                 state,
                 fail_fast: self.fail_fast,
             })??,
             None => {
-                if let Some(py_list) = seq.as_py_list() {
+                if let Some(py_list) = seq.as_py_list().filter(|_| !self.transforms()) {
                     length_check!(input, "List", self.min_length, self.max_length, py_list);
+                    if state.extra().deep_freeze {
+                        return Ok(deep_freeze(py_list.as_any())?.unbind());
+                    }
                     let list_copy = py_list.get_slice(0, usize::MAX);
                     return Ok(list_copy.into());
                 }
 
-                seq.iterate(ToVec {
+                let output = seq.iterate(ToVec {
                     py,
                     input,
                     actual_length,
                     max_length: self.max_length,
                     field_type: "List",
-                })??
+                    state,
+                })??;
+                if state.extra().deep_freeze {
+                    // without an item validator the items are unvalidated so have to be frozen here
+                    output
+                        .iter()
+                        .map(|item| Ok(deep_freeze(item.bind(py))?.unbind()))
+                        .collect::<PyResult<_>>()?
+                } else {
+                    output
+                }
             }
         };
         min_length_check!(input, "List", self.min_length, output);
+        let output = self.resize(py, output);
+        if let Some(DequeOutput { maxlen }) = self.deque {
+            // like `collections.deque`, items beyond `maxlen` are dropped from the left, with `deep_freeze` the
+            // items are frozen but the deque is kept, as a tuple couldn't keep its `maxlen`
+            return Ok(get_deque_type(py)?.call1((output, maxlen))?.unbind());
+        }
+        if state.extra().deep_freeze {
+            return Ok(PyTuple::new(py, output)?.into_py_any(py)?);
+        }
         Ok(output.into_py_any(py)?)
     }
 
@@ -186,6 +328,7 @@ struct ValidateToVec<'a, 's, 'py, I: Input<'py> + ?Sized> {
     max_length: Option<usize>,
     field_type: &'static str,
     item_validator: &'a CombinedValidator,
+    prefix_validators: &'a [Arc<CombinedValidator>],
     state: &'a mut ValidationState<'s, 'py>,
     fail_fast: bool,
 }
@@ -207,27 +350,29 @@ where
             capacity,
             max_length_check,
             self.item_validator,
+            self.prefix_validators,
             self.state,
             self.fail_fast,
         )
     }
 }
 
-struct ToVec<'a, 'py, I: Input<'py> + ?Sized> {
+struct ToVec<'a, 's, 'py, I: Input<'py> + ?Sized> {
     py: Python<'py>,
     input: &'a I,
     actual_length: Option<usize>,
     max_length: Option<usize>,
     field_type: &'static str,
+    state: &'a ValidationState<'s, 'py>,
 }
 
-impl<'py, T, I: Input<'py> + ?Sized> ConsumeIterator<PyResult<T>> for ToVec<'_, 'py, I>
+impl<'py, T, I: Input<'py> + ?Sized> ConsumeIterator<PyResult<T>> for ToVec<'_, '_, 'py, I>
 where
     T: BorrowInput<'py>,
 {
     type Output = ValResult<Vec<Py<PyAny>>>;
     fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<Vec<Py<PyAny>>> {
         let max_length_check = MaxLengthCheck::new(self.max_length, self.field_type, self.input, self.actual_length);
-        no_validator_iter_to_vec(self.py, self.input, iterator, max_length_check)
+        no_validator_iter_to_vec(self.py, self.input, iterator, max_length_check, self.state)
     }
 }
@@ -1,6 +1,7 @@
 // Validator for things inside of a typing.Literal[]
 // which can be an int, a string, bytes or an Enum value (including `class Foo(str, Enum)` type enums)
 use core::fmt::Debug;
+use std::borrow::Cow;
 use std::cell::OnceCell;
 use std::sync::Arc;
 
@@ -41,12 +42,23 @@ pub struct LiteralLookup<T: Debug> {
     // which we can't mix with `expected_py_dict`, as there may be conflicts
     // for an example, see tests/test_validators/test_literal.py::test_mix_int_enum_with_int
     expected_py_primitives: Option<Py<PyDict>>,
+    // whether `expected_str` is keyed by ASCII lowercase strings, to match strings ignoring case
+    case_insensitive: bool,
 
     pub values: Vec<T>,
 }
 
 impl<T: Debug> LiteralLookup<T> {
     pub fn new<'py>(py: Python<'py>, expected: impl Iterator<Item = (Bound<'py, PyAny>, T)>) -> PyResult<Self> {
+        Self::new_with_case(py, expected, false)
+    }
+
+    /// With `case_insensitive`, string inputs match the expected strings ignoring ASCII case
+    pub fn new_with_case<'py>(
+        py: Python<'py>,
+        expected: impl Iterator<Item = (Bound<'py, PyAny>, T)>,
+        case_insensitive: bool,
+    ) -> PyResult<Self> {
         let mut expected_bool = BoolLiteral::default();
         let mut expected_int = AHashMap::new();
         let mut expected_str: AHashMap<String, usize> = AHashMap::new();
@@ -78,7 +90,13 @@ impl<T: Debug> LiteralLookup<T> {
                 let str = either_str
                     .as_cow()
                     .map_err(|_| py_schema_error_type!("error extracting str {:?}", k))?;
-                expected_str.insert(str.to_string(), id);
+                if case_insensitive {
+                    if expected_str.insert(str.to_ascii_lowercase(), id).is_some() {
+                        return py_schema_err!("`expected` has strings which only differ in case: {}", k.repr()?);
+                    }
+                } else {
+                    expected_str.insert(str.to_string(), id);
+                }
                 expected_py_primitives.set_item(&k, id)?;
             } else if expected_py_dict.set_item(&k, id).is_err() {
                 expected_py_values.push((k.as_unbound().clone_ref(py), id));
@@ -93,6 +111,7 @@ impl<T: Debug> LiteralLookup<T> {
             expected_py_dict: (!expected_py_dict.is_empty()).then_some(expected_py_dict.into()),
             expected_py_values: (!expected_py_values.is_empty()).then_some(expected_py_values),
             expected_py_primitives: (!expected_py_primitives.is_empty()).then_some(expected_py_primitives.into()),
+            case_insensitive,
             values,
         })
     }
@@ -135,7 +154,7 @@ impl<T: Debug> LiteralLookup<T> {
 
             if let Ok(either_str) = validation_result {
                 let cow = either_str.as_cow()?;
-                if let Some(id) = expected_strings.get(cow.as_ref()) {
+                if let Some(id) = expected_strings.get(self.str_key(&cow).as_ref()) {
                     return Ok(Some((input, &self.values[*id])));
                 }
             }
@@ -184,6 +203,15 @@ impl<T: Debug> LiteralLookup<T> {
         Ok(None)
     }
 
+    /// The key of `s` in `expected_str`
+    fn str_key<'s>(&self, s: &'s str) -> Cow<'s, str> {
+        if self.case_insensitive {
+            Cow::Owned(s.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(s)
+        }
+    }
+
     /// Used by int enums
     pub fn validate_int<'a, 'py, I: Input<'py> + ?Sized>(
         &self,
@@ -207,7 +235,7 @@ impl<T: Debug> LiteralLookup<T> {
         if let Some(expected_strings) = &self.expected_str {
             if let Ok(either_str) = input.validate_str(strict, false) {
                 let s = either_str.into_inner();
-                if let Some(id) = expected_strings.get(s.as_cow()?.as_ref()) {
+                if let Some(id) = expected_strings.get(self.str_key(&s.as_cow()?).as_ref()) {
                     return Ok(Some(&self.values[*id]));
                 }
             }
@@ -268,7 +296,12 @@ impl BuildValidator for LiteralValidator {
             repr_args.push(item.repr()?.extract()?);
         }
         let (expected_repr, name) = expected_repr_name(repr_args, "literal");
-        let lookup = LiteralLookup::new(py, expected.into_iter().map(|v| (v.clone(), v.into())))?;
+        let case_insensitive = schema.get_as(intern!(py, "case_insensitive"))?.unwrap_or(false);
+        let lookup = LiteralLookup::new_with_case(
+            py,
+            expected.into_iter().map(|v| (v.clone(), v.into())),
+            case_insensitive,
+        )?;
         Ok(CombinedValidator::Literal(Self {
             lookup,
             expected_repr,
@@ -1,19 +1,20 @@
+use std::borrow::Cow;
 use std::fmt::Debug;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use enum_dispatch::enum_dispatch;
-use jiter::{PartialMode, StringCacheMode};
+use jiter::{FloatMode, PartialMode, PythonParse, StringCacheMode};
 
 use pyo3::exceptions::{PyTypeError, PyValueError};
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple, PyType};
+use pyo3::types::{PyAny, PyDict, PyList, PySet, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
-use crate::build_tools::{py_schema_err, py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{py_schema_err, py_schema_error_type, ExtraBehavior, SchemaError};
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{LocItem, ValError, ValResult, ValidationError};
-use crate::input::{Input, InputType, StringMapping};
+use crate::errors::{LocItem, Location, ValError, ValResult, ValidationError};
+use crate::input::{Input, InputType, JsonDecimals, StringMapping};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionState;
 use crate::tools::SchemaDict;
@@ -26,6 +27,7 @@ mod bool;
 mod bytes;
 mod call;
 mod callable;
+mod canonical_hash;
 mod chain;
 pub(crate) mod complex;
 mod config;
@@ -47,7 +49,7 @@ mod is_subclass;
 mod json;
 mod json_or_python;
 mod lax_or_strict;
-mod list;
+pub(crate) mod list;
 mod literal;
 mod missing_sentinel;
 mod model;
@@ -67,7 +69,8 @@ mod uuid;
 mod validation_state;
 mod with_default;
 
-pub use self::validation_state::{Exactness, ValidationState};
+pub use self::validation_state::{Exactness, MemberRecordings, Recordings, RecordingsCheckpoint, ValidationState};
+pub use literal::LiteralLookup;
 pub use with_default::DefaultType;
 
 #[pyclass(module = "pydantic_core._pydantic_core", name = "Some")]
@@ -105,6 +108,26 @@ impl PySome {
     }
 }
 
+/// What `validate_python` returns when asked for more than the validated value, attributes which weren't asked
+/// for are `None`
+#[pyclass(module = "pydantic_core._pydantic_core", frozen, get_all)]
+pub struct ValidationResult {
+    value: Py<PyAny>,
+    changed: Option<bool>,
+    hash: Option<u64>,
+    field_timings: Option<Py<PyDict>>,
+    union_matches: Option<Vec<Py<PyTuple>>>,
+    extra_types: Option<Py<PyDict>>,
+    defaults_used: Option<Py<PySet>>,
+}
+
+#[pymethods]
+impl ValidationResult {
+    pub fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("ValidationResult(value={})", self.value.bind(py).repr()?))
+    }
+}
+
 #[pyclass(module = "pydantic_core._pydantic_core", frozen)]
 #[derive(Debug)]
 pub struct SchemaValidator {
@@ -136,6 +159,7 @@ impl SchemaValidator {
         let mut definitions_builder = DefinitionsBuilder::new();
 
         let validator = build_validator_base(schema, config, &mut definitions_builder)?;
+        let deferred = definitions_builder.take_deferred();
         let definitions = definitions_builder.finish()?;
         let py_schema = schema.clone().unbind();
         let py_config = match config {
@@ -148,13 +172,17 @@ impl SchemaValidator {
         };
         let title = match config_title {
             Some(t) => t.unbind(),
-            None => validator.get_name().into_py_any(py)?,
+            None => match validator.get_title() {
+                Some(t) => t.into_py_any(py)?,
+                None => validator.get_name().into_py_any(py)?,
+            },
         };
         let hide_input_in_errors: bool = config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false);
         let validation_error_cause: bool = config.get_as(intern!(py, "validation_error_cause"))?.unwrap_or(false);
         let cache_str: StringCacheMode = config
             .get_as(intern!(py, "cache_strings"))?
             .unwrap_or(StringCacheMode::All);
+        validate_deferred_on_build(py, &deferred, cache_str)?;
         Ok(Self {
             validator,
             definitions,
@@ -168,7 +196,7 @@ impl SchemaValidator {
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, extra=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, extra=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, report_changes=false, with_hash=false, track_locations=true, intern_strings=false, profile=false, union_matches=false, deep_freeze=false, extra_types=false, defaults_used=false))]
     pub fn validate_python(
         &self,
         py: Python,
@@ -181,26 +209,176 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        report_changes: bool,
+        with_hash: bool,
+        track_locations: bool,
+        intern_strings: bool,
+        profile: bool,
+        union_matches: bool,
+        deep_freeze: bool,
+        extra_types: bool,
+        defaults_used: bool,
     ) -> PyResult<Py<PyAny>> {
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
             .transpose()?;
 
-        #[allow(clippy::used_underscore_items)]
-        self._validate(
-            py,
-            input,
-            InputType::Python,
+        let mut extra = Extra::new(
             strict,
             extra_behavior,
             from_attributes,
             context,
             self_instance,
-            allow_partial,
+            InputType::Python,
+            self.cache_str,
             by_alias,
             by_name,
-        )
-        .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+        );
+        extra.track_locations = track_locations;
+        extra.deep_freeze = deep_freeze;
+        let mut recordings = Recordings {
+            field_timings: profile.then(Vec::new),
+            union_matches: union_matches.then(Vec::new),
+            extra_types: extra_types.then(Vec::new),
+            defaults_used: defaults_used.then(Vec::new),
+        };
+        #[allow(clippy::used_underscore_items)]
+        let output = self
+            ._validate(py, input, extra, allow_partial, intern_strings, &mut recordings)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+
+        if !(report_changes || with_hash || profile || union_matches || extra_types || defaults_used) {
+            return Ok(output);
+        }
+        let field_timings = match recordings.field_timings {
+            Some(field_timings) => {
+                let timings = PyDict::new(py);
+                for (loc_path, nanoseconds) in field_timings {
+                    // a field validated more than once at the same location, e.g. by several union members,
+                    // has the time taken by each validation added up
+                    let loc_path = PyTuple::new(py, &loc_path)?;
+                    let previous: u64 = timings.get_item(&loc_path)?.map_or(Ok(0), |t| t.extract())?;
+                    timings.set_item(loc_path, previous + nanoseconds)?;
+                }
+                Some(timings.unbind())
+            }
+            None => None,
+        };
+        ValidationResult {
+            changed: report_changes
+                .then(|| output_changed(input, output.bind(py)))
+                .transpose()?,
+            hash: with_hash
+                .then(|| canonical_hash::canonical_hash(output.bind(py)))
+                .transpose()?,
+            field_timings,
+            union_matches: recordings
+                .union_matches
+                .map(|union_matches| {
+                    union_matches
+                        .into_iter()
+                        .map(|(loc_path, choice)| {
+                            Ok((PyTuple::new(py, &loc_path)?, choice).into_pyobject(py)?.unbind())
+                        })
+                        .collect::<PyResult<_>>()
+                })
+                .transpose()?,
+            extra_types: recordings
+                .extra_types
+                .map(|extra_types| {
+                    let types = PyDict::new(py);
+                    for (loc_path, extra_type) in extra_types {
+                        types.set_item(PyTuple::new(py, &loc_path)?, extra_type)?;
+                    }
+                    Ok::<_, PyErr>(types.unbind())
+                })
+                .transpose()?,
+            defaults_used: recordings
+                .defaults_used
+                .map(|defaults_used| {
+                    let loc_paths = defaults_used
+                        .iter()
+                        .map(|loc_path| PyTuple::new(py, loc_path))
+                        .collect::<PyResult<Vec<_>>>()?;
+                    Ok::<_, PyErr>(PySet::new(py, loc_paths)?.unbind())
+                })
+                .transpose()?,
+            value: output,
+        }
+        .into_py_any(py)
+    }
+
+    /// Validate each item of `inputs` sharing one validation state, returning a list where each entry is either the
+    /// validated value or the `ValidationError` raised for that item
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (inputs, *, strict=None, extra=None, from_attributes=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_python_batch<'py>(
+        &self,
+        py: Python<'py>,
+        inputs: &Bound<'py, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'py, PyString>>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<Bound<'py, PyList>> {
+        let results = PyList::empty(py);
+        self.validate_python_each(
+            py,
+            inputs.try_iter()?,
+            strict,
+            extra,
+            from_attributes,
+            context,
+            by_alias,
+            by_name,
+            |result| match result {
+                Ok(output) => results.append(output),
+                Err(err) => results.append(err.into_value(py)),
+            },
+        )?;
+        Ok(results)
+    }
+
+    /// Validate each item of `inputs` sharing one validation state, returning a list of results and a list of
+    /// `ValidationError`s of the same length, with `None` in the other list at each position
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (inputs, *, strict=None, extra=None, from_attributes=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_python_many<'py>(
+        &self,
+        py: Python<'py>,
+        inputs: &Bound<'py, PyList>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'py, PyString>>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyList>)> {
+        let results = PyList::empty(py);
+        let errors = PyList::empty(py);
+        self.validate_python_each(
+            py,
+            inputs.iter().map(Ok),
+            strict,
+            extra,
+            from_attributes,
+            context,
+            by_alias,
+            by_name,
+            |result| match result {
+                Ok(output) => {
+                    results.append(output)?;
+                    errors.append(py.None())
+                }
+                Err(err) => {
+                    results.append(py.None())?;
+                    errors.append(err.into_value(py))
+                }
+            },
+        )?;
+        Ok((results, errors))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -225,16 +403,72 @@ impl SchemaValidator {
         match self._validate(
             py,
             input,
-            InputType::Python,
+            Extra::new(
+                strict,
+                extra_behavior,
+                from_attributes,
+                context,
+                self_instance,
+                InputType::Python,
+                self.cache_str,
+                by_alias,
+                by_name,
+            ),
+            false.into(),
+            false,
+            &mut Recordings::default(),
+        ) {
+            Ok(_) => Ok(true),
+            Err(ValError::InternalErr(err)) => Err(err),
+            Err(ValError::Omit) => Err(ValidationError::omit_error()),
+            Err(ValError::UseDefault) => Err(ValidationError::use_default_error()),
+            Err(ValError::LineErrors(_)) => Ok(false),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, by_alias=None, by_name=None))]
+    pub fn isinstance_json(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<bool> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+
+        let json_bytes = match json::validate_json_bytes(input) {
+            Ok(v_match) => v_match.into_inner(),
+            Err(ValError::InternalErr(err)) => return Err(err),
+            Err(_) => return Ok(false),
+        };
+        let Ok(json_value) = jiter::JsonValue::parse(json_bytes.as_slice(), true) else {
+            return Ok(false);
+        };
+
+        // errors are discarded, so don't build their locations or carry on validating after the first one
+        let mut extra = Extra::new(
             strict,
             extra_behavior,
-            from_attributes,
+            None,
             context,
             self_instance,
-            false.into(),
+            InputType::Json,
+            self.cache_str,
             by_alias,
             by_name,
-        ) {
+        );
+        extra.track_locations = false;
+        let mut recursion_guard = RecursionState::default();
+        let mut state = ValidationState::new(extra, &mut recursion_guard, false.into());
+        state.fail_fast = true;
+        match self.validator.validate(py, &json_value, &mut state) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
             Err(ValError::Omit) => Err(ValidationError::omit_error()),
@@ -244,7 +478,7 @@ impl SchemaValidator {
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, json_number_mode=FloatMode::Float, parse_only=false, forbid_duplicate_keys=false, preserve_number_type=false, json_warnings=false, deep_freeze=false))]
     pub fn validate_json(
         &self,
         py: Python,
@@ -256,14 +490,24 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        json_number_mode: FloatMode,
+        parse_only: bool,
+        forbid_duplicate_keys: bool,
+        preserve_number_type: bool,
+        json_warnings: bool,
+        deep_freeze: bool,
     ) -> PyResult<Py<PyAny>> {
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
             .transpose()?;
+        if matches!(json_number_mode, FloatMode::LosslessFloat) && !parse_only {
+            return Err(PyValueError::new_err(
+                "`json_number_mode='lossless-float'` is only supported with `parse_only=True`",
+            ));
+        }
 
-        let r = match json::validate_json_bytes(input) {
-            #[allow(clippy::used_underscore_items)]
-            Ok(v_match) => self._validate_json(
+        let r = json::validate_json_bytes(input).and_then(|v_match| {
+            self.validate_json_data(
                 py,
                 input,
                 v_match.into_inner().as_slice(),
@@ -274,12 +518,142 @@ impl SchemaValidator {
                 allow_partial,
                 by_alias,
                 by_name,
-            ),
-            Err(err) => Err(err),
-        };
+                json_number_mode,
+                parse_only,
+                forbid_duplicate_keys,
+                preserve_number_type,
+                json_warnings,
+                deep_freeze,
+            )
+        });
         r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
     }
 
+    /// Validate the first JSON document in `input`, ignoring anything after it
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, by_alias=None, by_name=None, return_consumed=false))]
+    pub fn validate_json_prefix(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+        return_consumed: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+
+        let r = json::validate_json_bytes(input).and_then(|v_match| {
+            let json_bytes = v_match.into_inner();
+            let json_data = json_bytes.as_slice();
+            let consumed = json_prefix_len(input, json_data)?;
+            #[allow(clippy::used_underscore_items)]
+            let output = self._validate_json(
+                py,
+                input,
+                &json_data[..consumed],
+                strict,
+                extra_behavior,
+                context,
+                self_instance,
+                PartialMode::Off,
+                by_alias,
+                by_name,
+                FloatMode::Float,
+                false,
+                false,
+                false,
+            )?;
+            if return_consumed {
+                Ok((output, consumed).into_py_any(py)?)
+            } else {
+                Ok(output)
+            }
+        });
+        r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
+    }
+
+    /// Validate each non-blank line of newline-delimited JSON in `input` with the options of `validate_json`,
+    /// returning a list of the validated records and a list of the `ValidationError` of each invalid line
+    /// with its 1-based line number
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, by_alias=None, by_name=None, json_number_mode=FloatMode::Float, parse_only=false, forbid_duplicate_keys=false, preserve_number_type=false, json_warnings=false, deep_freeze=false))]
+    pub fn validate_ndjson<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'py, PyString>>,
+        context: Option<&Bound<'py, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+        json_number_mode: FloatMode,
+        parse_only: bool,
+        forbid_duplicate_keys: bool,
+        preserve_number_type: bool,
+        json_warnings: bool,
+        deep_freeze: bool,
+    ) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyList>)> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+        if matches!(json_number_mode, FloatMode::LosslessFloat) && !parse_only {
+            return Err(PyValueError::new_err(
+                "`json_number_mode='lossless-float'` is only supported with `parse_only=True`",
+            ));
+        }
+        let json_bytes = json::validate_json_bytes(input)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Json))?
+            .into_inner();
+
+        let results = PyList::empty(py);
+        let errors = PyList::empty(py);
+        for (index, line) in json_bytes.as_slice().split(|b| *b == b'\n').enumerate() {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            // errors show the line rather than the whole input
+            let line_str = PyString::new(py, &String::from_utf8_lossy(line));
+            let r = self.validate_json_data(
+                py,
+                line_str.as_any(),
+                line,
+                strict,
+                extra_behavior,
+                context,
+                None,
+                PartialMode::Off,
+                by_alias,
+                by_name,
+                json_number_mode,
+                parse_only,
+                forbid_duplicate_keys,
+                preserve_number_type,
+                json_warnings,
+                deep_freeze,
+            );
+            let line_number = index + 1;
+            match r {
+                Ok(output) => results.append(output)?,
+                Err(ValError::LineErrors(line_errors)) => {
+                    let line_errors = line_errors
+                        .into_iter()
+                        .map(|err| err.with_outer_location(line_number))
+                        .collect();
+                    let error = self.prepare_validation_err(py, ValError::LineErrors(line_errors), InputType::Json);
+                    errors.append((line_number, error.into_value(py)))?;
+                }
+                Err(e) => return Err(self.prepare_validation_err(py, e, InputType::Json)),
+            }
+        }
+        Ok((results, errors))
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (input, *, strict=None, extra=None, context=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
     pub fn validate_strings(
@@ -303,15 +677,20 @@ impl SchemaValidator {
         match self._validate(
             py,
             &string_mapping,
-            t,
-            strict,
-            extra_behavior,
-            None,
-            context,
-            None,
+            Extra::new(
+                strict,
+                extra_behavior,
+                None,
+                context,
+                None,
+                t,
+                self.cache_str,
+                by_alias,
+                by_name,
+            ),
             allow_partial,
-            by_alias,
-            by_name,
+            false,
+            &mut Recordings::default(),
         ) {
             Ok(r) => Ok(r),
             Err(e) => Err(self.prepare_validation_err(py, e, t)),
@@ -349,6 +728,10 @@ impl SchemaValidator {
             cache_str: self.cache_str,
             by_alias,
             by_name,
+            json_decimals: None,
+            preserve_number_type: false,
+            deep_freeze: false,
+            track_locations: true,
         };
 
         let guard = &mut RecursionState::default();
@@ -377,6 +760,10 @@ impl SchemaValidator {
             cache_str: self.cache_str,
             by_alias: None,
             by_name: None,
+            json_decimals: None,
+            preserve_number_type: false,
+            deep_freeze: false,
+            track_locations: true,
         };
         let recursion_guard = &mut RecursionState::default();
         let mut state = ValidationState::new(extra, recursion_guard, false.into());
@@ -415,38 +802,88 @@ impl SchemaValidator {
 }
 
 impl SchemaValidator {
-    #[allow(clippy::too_many_arguments)]
     fn _validate<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
-        input_type: InputType,
+        extra: Extra<'_, 'py>,
+        allow_partial: PartialMode,
+        intern_strings: bool,
+        recordings: &mut Recordings,
+    ) -> ValResult<Py<PyAny>> {
+        // the recursion state is owned by this call, so re-entrant validation (e.g. a function validator
+        // calling back into a `SchemaValidator` with the same input) can't see or corrupt the outer guard
+        let track_locations = extra.track_locations;
+        let mut recursion_guard = RecursionState::default();
+        let mut state = ValidationState::new(extra, &mut recursion_guard, allow_partial);
+        if intern_strings {
+            state.enable_string_interning();
+        }
+        state.recordings = std::mem::take(recordings);
+        let result = self.validator.validate(py, input, &mut state);
+        *recordings = std::mem::take(&mut state.recordings);
+        match result {
+            // sequences and fields skip their locations without `track_locations`, this clears any others
+            Err(ValError::LineErrors(mut line_errors)) if !track_locations => {
+                for line_error in &mut line_errors {
+                    line_error.location = Location::Empty;
+                }
+                Err(ValError::LineErrors(line_errors))
+            }
+            result => result,
+        }
+    }
+
+    /// Validate, or with `parse_only` just parse, one JSON document with the options of `validate_json`
+    #[allow(clippy::too_many_arguments)]
+    fn validate_json_data(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        json_data: &[u8],
         strict: Option<bool>,
         extra_behavior: Option<ExtraBehavior>,
-        from_attributes: Option<bool>,
-        context: Option<&Bound<'py, PyAny>>,
-        self_instance: Option<&Bound<'py, PyAny>>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        json_number_mode: FloatMode,
+        parse_only: bool,
+        forbid_duplicate_keys: bool,
+        preserve_number_type: bool,
+        json_warnings: bool,
+        deep_freeze: bool,
     ) -> ValResult<Py<PyAny>> {
-        let mut recursion_guard = RecursionState::default();
-        let mut state = ValidationState::new(
-            Extra::new(
-                strict,
-                extra_behavior,
-                from_attributes,
-                context,
-                self_instance,
-                input_type,
-                self.cache_str,
-                by_alias,
-                by_name,
-            ),
-            &mut recursion_guard,
+        let json_data = if json_warnings {
+            json::warn_json_anomalies(py, json_data, json_number_mode)?
+        } else {
+            Cow::Borrowed(json_data)
+        };
+        let json_data = json_data.as_ref();
+        if parse_only {
+            if forbid_duplicate_keys {
+                json::check_duplicate_keys(input, json_data, allow_partial)?;
+            }
+            return parse_json_to_python(py, input, json_data, allow_partial, json_number_mode).map(Bound::unbind);
+        }
+        #[allow(clippy::used_underscore_items)]
+        self._validate_json(
+            py,
+            input,
+            json_data,
+            strict,
+            extra_behavior,
+            context,
+            self_instance,
             allow_partial,
-        );
-        self.validator.validate(py, input, &mut state)
+            by_alias,
+            by_name,
+            json_number_mode,
+            forbid_duplicate_keys,
+            preserve_number_type,
+            deep_freeze,
+        )
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -462,23 +899,87 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        float_mode: FloatMode,
+        forbid_duplicate_keys: bool,
+        preserve_number_type: bool,
+        deep_freeze: bool,
     ) -> ValResult<Py<PyAny>> {
         let json_value = jiter::JsonValue::parse_with_config(json_data, true, allow_partial)
             .map_err(|e| json::map_json_err(input, e, json_data))?;
-        #[allow(clippy::used_underscore_items)]
-        self._validate(
-            py,
-            &json_value,
-            InputType::Json,
+        if forbid_duplicate_keys {
+            json::forbid_duplicate_keys(&json_value)?;
+        }
+        let json_decimals = match float_mode {
+            FloatMode::Decimal => Some(JsonDecimals::new(json_data, &json_value)),
+            _ => None,
+        };
+        let mut extra = Extra::new(
             strict,
             extra_behavior,
             None,
             context,
             self_instance,
-            allow_partial,
+            InputType::Json,
+            self.cache_str,
             by_alias,
             by_name,
-        )
+        );
+        extra.json_decimals = json_decimals.as_ref();
+        extra.preserve_number_type = preserve_number_type;
+        extra.deep_freeze = deep_freeze;
+        #[allow(clippy::used_underscore_items)]
+        self._validate(py, &json_value, extra, allow_partial, false, &mut Recordings::default())
+    }
+
+    /// Validate each of `inputs` sharing one validation state, calling `on_result` with either the validated value or
+    /// the `ValidationError` for each input, internal errors are raised rather than passed to `on_result`
+    #[allow(clippy::too_many_arguments)]
+    fn validate_python_each<'py>(
+        &self,
+        py: Python<'py>,
+        inputs: impl Iterator<Item = PyResult<Bound<'py, PyAny>>>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'py, PyString>>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+        mut on_result: impl FnMut(Result<Py<PyAny>, PyErr>) -> PyResult<()>,
+    ) -> PyResult<()> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+
+        let mut recursion_guard = RecursionState::default();
+        let mut state = ValidationState::new(
+            Extra::new(
+                strict,
+                extra_behavior,
+                from_attributes,
+                context,
+                None,
+                InputType::Python,
+                self.cache_str,
+                by_alias,
+                by_name,
+            ),
+            &mut recursion_guard,
+            PartialMode::Off,
+        );
+        for input in inputs {
+            let input = input?;
+            state.exactness = None;
+            state.fields_set_count = None;
+            state.has_field_error = false;
+            match self.validator.validate(py, &input, &mut state) {
+                Ok(output) => on_result(Ok(output))?,
+                Err(e @ ValError::LineErrors(_)) => {
+                    on_result(Err(self.prepare_validation_err(py, e, InputType::Python)))?;
+                }
+                Err(e) => return Err(self.prepare_validation_err(py, e, InputType::Python)),
+            }
+        }
+        Ok(())
     }
 
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
@@ -494,6 +995,55 @@ impl SchemaValidator {
     }
 }
 
+/// Whether validation changed the input, the output is considered unchanged if it's the very same object,
+/// or if it has the same type as the input and compares equal to it.
+fn output_changed(input: &Bound<'_, PyAny>, output: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if output.is(input) {
+        Ok(false)
+    } else if !output.get_type().is(input.get_type()) {
+        Ok(true)
+    } else {
+        Ok(!output.eq(input)?)
+    }
+}
+
+/// Parse JSON data straight to python objects, used when the data isn't validated as `JsonValue`
+fn parse_json_to_python<'py>(
+    py: Python<'py>,
+    input: &Bound<'py, PyAny>,
+    json_data: &[u8],
+    allow_partial: PartialMode,
+    float_mode: FloatMode,
+) -> ValResult<Bound<'py, PyAny>> {
+    let parse_builder = PythonParse {
+        allow_inf_nan: true,
+        cache_mode: StringCacheMode::All,
+        partial_mode: allow_partial,
+        catch_duplicate_keys: false,
+        float_mode,
+    };
+    parse_builder
+        .python_parse(py, json_data)
+        .map_err(|e| json::map_json_err(input, e, json_data))
+}
+
+/// The length of the first JSON value in `json_data`, including any leading whitespace
+fn json_prefix_len(input: &Bound<'_, PyAny>, json_data: &[u8]) -> ValResult<usize> {
+    let mut jiter = jiter::Jiter::new(json_data).with_allow_inf_nan();
+    match jiter.next_skip() {
+        Ok(()) => Ok(jiter.current_index()),
+        Err(jiter::JiterError {
+            error_type: jiter::JiterErrorType::JsonError(error_type),
+            index,
+        }) => Err(json::map_json_err(
+            input,
+            jiter::JsonError { error_type, index },
+            json_data,
+        )),
+        Err(err) => Err(PyValueError::new_err(err.to_string()).into()),
+    }
+}
+
 pub trait BuildValidator: Sized {
     const EXPECTED_TYPE: &'static str;
 
@@ -530,6 +1080,43 @@ macro_rules! validator_match {
     };
 }
 
+/// Validate the values deferred until the whole validator is built, the defaults of `WithDefaultValidator`
+/// where `validate_defaults_on_build` is set and the `pad_value` of `ListValidator`
+fn validate_deferred_on_build(
+    py: Python,
+    deferred: &[Arc<CombinedValidator>],
+    cache_str: StringCacheMode,
+) -> PyResult<()> {
+    for validator in deferred {
+        let extra = Extra {
+            input_type: InputType::Python,
+            data: None,
+            strict: None,
+            extra_behavior: None,
+            from_attributes: None,
+            field_name: None,
+            context: None,
+            self_instance: None,
+            cache_str,
+            by_alias: None,
+            by_name: None,
+            json_decimals: None,
+            preserve_number_type: false,
+            deep_freeze: false,
+            track_locations: true,
+        };
+        let recursion_guard = &mut RecursionState::default();
+        let mut state = ValidationState::new(extra, recursion_guard, false.into());
+        let result = match validator.as_ref() {
+            CombinedValidator::WithDefault(with_default) => with_default.validate_default_on_build(py, &mut state),
+            CombinedValidator::List(list) => list.validate_pad_value_on_build(py, &mut state),
+            _ => Ok(()),
+        };
+        result.map_err(|e| SchemaError::from_val_error(py, e))?;
+    }
+    Ok(())
+}
+
 // Used when creating the base validator instance, to avoid reusing the instance
 // when unpickling:
 pub fn build_validator_base(
@@ -691,6 +1278,14 @@ pub struct Extra<'a, 'py> {
     by_alias: Option<bool>,
     /// Whether to use the field's name to match the input data to an attribute.
     by_name: Option<bool>,
+    /// The source text of JSON floats with `json_number_mode='decimal'`, so they can be read as exact `Decimal`s
+    pub json_decimals: Option<&'a JsonDecimals<'a>>,
+    /// Whether float validators return integer JSON numbers as ints, set by `validate_json(preserve_number_type=True)`
+    pub preserve_number_type: bool,
+    /// Whether containers are returned immutable, set by `deep_freeze=True`
+    pub deep_freeze: bool,
+    /// Whether error locations are built, cleared with `track_locations=False` when only pass/fail matters
+    pub track_locations: bool,
 }
 
 impl<'a, 'py> Extra<'a, 'py> {
@@ -718,6 +1313,10 @@ impl<'a, 'py> Extra<'a, 'py> {
             cache_str,
             by_alias,
             by_name,
+            json_decimals: None,
+            preserve_number_type: false,
+            deep_freeze: false,
+            track_locations: true,
         }
     }
 }
@@ -736,6 +1335,10 @@ impl Extra<'_, '_> {
             cache_str: self.cache_str,
             by_alias: self.by_alias,
             by_name: self.by_name,
+            json_decimals: self.json_decimals,
+            preserve_number_type: self.preserve_number_type,
+            deep_freeze: self.deep_freeze,
+            track_locations: self.track_locations,
         }
     }
 }
@@ -882,4 +1485,10 @@ pub trait Validator: Send + Sync + Debug {
     /// `get_name` generally returns `Self::EXPECTED_TYPE` or some other clear identifier of the validator
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
+
+    /// The `title` from the validator's own config, used in place of `get_name` in the top level message in
+    /// `ValidationError` when this is the outermost validator
+    fn get_title(&self) -> Option<String> {
+        None
+    }
 }
@@ -1,19 +1,23 @@
+use std::borrow::Cow;
 use std::ptr::null_mut;
 use std::sync::Arc;
 
+use jiter::{JsonArray, JsonValue};
 use pyo3::exceptions::PyTypeError;
-use pyo3::types::{PyDict, PySet, PyString, PyTuple, PyType};
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyTuple, PyType};
 use pyo3::{ffi, BoundObject, IntoPyObjectExt};
 use pyo3::{intern, prelude::*};
 
 use super::function::convert_err;
 use super::validation_state::Exactness;
+use super::with_default::deepcopy_default;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, ValidationState, Validator,
+    build_validator, BuildValidator, CombinedValidator, DefaultType, DefinitionsBuilder, Extra, ValidationState,
+    Validator,
 };
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config_same;
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{input_as_python_instance, py_error_on_minusone, Input};
 use crate::tools::{py_err, SchemaDict};
 use crate::PydanticUndefinedType;
@@ -23,6 +27,7 @@ const DUNDER_DICT: &str = "__dict__";
 const DUNDER_FIELDS_SET_KEY: &str = "__pydantic_fields_set__";
 const DUNDER_MODEL_EXTRA_KEY: &str = "__pydantic_extra__";
 const DUNDER_MODEL_PRIVATE_KEY: &str = "__pydantic_private__";
+const DUNDER_MODEL_LAZY_KEY: &str = "__pydantic_lazy__";
 
 #[derive(Debug, Clone)]
 pub(super) enum Revalidate {
@@ -50,6 +55,50 @@ impl Revalidate {
     }
 }
 
+/// A private attribute (e.g. `_state`) which is initialised from its default or default factory
+/// once the model's fields have been populated.
+#[derive(Debug)]
+struct PrivateAttribute {
+    name: Py<PyString>,
+    default: DefaultType,
+    copy_default: bool,
+}
+
+impl_py_gc_traverse!(PrivateAttribute { name, default });
+
+/// A field listed in `lazy_fields`, with the validator for its declared schema which is run
+/// when the field is assigned, including when its pending value is resolved
+#[derive(Debug)]
+struct LazyFieldValidator {
+    name: Py<PyString>,
+    validator: Arc<CombinedValidator>,
+}
+
+impl_py_gc_traverse!(LazyFieldValidator { name, validator });
+
+impl PrivateAttribute {
+    fn build(name: Bound<'_, PyString>, schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let default = DefaultType::new(schema)?;
+        let copy_default = if let DefaultType::Default(default_obj) = &default {
+            default_obj.bind(schema.py()).hash().is_err()
+        } else {
+            false
+        };
+        Ok(Self {
+            name: name.unbind(),
+            default,
+            copy_default,
+        })
+    }
+
+    fn default_value(&self, py: Python, validated_data: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Py<PyAny>>> {
+        match self.default.default_value(py, validated_data)? {
+            Some(dft) if self.copy_default => deepcopy_default(py, &dft).map(Some),
+            dft => Ok(dft),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ModelValidator {
     revalidate: Revalidate,
@@ -60,8 +109,23 @@ pub struct ModelValidator {
     frozen: bool,
     custom_init: bool,
     root_model: bool,
+    private_attributes: Vec<PrivateAttribute>,
+    // with `from_tuple`, the keys to map positional tuple or list items to, in field declaration order
+    tuple_field_keys: Option<Vec<Py<PyString>>>,
+    // the attribute extras are stored under, `__pydantic_extra__` unless set with `extra_field_name`
+    extra_field_name: Py<PyString>,
+    // the class declares `__slots__` throughout its MRO, with no slot for `extra_field_name` or `__dict__`
+    extra_slot_missing: bool,
+    // groups of fields where exactly one field of each group must be set
+    required_one_of: Vec<Vec<String>>,
+    // fields whose values are stored as given in `__pydantic_lazy__` and validated when resolved
+    lazy_fields: Vec<LazyFieldValidator>,
+    // with `lazy_fields`, the names of all fields in declaration order, which `__dict__` is kept in as they're resolved
+    field_order: Vec<Py<PyString>>,
     undefined: Py<PyAny>,
     name: String,
+    // `title` from the model's config, used in place of the class name in `ValidationError` titles
+    title: Option<String>,
 }
 
 impl BuildValidator for ModelValidator {
@@ -74,14 +138,43 @@ impl BuildValidator for ModelValidator {
     ) -> PyResult<Arc<CombinedValidator>> {
         let py = schema.py();
         // models ignore the parent config and always use the config from this model
-        let config = schema.get_as(intern!(py, "config"))?;
+        let config: Option<Bound<'_, PyDict>> = schema.get_as(intern!(py, "config"))?;
 
         let class: Bound<'_, PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let generic_origin: Option<Bound<'_, PyType>> = schema.get_as(intern!(py, "generic_origin"))?;
-        let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
+        let sub_schema: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "schema"))?;
+        let (sub_schema, lazy_fields, field_order) =
+            match schema.get_as::<Bound<'_, PyList>>(intern!(py, "lazy_fields"))? {
+                Some(names) => lazy_fields_schema(&sub_schema, &names, config.as_ref(), definitions)?,
+                None => (sub_schema, Vec::new(), Vec::new()),
+            };
+        let sub_schema = if schema.get_as(intern!(py, "require_all"))?.unwrap_or(false) {
+            with_require_all(&sub_schema)?
+        } else {
+            sub_schema
+        };
         let validator = build_validator(&sub_schema, config.as_ref(), definitions)?;
         let name = class.getattr(intern!(py, "__name__"))?.extract()?;
 
+        let private_attributes = match schema.get_as::<Bound<'_, PyDict>>(intern!(py, "private_attributes"))? {
+            Some(private_attributes) => private_attributes
+                .iter()
+                .map(|(name, attr_schema)| PrivateAttribute::build(name.downcast_into()?, attr_schema.downcast()?))
+                .collect::<PyResult<_>>()?,
+            None => Vec::new(),
+        };
+
+        let tuple_field_keys = if schema.get_as(intern!(py, "from_tuple"))?.unwrap_or(false) {
+            Some(tuple_field_keys(&sub_schema)?)
+        } else {
+            None
+        };
+
+        let extra_field_name: Bound<'_, PyString> = schema
+            .get_as(intern!(py, "extra_field_name"))?
+            .unwrap_or_else(|| intern!(py, DUNDER_MODEL_EXTRA_KEY).clone());
+        let extra_slot_missing = extra_slot_missing(&class, extra_field_name.to_str()?)?;
+
         Ok(CombinedValidator::Model(Self {
             revalidate: Revalidate::from_str(
                 schema_or_config_same::<Bound<'_, PyString>>(
@@ -100,9 +193,17 @@ impl BuildValidator for ModelValidator {
             frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
             custom_init: schema.get_as(intern!(py, "custom_init"))?.unwrap_or(false),
             root_model: schema.get_as(intern!(py, "root_model"))?.unwrap_or(false),
+            private_attributes,
+            tuple_field_keys,
+            extra_field_name: extra_field_name.unbind(),
+            extra_slot_missing,
+            required_one_of: required_one_of_groups(schema, &sub_schema)?,
+            lazy_fields,
+            field_order,
             undefined: PydanticUndefinedType::get(py).clone_ref(schema.py()).into_any(),
             // Get the class's `__name__`, not using `class.qualname()`
             name,
+            title: config.as_ref().get_as(intern!(py, "title"))?,
         })
         .into())
     }
@@ -111,7 +212,12 @@ impl BuildValidator for ModelValidator {
 impl_py_gc_traverse!(ModelValidator {
     class,
     generic_origin,
-    validator
+    validator,
+    private_attributes,
+    tuple_field_keys,
+    extra_field_name,
+    lazy_fields,
+    field_order,
 });
 
 impl Validator for ModelValidator {
@@ -160,13 +266,20 @@ impl Validator for ModelValidator {
                 } else {
                     // get dict here so from_attributes logic doesn't apply
                     let dict = py_input.getattr(intern!(py, DUNDER_DICT))?;
-                    let model_extra = py_input.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?;
+                    let model_extra = py_input.getattr(self.extra_field_name.bind(py))?;
 
-                    let inner_input = if PyAnyMethods::is_none(&model_extra) {
+                    let pending = self.pending_lazy_fields(py_input)?;
+
+                    let inner_input = if PyAnyMethods::is_none(&model_extra) && pending.is_none() {
                         dict
                     } else {
                         let full_model_dict = dict.downcast::<PyDict>()?.copy()?;
-                        full_model_dict.update(model_extra.downcast()?)?;
+                        if !PyAnyMethods::is_none(&model_extra) {
+                            full_model_dict.update(model_extra.downcast()?)?;
+                        }
+                        if let Some(pending) = pending {
+                            full_model_dict.update(pending.as_mapping())?;
+                        }
                         full_model_dict.into_any()
                     };
                     self.validate_construct(py, &inner_input, Some(&fields_set), state)
@@ -177,6 +290,17 @@ impl Validator for ModelValidator {
         } else {
             // Having to construct a new model is not an exact match
             state.floor_exactness(Exactness::Strict);
+            if let Some(ref field_keys) = self.tuple_field_keys {
+                // key the items by field while keeping their input type, so JSON items are still validated as JSON
+                if let Some(py_input) = input.as_python() {
+                    if let Some(fields_dict) = fields_from_py_tuple(field_keys, py_input, state.strict_or(false))? {
+                        return self.validate_construct(py, fields_dict.as_any(), None, state);
+                    }
+                } else if let Some(JsonValue::Array(items)) = input.as_json() {
+                    let fields_object = fields_from_json_array(py, field_keys, items, input)?;
+                    return self.validate_construct(py, &fields_object, None, state);
+                }
+            }
             self.validate_construct(py, input, None, state)
         }
     }
@@ -189,7 +313,13 @@ impl Validator for ModelValidator {
         field_value: &Bound<'py, PyAny>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        if self.frozen {
+        let lazy_field = self.lazy_fields.iter().find(|f| f.name.bind(py) == field_name);
+        // resolving the pending value of a lazy field completes validation rather than changing the model
+        let resolving = match (lazy_field, self.pending_lazy_fields(model)?) {
+            (Some(_), Some(pending)) => pending.get_item(field_name)?.is_some_and(|v| v.is(field_value)),
+            _ => false,
+        };
+        if self.frozen && !resolving {
             return Err(ValError::new(ErrorTypeDefaults::FrozenInstance, field_value));
         } else if self.root_model {
             return if field_name != ROOT_FIELD {
@@ -211,8 +341,15 @@ impl Validator for ModelValidator {
         }
         let old_dict = model.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?;
 
+        // lazy fields accept any value in the fields schema, so they're validated against their declared schema first
+        let lazy_output = match lazy_field {
+            Some(lazy_field) => Some(self.validate_lazy_field(py, lazy_field, &old_dict, field_value, state)?),
+            None => None,
+        };
+        let field_value = lazy_output.as_ref().unwrap_or(field_value);
+
         let input_dict = old_dict.copy()?;
-        if let Ok(old_extra) = model.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?.downcast::<PyDict>() {
+        if let Ok(old_extra) = model.getattr(self.extra_field_name.bind(py))?.downcast::<PyDict>() {
             input_dict.update(old_extra.as_mapping())?;
         }
         input_dict.set_item(field_name, field_value)?;
@@ -226,22 +363,45 @@ impl Validator for ModelValidator {
             Bound<'_, PyAny>,
             Bound<'_, PySet>,
         ) = output.extract(py)?;
-
-        if let Ok(fields_set) = model.getattr(intern!(py, DUNDER_FIELDS_SET_KEY)) {
-            let fields_set = fields_set.downcast::<PySet>()?;
+        let validated_dict = match lazy_field {
+            Some(_) => self.in_field_order(&validated_dict)?,
+            None => validated_dict,
+        };
+
+        let fields_set = match model.getattr(intern!(py, DUNDER_FIELDS_SET_KEY)) {
+            Ok(fields_set) => Some(fields_set.downcast_into::<PySet>()?),
+            Err(_) => None,
+        };
+        if let Some(fields_set) = fields_set.as_ref().filter(|_| !self.required_one_of.is_empty()) {
+            // check the fields which would be set after the assignment before changing the model
+            let new_fields_set = PySet::new(py, fields_set)?;
+            for field_name in &validated_fields_set {
+                new_fields_set.add(field_name)?;
+            }
+            self.check_required_one_of(field_value, new_fields_set.as_any())?;
+        }
+        if let Some(fields_set) = fields_set {
             for field_name in validated_fields_set {
                 fields_set.add(field_name)?;
             }
         }
 
+        self.check_extra_slot(&validated_extra)?;
         force_setattr(py, model, intern!(py, DUNDER_DICT), validated_dict)?;
-        force_setattr(py, model, intern!(py, DUNDER_MODEL_EXTRA_KEY), validated_extra)?;
+        force_setattr(py, model, self.extra_field_name.bind(py), validated_extra)?;
+        if lazy_field.is_some() {
+            self.drop_pending_lazy_field(model, field_name)?;
+        }
         Ok(model.into_py_any(py)?)
     }
 
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn get_title(&self) -> Option<String> {
+        self.title.clone()
+    }
 }
 
 impl ModelValidator {
@@ -273,7 +433,12 @@ impl ModelValidator {
 
             let (model_dict, model_extra, fields_set): (Bound<PyAny>, Bound<PyAny>, Bound<PyAny>) =
                 output.extract(py)?;
-            set_model_attrs(self_instance, &model_dict, &model_extra, &fields_set)?;
+            self.check_extra_slot(&model_extra)?;
+            state.record_extra_types(&model_extra)?;
+            self.check_required_one_of(input, &fields_set)?;
+            self.set_model_attrs(self_instance, &model_dict, &model_extra, &fields_set)?;
+            self.defer_lazy_fields(self_instance, &model_dict, &fields_set)?;
+            self.init_private_attributes(py, self_instance, &model_dict)?;
         }
         self.call_post_init(py, self_instance.clone(), input, state.extra())
     }
@@ -319,12 +484,210 @@ impl ModelValidator {
 
             let (model_dict, model_extra, val_fields_set): (Bound<PyAny>, Bound<PyAny>, Bound<PyAny>) =
                 output.extract(py)?;
+            self.check_extra_slot(&model_extra)?;
+            state.record_extra_types(&model_extra)?;
             let fields_set = existing_fields_set.unwrap_or(&val_fields_set);
-            set_model_attrs(&instance, &model_dict, &model_extra, fields_set)?;
+            self.check_required_one_of(input, fields_set)?;
+            self.set_model_attrs(&instance, &model_dict, &model_extra, fields_set)?;
+            self.defer_lazy_fields(&instance, &model_dict, fields_set)?;
+            self.init_private_attributes(py, &instance, &model_dict)?;
         }
         self.call_post_init(py, instance, input, state.extra())
     }
 
+    /// Move the values given for `lazy_fields` from the model's `__dict__` to `__pydantic_lazy__`, they're validated
+    /// when the model resolves them by assigning the pending value, typically from its `__getattr__`
+    fn defer_lazy_fields(
+        &self,
+        instance: &Bound<'_, PyAny>,
+        model_dict: &Bound<'_, PyAny>,
+        fields_set: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        if self.lazy_fields.is_empty() {
+            return Ok(());
+        }
+        let py = instance.py();
+        let model_dict = model_dict.downcast::<PyDict>()?;
+        let pending = PyDict::new(py);
+        for lazy_field in &self.lazy_fields {
+            let name = lazy_field.name.bind(py);
+            // defaults of fields which weren't given are kept as they are, like those of any other field
+            if !fields_set.contains(name)? {
+                continue;
+            }
+            if let Some(value) = model_dict.get_item(name)? {
+                model_dict.del_item(name)?;
+                pending.set_item(name, value)?;
+            }
+        }
+        force_setattr(py, instance, intern!(py, DUNDER_MODEL_LAZY_KEY), pending)
+    }
+
+    /// The values of `lazy_fields` waiting to be validated, if the model has any
+    fn pending_lazy_fields<'py>(&self, model: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        if self.lazy_fields.is_empty() {
+            return Ok(None);
+        }
+        match model.getattr(intern!(model.py(), DUNDER_MODEL_LAZY_KEY)) {
+            Ok(pending) => Ok(pending.downcast_into::<PyDict>().ok().filter(|p| !p.is_empty())),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Once a lazy field is assigned its pending value is no longer needed, `__pydantic_lazy__` is replaced
+    /// rather than changed in place as shallow copies of the model share it
+    fn drop_pending_lazy_field(&self, model: &Bound<'_, PyAny>, field_name: &str) -> PyResult<()> {
+        let Some(pending) = self.pending_lazy_fields(model)? else {
+            return Ok(());
+        };
+        if pending.contains(field_name)? {
+            let pending = pending.copy()?;
+            pending.del_item(field_name)?;
+            force_setattr(model.py(), model, intern!(model.py(), DUNDER_MODEL_LAZY_KEY), pending)?;
+        }
+        Ok(())
+    }
+
+    /// A copy of `model_dict` with the fields in declaration order, rather than resolved lazy fields coming last
+    fn in_field_order<'py>(&self, model_dict: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let py = model_dict.py();
+        let ordered = PyDict::new(py);
+        for field_name in &self.field_order {
+            if let Some(value) = model_dict.get_item(field_name)? {
+                ordered.set_item(field_name, value)?;
+            }
+        }
+        ordered.update(model_dict.as_mapping())?;
+        Ok(ordered)
+    }
+
+    fn validate_lazy_field<'py>(
+        &self,
+        py: Python<'py>,
+        lazy_field: &LazyFieldValidator,
+        model_dict: &Bound<'py, PyDict>,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Bound<'py, PyAny>> {
+        let name = lazy_field.name.bind(py);
+        let data = model_dict.copy()?;
+        let state = &mut state.rebind_extra(|extra| {
+            extra.field_name = Some(name.clone());
+            extra.data = Some(data);
+        });
+        match lazy_field.validator.validate(py, field_value, state) {
+            Ok(output) => Ok(output.into_bound(py)),
+            Err(ValError::LineErrors(line_errors)) => {
+                let field_name = name.to_str()?;
+                Err(ValError::LineErrors(
+                    line_errors
+                        .into_iter()
+                        .map(|e| e.with_outer_location(field_name))
+                        .collect(),
+                ))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Extra fields can't be stored on a slotted model without a slot for `extra_field_name`,
+    /// rather than setting them as arbitrary attributes each extra key is reported as forbidden
+    fn check_extra_slot(&self, model_extra: &Bound<'_, PyAny>) -> ValResult<()> {
+        if !self.extra_slot_missing {
+            return Ok(());
+        }
+        match model_extra.downcast::<PyDict>() {
+            Ok(extra) if !extra.is_empty() => {
+                let errors = extra
+                    .iter()
+                    .map(|(key, value)| {
+                        Ok(ValLineError::new_with_loc(
+                            ErrorTypeDefaults::ExtraForbidden,
+                            &value,
+                            key.downcast::<PyString>()?.to_string(),
+                        ))
+                    })
+                    .collect::<PyResult<_>>()?;
+                Err(ValError::LineErrors(errors))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Each `required_one_of` group must have exactly one of its fields in `fields_set`,
+    /// violations are reported at the model root
+    fn check_required_one_of<'py>(
+        &self,
+        input: &(impl Input<'py> + ?Sized),
+        fields_set: &Bound<'_, PyAny>,
+    ) -> ValResult<()> {
+        if self.required_one_of.is_empty() {
+            return Ok(());
+        }
+        let fields_set = fields_set.downcast::<PySet>()?;
+        let mut errors = Vec::new();
+        for group in &self.required_one_of {
+            let mut actual_count = 0;
+            for field in group {
+                if fields_set.contains(field)? {
+                    actual_count += 1;
+                }
+            }
+            if actual_count != 1 {
+                let fields = group.iter().map(|f| format!("'{f}'")).collect::<Vec<_>>().join(", ");
+                errors.push(ValLineError::new(
+                    ErrorType::ExactlyOneRequired {
+                        fields,
+                        actual_count,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    /// Set `__pydantic_private__` from the defaults of the configured private attributes,
+    /// attributes without a default are left unset
+    fn init_private_attributes<'py>(
+        &self,
+        py: Python<'py>,
+        instance: &Bound<'py, PyAny>,
+        model_dict: &Bound<'py, PyAny>,
+    ) -> PyResult<()> {
+        if self.private_attributes.is_empty() {
+            return Ok(());
+        }
+        let validated_data = model_dict.downcast::<PyDict>().ok();
+        let private = PyDict::new(py);
+        for attr in &self.private_attributes {
+            if let Some(value) = attr.default_value(py, validated_data)? {
+                private.set_item(&attr.name, value)?;
+            }
+        }
+        force_setattr(py, instance, intern!(py, DUNDER_MODEL_PRIVATE_KEY), private)
+    }
+
+    fn set_model_attrs(
+        &self,
+        instance: &Bound<'_, PyAny>,
+        model_dict: &Bound<'_, PyAny>,
+        model_extra: &Bound<'_, PyAny>,
+        fields_set: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let py = instance.py();
+        force_setattr(py, instance, intern!(py, DUNDER_DICT), model_dict)?;
+        force_setattr(py, instance, self.extra_field_name.bind(py), model_extra)?;
+        force_setattr(py, instance, intern!(py, DUNDER_MODEL_PRIVATE_KEY), py.None())?;
+        force_setattr(py, instance, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
+        Ok(())
+    }
+
     fn call_post_init<'py>(
         &self,
         py: Python<'py>,
@@ -362,18 +725,213 @@ pub(super) fn create_class<'py>(class: &Bound<'py, PyType>) -> PyResult<Bound<'p
     }
 }
 
-fn set_model_attrs(
-    instance: &Bound<'_, PyAny>,
-    model_dict: &Bound<'_, PyAny>,
-    model_extra: &Bound<'_, PyAny>,
-    fields_set: &Bound<'_, PyAny>,
-) -> PyResult<()> {
-    let py = instance.py();
-    force_setattr(py, instance, intern!(py, DUNDER_DICT), model_dict)?;
-    force_setattr(py, instance, intern!(py, DUNDER_MODEL_EXTRA_KEY), model_extra)?;
-    force_setattr(py, instance, intern!(py, DUNDER_MODEL_PRIVATE_KEY), py.None())?;
-    force_setattr(py, instance, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
-    Ok(())
+/// The `required_one_of` groups, their field names are checked against the fields of a `model-fields` schema
+fn required_one_of_groups(schema: &Bound<'_, PyDict>, fields_schema: &Bound<'_, PyDict>) -> PyResult<Vec<Vec<String>>> {
+    let py = schema.py();
+    let groups: Vec<Vec<String>> = schema.get_as(intern!(py, "required_one_of"))?.unwrap_or_default();
+    if groups.iter().any(Vec::is_empty) {
+        return py_schema_err!("`required_one_of` groups must not be empty");
+    }
+    let schema_type: Bound<'_, PyString> = fields_schema.get_as_req(intern!(py, "type"))?;
+    if schema_type.to_str()? == "model-fields" {
+        let fields: Bound<'_, PyDict> = fields_schema.get_as_req(intern!(py, "fields"))?;
+        for field_name in groups.iter().flatten() {
+            if !fields.contains(field_name)? {
+                return py_schema_err!("`required_one_of` field '{}' is not a field of the model", field_name);
+            }
+        }
+    }
+    Ok(groups)
+}
+
+/// A copy of `fields_schema` with `require_all` set on its `model-fields` schema, looking through validator functions
+/// wrapping it, rather than on the config which nested schemas would inherit
+fn with_require_all<'py>(fields_schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+    let py = fields_schema.py();
+    let fields_schema = fields_schema.copy()?;
+    let schema_type: Bound<'_, PyString> = fields_schema.get_as_req(intern!(py, "type"))?;
+    match schema_type.to_str()? {
+        "model-fields" => fields_schema.set_item(intern!(py, "require_all"), true)?,
+        "function-before" | "function-after" | "function-wrap" => {
+            let inner_schema: Bound<'_, PyDict> = fields_schema.get_as_req(intern!(py, "schema"))?;
+            fields_schema.set_item(intern!(py, "schema"), with_require_all(&inner_schema)?)?;
+        }
+        _ => return py_schema_err!("`require_all` requires a `model-fields` schema"),
+    }
+    Ok(fields_schema)
+}
+
+/// Keys for the fields of a `model-fields` schema in declaration order, a field's string alias is used if it has one
+fn tuple_field_keys(fields_schema: &Bound<'_, PyDict>) -> PyResult<Vec<Py<PyString>>> {
+    let py = fields_schema.py();
+    let schema_type: Bound<'_, PyString> = fields_schema.get_as_req(intern!(py, "type"))?;
+    if schema_type.to_str()? != "model-fields" {
+        return py_schema_err!("`from_tuple` requires a `model-fields` schema");
+    }
+    let fields: Bound<'_, PyDict> = fields_schema.get_as_req(intern!(py, "fields"))?;
+    fields
+        .iter()
+        .map(|(field_name, field_info)| {
+            let Ok(field_name) = field_name.downcast_into::<PyString>() else {
+                return py_schema_err!("`from_tuple` requires string field names");
+            };
+            let field_info = field_info.downcast::<PyDict>()?;
+            match field_info.get_item(intern!(py, "validation_alias"))? {
+                None => Ok(field_name.unbind()),
+                Some(alias) => match alias.downcast_into::<PyString>() {
+                    Ok(alias) => Ok(alias.unbind()),
+                    Err(_) => py_schema_err!(
+                        "`from_tuple` does not support the path `validation_alias` of field '{}'",
+                        field_name
+                    ),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Map the items of a tuple (or a list in lax mode) to the model's fields by position
+fn fields_from_py_tuple<'py>(
+    field_keys: &[Py<PyString>],
+    input: &Bound<'py, PyAny>,
+    strict: bool,
+) -> ValResult<Option<Bound<'py, PyDict>>> {
+    let py = input.py();
+    let items = match input.downcast::<PyTuple>() {
+        Ok(tuple) => tuple.to_list(),
+        Err(_) if strict => return Ok(None),
+        Err(_) => match input.downcast::<PyList>() {
+            Ok(list) => list.clone(),
+            Err(_) => return Ok(None),
+        },
+    };
+    check_tuple_length(field_keys, PyListMethods::len(&items), input)?;
+
+    let fields_dict = PyDict::new(py);
+    for (key, item) in field_keys.iter().zip(items) {
+        fields_dict.set_item(key.bind(py), item)?;
+    }
+    Ok(Some(fields_dict))
+}
+
+/// Map the items of a JSON array to the model's fields by position, as a JSON object
+fn fields_from_json_array<'py, 'data>(
+    py: Python<'py>,
+    field_keys: &[Py<PyString>],
+    items: &JsonArray<'data>,
+    input: &(impl Input<'py> + ?Sized),
+) -> ValResult<JsonValue<'data>> {
+    check_tuple_length(field_keys, items.len(), input)?;
+    let fields = field_keys
+        .iter()
+        .zip(items.iter())
+        .map(|(key, item)| Ok((Cow::Owned(key.bind(py).to_str()?.to_owned()), item.clone())))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(JsonValue::Object(Arc::new(fields)))
+}
+
+fn check_tuple_length<'py>(
+    field_keys: &[Py<PyString>],
+    actual_length: usize,
+    input: &(impl Input<'py> + ?Sized),
+) -> ValResult<()> {
+    if actual_length == field_keys.len() {
+        Ok(())
+    } else {
+        Err(ValError::new(
+            ErrorType::ModelTupleLength {
+                expected_length: field_keys.len(),
+                actual_length,
+                context: None,
+            },
+            input,
+        ))
+    }
+}
+
+type LazyFieldsSchema<'py> = (Bound<'py, PyDict>, Vec<LazyFieldValidator>, Vec<Py<PyString>>);
+
+/// A copy of the `model-fields` schema where the schemas of `lazy_fields` accept any value (keeping any default),
+/// along with validators for their declared schemas to run when each field is resolved, and all field names
+fn lazy_fields_schema<'py>(
+    fields_schema: &Bound<'py, PyDict>,
+    names: &Bound<'py, PyList>,
+    config: Option<&Bound<'py, PyDict>>,
+    definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+) -> PyResult<LazyFieldsSchema<'py>> {
+    let py = fields_schema.py();
+    let schema_type: Bound<'_, PyString> = fields_schema.get_as_req(intern!(py, "type"))?;
+    if schema_type.to_str()? != "model-fields" {
+        return py_schema_err!("`lazy_fields` requires a `model-fields` schema");
+    }
+    let fields = fields_schema
+        .get_as_req::<Bound<'_, PyDict>>(intern!(py, "fields"))?
+        .copy()?;
+    let any_schema = PyDict::new(py);
+    any_schema.set_item(intern!(py, "type"), intern!(py, "any"))?;
+
+    let mut lazy_fields = Vec::with_capacity(names.len());
+    for name in names {
+        let name = name.downcast_into::<PyString>()?;
+        let Some(field) = fields.get_item(&name)? else {
+            return py_schema_err!("`lazy_fields` includes unknown field `{}`", name);
+        };
+        let field = field.downcast::<PyDict>()?.copy()?;
+        let field_schema: Bound<'_, PyDict> = field.get_as_req(intern!(py, "schema"))?;
+        let field_schema_type: Bound<'_, PyString> = field_schema.get_as_req(intern!(py, "type"))?;
+        let (deferred_schema, placeholder_schema) = if field_schema_type.to_str()? == "default" {
+            let wrapper = field_schema.copy()?;
+            wrapper.set_item(intern!(py, "schema"), &any_schema)?;
+            (field_schema.get_as_req(intern!(py, "schema"))?, wrapper)
+        } else {
+            (field_schema, any_schema.clone())
+        };
+        field.set_item(intern!(py, "schema"), placeholder_schema)?;
+        fields.set_item(&name, field)?;
+        lazy_fields.push(LazyFieldValidator {
+            name: name.unbind(),
+            validator: build_validator(&deferred_schema, config, definitions)?,
+        });
+    }
+
+    let field_order = fields
+        .keys()
+        .iter()
+        .map(|name| Ok(name.downcast_into::<PyString>()?.unbind()))
+        .collect::<PyResult<_>>()?;
+    let fields_schema = fields_schema.copy()?;
+    fields_schema.set_item(intern!(py, "fields"), fields)?;
+    Ok((fields_schema, lazy_fields, field_order))
+}
+
+/// Whether every class in the MRO of `class` (besides `object`) declares `__slots__`, and none of them
+/// include `extra_field_name`
+fn extra_slot_missing(class: &Bound<'_, PyType>, extra_field_name: &str) -> PyResult<bool> {
+    let py = class.py();
+    let object_type = py.get_type::<PyAny>();
+    for base in class.mro() {
+        if base.is(&object_type) {
+            continue;
+        }
+        let namespace = base.getattr(intern!(py, DUNDER_DICT))?;
+        let Some(slots) = namespace.get_item(intern!(py, "__slots__")).ok() else {
+            return Ok(false);
+        };
+        // with a `__dict__` slot, extra fields can be stored like any other attribute
+        let is_storage_slot = |slot: &Bound<'_, PyString>| slot == extra_field_name || slot == DUNDER_DICT;
+        if let Ok(slot) = slots.downcast::<PyString>() {
+            if is_storage_slot(slot) {
+                return Ok(false);
+            }
+        } else {
+            for slot in slots.try_iter()? {
+                if slot?.downcast::<PyString>().is_ok_and(is_storage_slot) {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+    Ok(true)
 }
 
 pub(super) fn force_setattr<'py, N, V>(py: Python<'py>, obj: &Bound<'py, PyAny>, attr_name: N, value: V) -> PyResult<()>
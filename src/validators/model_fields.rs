@@ -37,11 +37,14 @@ pub struct ModelFieldsValidator {
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Arc<CombinedValidator>>,
     extras_keys_validator: Option<Arc<CombinedValidator>>,
+    allowed_extra_keys: AHashSet<String>,
     strict: bool,
     from_attributes: bool,
     loc_by_alias: bool,
     validate_by_alias: Option<bool>,
     validate_by_name: Option<bool>,
+    // whether field defaults are ignored for missing fields, set by the model's `require_all`
+    require_all: bool,
 }
 
 impl BuildValidator for ModelFieldsValidator {
@@ -70,6 +73,11 @@ impl BuildValidator for ModelFieldsValidator {
             (Some(_), _) => return py_schema_err!("extras_keys_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let allowed_extra_keys: AHashSet<String> = schema
+            .get_as::<Vec<String>>(intern!(py, "allowed_extra_keys"))?
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
         let model_name: String = schema
             .get_as(intern!(py, "model_name"))?
             .unwrap_or_else(|| "Model".to_string());
@@ -107,11 +115,13 @@ impl BuildValidator for ModelFieldsValidator {
             extra_behavior,
             extras_validator,
             extras_keys_validator,
+            allowed_extra_keys,
             strict,
             from_attributes,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
             validate_by_alias: config.get_as(intern!(py, "validate_by_alias"))?,
             validate_by_name: config.get_as(intern!(py, "validate_by_name"))?,
+            require_all: schema.get_as(intern!(py, "require_all"))?.unwrap_or(false),
         })
         .into())
     }
@@ -205,7 +215,9 @@ impl Validator for ModelFieldsValidator {
                         used_keys.insert(lookup_path.first_key());
                     }
 
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    match state.validate_field(&field.name, |state| {
+                        field.validator.validate(py, value.borrow_input(), state)
+                    }) {
                         Ok(value) => {
                             model_dict.set_item(&field.name_py, value)?;
                             fields_set_vec.push(field.name_py.clone_ref(py));
@@ -227,7 +239,12 @@ impl Validator for ModelFieldsValidator {
                     continue;
                 }
 
-                match field.validator.default_value(py, Some(field.name.as_str()), state) {
+                let default_value = if self.require_all {
+                    Ok(None)
+                } else {
+                    field.validator.default_value(py, Some(field.name.as_str()), state)
+                };
+                match default_value {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
                         model_dict.set_item(&field.name_py, value)?;
@@ -266,6 +283,7 @@ impl Validator for ModelFieldsValidator {
                 extra_behavior: ExtraBehavior,
                 extras_validator: Option<&'a CombinedValidator>,
                 extras_keys_validator: Option<&'a CombinedValidator>,
+                allowed_extra_keys: &'a AHashSet<String>,
                 state: &'a mut ValidationState<'s, 'py>,
             }
 
@@ -307,6 +325,11 @@ impl Validator for ModelFieldsValidator {
                         let value = value.borrow_input();
                         // Unknown / extra field
                         match self.extra_behavior {
+                            ExtraBehavior::Forbid if self.allowed_extra_keys.contains(cow.as_ref()) => {
+                                let py_key = either_str.as_py_string(self.py, self.state.cache_str());
+                                model_extra_dict.set_item(&py_key, self.state.input_to_object(self.py, value)?)?;
+                                self.fields_set_vec.push(py_key.into());
+                            }
                             ExtraBehavior::Forbid => {
                                 self.errors.push(ValLineError::new_with_loc(
                                     ErrorTypeDefaults::ExtraForbidden,
@@ -346,7 +369,7 @@ impl Validator for ModelFieldsValidator {
                                         Err(err) => return Err(err),
                                     }
                                 } else {
-                                    model_extra_dict.set_item(&py_key, value.to_object(self.py)?)?;
+                                    model_extra_dict.set_item(&py_key, self.state.input_to_object(self.py, value)?)?;
                                     self.fields_set_vec.push(py_key.into());
                                 }
                             }
@@ -364,10 +387,11 @@ impl Validator for ModelFieldsValidator {
                 extra_behavior,
                 extras_validator: self.extras_validator.as_deref(),
                 extras_keys_validator: self.extras_keys_validator.as_deref(),
+                allowed_extra_keys: &self.allowed_extra_keys,
                 state,
             })??;
 
-            if matches!(extra_behavior, ExtraBehavior::Allow) {
+            if self.stores_extra(extra_behavior) {
                 model_extra_dict_op = Some(model_extra_dict);
             }
         }
@@ -378,9 +402,9 @@ impl Validator for ModelFieldsValidator {
             let fields_set = PySet::new(py, &fields_set_vec)?;
             state.add_fields_set(fields_set_count);
 
-            // if we have extra=allow, but we didn't create a dict because we were validating
-            // from attributes, set it now so __pydantic_extra__ is always a dict if extra=allow
-            if matches!(extra_behavior, ExtraBehavior::Allow) && model_extra_dict_op.is_none() {
+            // if we store extras, but we didn't create a dict because we were validating
+            // from attributes, set it now so __pydantic_extra__ is always a dict in that case
+            if self.stores_extra(extra_behavior) && model_extra_dict_op.is_none() {
                 model_extra_dict_op = Some(PyDict::new(py));
             }
 
@@ -494,3 +518,15 @@ impl Validator for ModelFieldsValidator {
         Self::EXPECTED_TYPE
     }
 }
+
+impl ModelFieldsValidator {
+    /// Extras are kept on `__pydantic_extra__` with `extra='allow'`, or with `extra='forbid'`
+    /// when some keys are allowlisted via `allowed_extra_keys`.
+    fn stores_extra(&self, extra_behavior: ExtraBehavior) -> bool {
+        match extra_behavior {
+            ExtraBehavior::Allow => true,
+            ExtraBehavior::Forbid => !self.allowed_extra_keys.is_empty(),
+            ExtraBehavior::Ignore => false,
+        }
+    }
+}
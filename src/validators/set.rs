@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use pyo3::types::{PyDict, PySet};
+use pyo3::types::{PyDict, PyFrozenSet, PySet};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
 use crate::errors::ValResult;
@@ -21,7 +21,7 @@ pub struct SetValidator {
 }
 
 macro_rules! set_build {
-    () => {
+    ($($flag:ident),* $(,)?) => {
         fn build(
             schema: &Bound<'_, PyDict>,
             config: Option<&Bound<'_, PyDict>>,
@@ -43,6 +43,7 @@ macro_rules! set_build {
                     max_length,
                     name,
                     fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+                    $($flag: schema.get_as(pyo3::intern!(py, stringify!($flag)))?.unwrap_or(false),)*
                 }
                 .into(),
             ))
@@ -77,6 +78,9 @@ impl Validator for SetValidator {
             fail_fast: self.fail_fast,
         })??;
         min_length_check!(input, "Set", self.min_length, set);
+        if state.extra().deep_freeze {
+            return Ok(PyFrozenSet::new(py, &set)?.into_py_any(py)?);
+        }
         Ok(set.into_py_any(py)?)
     }
 
@@ -1,13 +1,20 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
 
+use ahash::AHashSet;
+
+use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use pyo3::IntoPyObjectExt;
 use regex::Regex;
+use regex_automata::nfa::thompson::backtrack::BoundedBacktracker;
+use unicode_normalization::{is_nfc, is_nfd, is_nfkc, is_nfkd, UnicodeNormalization};
+use url::Url;
 
 use crate::build_tools::LazyLock;
-use crate::build_tools::{is_strict, py_schema_error_type, schema_or_config, schema_or_config_same};
+use crate::build_tools::{is_strict, py_schema_err, py_schema_error_type, schema_or_config, schema_or_config_same};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
 use crate::tools::SchemaDict;
@@ -76,10 +83,8 @@ impl Validator for StrValidator {
         input
             .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)
             .and_then(|val_match| {
-                Ok(val_match
-                    .unpack(state)
-                    .as_py_string(py, state.cache_str())
-                    .into_py_any(py)?)
+                let py_string = val_match.unpack(state).as_py_string(py, state.cache_str());
+                Ok(state.intern_str(py_string).into_py_any(py)?)
             })
     }
 
@@ -95,10 +100,17 @@ pub struct StrConstrainedValidator {
     pattern: Option<Pattern>,
     max_length: Option<usize>,
     min_length: Option<usize>,
-    strip_whitespace: bool,
+    strip_leading: bool,
+    strip_trailing: bool,
+    collapse_whitespace: bool,
+    normalize: Option<NormalizationForm>,
     to_lower: bool,
     to_upper: bool,
+    in_set: Option<AHashSet<String>>,
+    format: Option<StrFormat>,
     coerce_numbers_to_str: bool,
+    // reject strings which are empty once stripped of whitespace, whether or not `strip_whitespace` is set
+    strict_nonempty: bool,
 }
 
 impl_py_gc_traverse!(StrConstrainedValidator {});
@@ -115,8 +127,20 @@ impl Validator for StrConstrainedValidator {
             .unpack(state);
         let cow = either_str.as_cow()?;
         let mut str = cow.as_ref();
-        if self.strip_whitespace {
-            str = str.trim();
+        if self.strip_leading {
+            str = str.trim_start();
+        }
+        if self.strip_trailing {
+            str = str.trim_end();
+        }
+        let collapsed;
+        if self.collapse_whitespace {
+            collapsed = collapse_whitespace(str);
+            str = &collapsed;
+        }
+        let normalized = self.normalize.and_then(|form| form.normalize(str));
+        if let Some(ref normalized) = normalized {
+            str = normalized;
         }
 
         let str_len: Option<usize> = if self.min_length.is_some() | self.max_length.is_some() {
@@ -146,8 +170,26 @@ impl Validator for StrConstrainedValidator {
                 ));
             }
         }
+        if self.strict_nonempty && str.trim().is_empty() {
+            return Err(ValError::new(
+                ErrorType::StringTooShort {
+                    min_length: 1,
+                    context: None,
+                },
+                input,
+            ));
+        }
 
         if let Some(pattern) = &self.pattern {
+            if let Some(step_limit) = pattern.exceeds_step_limit(str) {
+                return Err(ValError::new(
+                    ErrorType::RegexTimeout {
+                        step_limit,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
             if !pattern.is_match(py, str)? {
                 return Err(ValError::new(
                     ErrorType::StringPatternMismatch {
@@ -159,17 +201,36 @@ impl Validator for StrConstrainedValidator {
             }
         }
 
-        let py_string = if self.to_lower {
-            state.maybe_cached_str(py, &str.to_lowercase())
+        let transformed = if self.to_lower {
+            Some(str.to_lowercase())
         } else if self.to_upper {
-            state.maybe_cached_str(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
+            Some(str.to_uppercase())
+        } else {
+            None
+        };
+
+        if let Some(in_set) = &self.in_set {
+            if !in_set.contains(transformed.as_deref().unwrap_or(str)) {
+                return Err(ValError::new(ErrorType::StringNotInSet { context: None }, input));
+            }
+        }
+
+        let canonical = match self.format {
+            Some(format) => format.check(str, input)?,
+            None => None,
+        };
+
+        let py_string = if let Some(canonical) = canonical {
+            state.maybe_cached_str(py, &canonical)
+        } else if let Some(transformed) = transformed {
+            state.maybe_cached_str(py, &transformed)
+        } else if self.strip_leading || self.strip_trailing || self.collapse_whitespace || normalized.is_some() {
             state.maybe_cached_str(py, str)
         } else {
             // we haven't modified the string, return the original as it might be a PyString
             either_str.as_py_string(py, state.cache_str())
         };
-        Ok(py_string.into_py_any(py)?)
+        Ok(state.intern_str(py_string).into_py_any(py)?)
     }
 
     fn get_name(&self) -> &'static str {
@@ -195,7 +256,8 @@ impl StrConstrainedValidator {
                     .map(|s| s.to_str())
                     .transpose()?
                     .unwrap_or(RegexEngine::RUST_REGEX);
-                Pattern::compile(s, regex_engine)
+                let step_limit = schema.get_as(intern!(py, "regex_step_limit"))?;
+                Pattern::compile(s, regex_engine, step_limit)
             })
             .transpose()?;
         let min_length: Option<usize> =
@@ -210,23 +272,53 @@ impl StrConstrainedValidator {
             intern!(py, "str_strip_whitespace"),
         )?
         .unwrap_or(false);
+        // `strip_whitespace` strips both ends, the directional flags can only add stripping on top of it
+        let strip_leading = strip_whitespace || schema.get_as(intern!(py, "strip_leading"))?.unwrap_or(false);
+        let strip_trailing = strip_whitespace || schema.get_as(intern!(py, "strip_trailing"))?.unwrap_or(false);
+        let collapse_whitespace: bool = schema.get_as(intern!(py, "collapse_whitespace"))?.unwrap_or(false);
+        let normalize = schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "normalize"))?
+            .map(|form| NormalizationForm::from_str(form.to_str()?))
+            .transpose()?;
         let to_lower: bool =
             schema_or_config(schema, config, intern!(py, "to_lower"), intern!(py, "str_to_lower"))?.unwrap_or(false);
         let to_upper: bool =
             schema_or_config(schema, config, intern!(py, "to_upper"), intern!(py, "str_to_upper"))?.unwrap_or(false);
 
+        let in_set = schema
+            .get_as::<Bound<'_, PyAny>>(intern!(py, "in_set"))?
+            .map(|values| {
+                values
+                    .try_iter()?
+                    .map(|value| value?.extract::<String>())
+                    .collect::<PyResult<AHashSet<String>>>()
+            })
+            .transpose()?;
+
+        let format = schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "format"))?
+            .map(|format| StrFormat::from_str(format.to_str()?))
+            .transpose()?;
+
         let coerce_numbers_to_str: bool =
             schema_or_config_same(schema, config, intern!(py, "coerce_numbers_to_str"))?.unwrap_or(false);
+        let strict_nonempty: bool = schema.get_as(intern!(py, "strict_nonempty"))?.unwrap_or(false);
 
         Ok(Self {
             strict: is_strict(schema, config)?,
             pattern,
             min_length,
             max_length,
-            strip_whitespace,
+            strip_leading,
+            strip_trailing,
+            collapse_whitespace,
+            normalize,
             to_lower,
             to_upper,
+            in_set,
+            format,
             coerce_numbers_to_str,
+            strict_nonempty,
         })
     }
 
@@ -236,10 +328,272 @@ impl StrConstrainedValidator {
         self.pattern.is_some()
             || self.max_length.is_some()
             || self.min_length.is_some()
-            || self.strip_whitespace
+            || self.strip_leading
+            || self.strip_trailing
+            || self.collapse_whitespace
+            || self.normalize.is_some()
             || self.to_lower
             || self.to_upper
+            || self.in_set.is_some()
+            || self.format.is_some()
+            || self.strict_nonempty
+    }
+}
+
+/// Replace each run of whitespace with a single space
+fn collapse_whitespace(str: &str) -> String {
+    let mut collapsed = String::with_capacity(str.len());
+    let mut in_whitespace = false;
+    for c in str.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(c);
+            in_whitespace = false;
+        }
+    }
+    collapsed
+}
+
+/// A Unicode normalization form strings are converted to with `normalize`
+#[derive(Debug, Clone, Copy)]
+enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl NormalizationForm {
+    fn from_str(form: &str) -> PyResult<Self> {
+        match form {
+            "NFC" => Ok(Self::Nfc),
+            "NFD" => Ok(Self::Nfd),
+            "NFKC" => Ok(Self::Nfkc),
+            "NFKD" => Ok(Self::Nfkd),
+            s => py_schema_err!(
+                "Invalid normalization form: {}, should be 'NFC', 'NFD', 'NFKC' or 'NFKD'",
+                s
+            ),
+        }
+    }
+
+    /// `str` in this form, None if it's already in this form
+    fn normalize(self, str: &str) -> Option<String> {
+        match self {
+            Self::Nfc => (!is_nfc(str)).then(|| str.nfc().collect()),
+            Self::Nfd => (!is_nfd(str)).then(|| str.nfd().collect()),
+            Self::Nfkc => (!is_nfkc(str)).then(|| str.nfkc().collect()),
+            Self::Nfkd => (!is_nfkd(str)).then(|| str.nfkd().collect()),
+        }
+    }
+}
+
+/// A format checked by parsing the string, the string itself is returned unchanged except for `LanguageTag`
+/// which is returned in its canonical case
+#[derive(Debug, Clone, Copy)]
+enum StrFormat {
+    Uri,
+    Email,
+    Hostname,
+    Ipv4,
+    Ipv6,
+    Base64,
+    Base32,
+    LanguageTag,
+    JsonPointer,
+}
+
+impl StrFormat {
+    fn from_str(format: &str) -> PyResult<Self> {
+        match format {
+            "uri" => Ok(Self::Uri),
+            "email" => Ok(Self::Email),
+            "hostname" => Ok(Self::Hostname),
+            "ipv4" => Ok(Self::Ipv4),
+            "ipv6" => Ok(Self::Ipv6),
+            "base64" => Ok(Self::Base64),
+            "base32" => Ok(Self::Base32),
+            "language_tag" => Ok(Self::LanguageTag),
+            "json_pointer" => Ok(Self::JsonPointer),
+            s => py_schema_err!("Invalid string format: {}", s),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Uri => "uri",
+            Self::Email => "email",
+            Self::Hostname => "hostname",
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+            Self::Base64 => "base64",
+            Self::Base32 => "base32",
+            Self::LanguageTag => "language_tag",
+            Self::JsonPointer => "json_pointer",
+        }
+    }
+
+    /// Check `str` matches the format, returning its canonical form if that differs from `str`
+    fn check<'py>(self, str: &str, input: &(impl Input<'py> + ?Sized)) -> ValResult<Option<String>> {
+        let result = match self {
+            Self::LanguageTag => {
+                return match canonical_language_tag(str) {
+                    Ok(canonical) => Ok(Some(canonical).filter(|c| c != str)),
+                    Err(error) => Err(self.error(error, input)),
+                }
+            }
+            Self::Uri => Url::parse(str).map(drop).map_err(|e| e.to_string()),
+            Self::Email => check_email(str),
+            Self::Hostname => check_hostname(str),
+            Self::Ipv4 => str.parse::<Ipv4Addr>().map(drop).map_err(|e| e.to_string()),
+            Self::Ipv6 => str.parse::<Ipv6Addr>().map(drop).map_err(|e| e.to_string()),
+            Self::Base64 => check_base_encoding(
+                str,
+                |c| c.is_ascii_alphanumeric() || c == '+' || c == '/',
+                4,
+                &[0, 1, 2],
+            ),
+            Self::Base32 => check_base_encoding(str, |c| matches!(c, 'A'..='Z' | '2'..='7'), 8, &[0, 1, 3, 4, 6]),
+            Self::JsonPointer => check_json_pointer(str),
+        };
+        result.map(|()| None).map_err(|error| self.error(error, input))
+    }
+
+    fn error<'py>(self, error: String, input: &(impl Input<'py> + ?Sized)) -> ValError {
+        ValError::new(
+            ErrorType::StringFormat {
+                format: self.name().to_string(),
+                error,
+                context: None,
+            },
+            input,
+        )
+    }
+}
+
+/// A pragmatic check of the shape of an email address: a dot-atom local part, an `@` and a hostname
+/// with at least two labels, quoted local parts and IP address literals aren't supported
+fn check_email(str: &str) -> Result<(), String> {
+    let Some((local, domain)) = str.rsplit_once('@') else {
+        return Err("missing an '@' sign".to_string());
+    };
+    let is_atom_char = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c);
+    if local.is_empty() || local.len() > 64 {
+        return Err("the part before the '@' should have 1 to 64 characters".to_string());
+    }
+    if local
+        .split('.')
+        .any(|atom| atom.is_empty() || !atom.chars().all(is_atom_char))
+    {
+        return Err("the part before the '@' contains invalid characters".to_string());
+    }
+    check_hostname(domain).map_err(|e| format!("the domain is invalid, {e}"))?;
+    if !domain.trim_end_matches('.').contains('.') {
+        return Err("the domain should contain a '.'".to_string());
+    }
+    Ok(())
+}
+
+/// Check a hostname as defined by RFC 1123, a trailing `.` is allowed
+fn check_hostname(str: &str) -> Result<(), String> {
+    let name = str.strip_suffix('.').unwrap_or(str);
+    if name.is_empty() || name.len() > 253 {
+        return Err("should have 1 to 253 characters".to_string());
+    }
+    for label in name.split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err("each label should have 1 to 63 characters".to_string());
+        }
+        if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err("labels should only contain letters, digits and hyphens".to_string());
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err("labels should not start or end with a hyphen".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Check the shape of a base64 or base32 string (RFC 4648) without decoding it: characters from the alphabet,
+/// a length which is a multiple of `block_len` and one of the allowed numbers of trailing `=` padding characters
+fn check_base_encoding(
+    str: &str,
+    is_alphabet_char: impl Fn(char) -> bool,
+    block_len: usize,
+    padding_lens: &[usize],
+) -> Result<(), String> {
+    let data = str.trim_end_matches('=');
+    if !data.chars().all(is_alphabet_char) {
+        return Err("contains invalid characters".to_string());
+    }
+    if str.len() % block_len != 0 {
+        return Err(format!("the length should be a multiple of {block_len}"));
+    }
+    if !padding_lens.contains(&(str.len() - data.len())) {
+        return Err("the padding is invalid".to_string());
     }
+    Ok(())
+}
+
+/// Check the shape of a JSON Pointer (RFC 6901) like `/a/b~1c`: empty, or `/` separated reference tokens where
+/// `~` is only used in the escapes `~0` and `~1`
+fn check_json_pointer(str: &str) -> Result<(), String> {
+    if !(str.is_empty() || str.starts_with('/')) {
+        return Err("should be empty or start with '/'".to_string());
+    }
+    let mut chars = str.chars();
+    while let Some(c) = chars.next() {
+        if c == '~' && !matches!(chars.next(), Some('0' | '1')) {
+            return Err("'~' should be followed by '0' or '1'".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Check the shape of a BCP 47 language tag like `en-US` or `zh-Hant-TW` and return it with each subtag in its
+/// conventional case: the language and anything from a singleton onwards lowercase, a script title case and
+/// a region uppercase. Subtags aren't checked against the registry.
+fn canonical_language_tag(str: &str) -> Result<String, String> {
+    let mut canonical = String::with_capacity(str.len());
+    let mut after_singleton = false;
+    let mut last_len = 0;
+    for (i, subtag) in str.split('-').enumerate() {
+        if subtag.is_empty() || subtag.len() > 8 || !subtag.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err("each subtag should have 1 to 8 letters or digits".to_string());
+        }
+        let is_alpha = subtag.chars().all(|c| c.is_ascii_alphabetic());
+        if i == 0 {
+            if !is_alpha || !(subtag.len() >= 2 || subtag.eq_ignore_ascii_case("x")) {
+                return Err("should start with a language subtag of 2 to 8 letters".to_string());
+            }
+            canonical.push_str(&subtag.to_ascii_lowercase());
+            after_singleton = subtag.len() == 1;
+        } else {
+            canonical.push('-');
+            if after_singleton || subtag.len() == 1 {
+                after_singleton = true;
+                canonical.push_str(&subtag.to_ascii_lowercase());
+            } else if subtag.len() == 4 && is_alpha {
+                canonical.push_str(&subtag[..1].to_ascii_uppercase());
+                canonical.push_str(&subtag[1..].to_ascii_lowercase());
+            } else if (subtag.len() == 2 && is_alpha)
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+            {
+                canonical.push_str(&subtag.to_ascii_uppercase());
+            } else {
+                canonical.push_str(&subtag.to_ascii_lowercase());
+            }
+        }
+        last_len = subtag.len();
+    }
+    if last_len == 1 {
+        return Err("a singleton subtag should be followed by another subtag".to_string());
+    }
+    Ok(canonical)
 }
 
 #[derive(Debug, Clone)]
@@ -251,6 +605,9 @@ struct Pattern {
 #[derive(Debug, Clone)]
 enum RegexEngine {
     RustRegex(Regex),
+    // `rust-regex` with `regex_step_limit`, the backtracker visits each pattern state at most once per position of
+    // the input and refuses inputs it can't search within its visited capacity, so matching is bounded by the limit
+    RustRegexStepLimit(Box<BoundedBacktracker>, usize),
     PythonRe(Py<PyAny>),
 }
 
@@ -271,7 +628,7 @@ impl Pattern {
         }
     }
 
-    fn compile(pattern: Bound<'_, PyAny>, engine: &str) -> PyResult<Self> {
+    fn compile(pattern: Bound<'_, PyAny>, engine: &str, step_limit: Option<usize>) -> PyResult<Self> {
         let pattern_str = Self::extract_pattern_str(&pattern)?;
 
         let py = pattern.py();
@@ -281,6 +638,11 @@ impl Pattern {
         let re_pattern = re_module.getattr(intern!(py, "Pattern"))?;
 
         if pattern.is_instance(&re_pattern)? {
+            if step_limit.is_some() {
+                return Err(py_schema_error_type!(
+                    "'regex_step_limit' is not supported with a compiled `re.Pattern`"
+                ));
+            }
             // if the pattern is already a compiled regex object, we default to using the python re engine
             // so that any flags, etc. are preserved
             Ok(Self {
@@ -288,11 +650,26 @@ impl Pattern {
                 engine: RegexEngine::PythonRe(pattern.unbind()),
             })
         } else {
-            let engine = match engine {
-                RegexEngine::RUST_REGEX => {
+            let engine = match (engine, step_limit) {
+                (RegexEngine::RUST_REGEX, None) => {
                     RegexEngine::RustRegex(Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?)
                 }
-                RegexEngine::PYTHON_RE => RegexEngine::PythonRe(re_compile.call1((pattern,))?.into()),
+                (RegexEngine::RUST_REGEX, Some(step_limit)) => {
+                    // the visited capacity is in bytes, one bit per step
+                    let config = BoundedBacktracker::config().visited_capacity(step_limit.div_ceil(8));
+                    let backtracker = BoundedBacktracker::builder()
+                        .configure(config)
+                        .build(&pattern_str)
+                        .map_err(|e| py_schema_error_type!("{}", e))?;
+                    RegexEngine::RustRegexStepLimit(Box::new(backtracker), step_limit)
+                }
+                (RegexEngine::PYTHON_RE, Some(_)) => {
+                    return Err(py_schema_error_type!(
+                        "'regex_step_limit' is only supported by the '{}' regex engine",
+                        RegexEngine::RUST_REGEX
+                    ));
+                }
+                (RegexEngine::PYTHON_RE, None) => RegexEngine::PythonRe(re_compile.call1((pattern,))?.into()),
                 _ => return Err(py_schema_error_type!("Invalid regex engine: {}", engine)),
             };
 
@@ -303,9 +680,24 @@ impl Pattern {
         }
     }
 
+    /// The step limit if searching `target` could take more steps than `regex_step_limit` allows
+    fn exceeds_step_limit(&self, target: &str) -> Option<usize> {
+        match &self.engine {
+            RegexEngine::RustRegexStepLimit(backtracker, step_limit)
+                if target.len() > backtracker.max_haystack_len() =>
+            {
+                Some(*step_limit)
+            }
+            _ => None,
+        }
+    }
+
     fn is_match(&self, py: Python<'_>, target: &str) -> PyResult<bool> {
         match &self.engine {
             RegexEngine::RustRegex(regex) => Ok(regex.is_match(target)),
+            RegexEngine::RustRegexStepLimit(backtracker, _) => backtracker
+                .try_is_match(&mut backtracker.create_cache(), target)
+                .map_err(|e| PyValueError::new_err(e.to_string())),
             RegexEngine::PythonRe(py_regex) => {
                 Ok(!py_regex.call_method1(py, intern!(py, "search"), (target,))?.is_none(py))
             }
@@ -9,7 +9,7 @@ use pyo3::IntoPyObjectExt;
 use speedate::{MicrosecondsPrecisionOverflowBehavior, Time};
 
 use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
+use crate::errors::{comparison_context, ErrorType, ValError, ValResult};
 use crate::input::Input;
 
 use super::datetime::extract_microseconds_precision;
@@ -62,7 +62,7 @@ impl Validator for TimeValidator {
                             return Err(ValError::new(
                                 ErrorType::$error {
                                     $constraint: constraint.to_string().into(),
-                                    context: None,
+                                    context: Some(comparison_context(py, raw_time.to_string())?.unbind()),
                                 },
                                 input,
                             ));
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use pyo3::exceptions::PyValueError;
@@ -6,18 +7,42 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDelta, PyDeltaAccess, PyDict, PyString};
 use speedate::{Duration, MicrosecondsPrecisionOverflowBehavior};
 
-use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{duration_as_pytimedelta, Input};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{comparison_context, ErrorType, ValError, ValResult};
+use crate::input::{duration_as_pytimedelta, iso8601_as_duration, EitherTimedelta, Input, InputType};
+use crate::tools::SchemaDict;
 
 use super::datetime::extract_microseconds_precision;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct TimeDeltaValidator {
     strict: bool,
     constraints: Option<TimedeltaConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    // which string formats are accepted, when unset both are
+    duration_format: Option<DurationFormat>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DurationFormat {
+    // `[-][DD days, ]HH:MM:SS[.ffffff]` and the other formats speedate accepts, except ISO 8601
+    NumberOrColon,
+    // `[-]P[nY][nM][nW][nD][T[nH][nM][nS]]`
+    Iso8601,
+}
+
+impl DurationFormat {
+    fn from_str(value: &str) -> PyResult<Self> {
+        match value {
+            "number-or-colon" => Ok(Self::NumberOrColon),
+            "iso8601" => Ok(Self::Iso8601),
+            s => py_schema_err!(
+                "Invalid duration_format: {}, should be 'number-or-colon' or 'iso8601'",
+                s
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -64,6 +89,10 @@ impl BuildValidator for TimeDeltaValidator {
                 || constraints.gt.is_some())
             .then_some(constraints),
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            duration_format: schema
+                .get_as::<Bound<'_, PyString>>(intern!(py, "duration_format"))?
+                .map(|format| DurationFormat::from_str(format.to_str()?))
+                .transpose()?,
         })
         .into())
     }
@@ -78,9 +107,18 @@ impl Validator for TimeDeltaValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let timedelta = input
-            .validate_timedelta(state.strict_or(self.strict), self.microseconds_precision)?
-            .unpack(state);
+        let strict = state.strict_or(self.strict);
+        let timedelta = match self.duration_str(input, strict, state.extra().input_type) {
+            Some(duration_str) => {
+                if state.extra().input_type == InputType::Python {
+                    state.floor_exactness(Exactness::Lax);
+                }
+                self.parse_duration_str(input, &duration_str, strict)?
+            }
+            None => input
+                .validate_timedelta(strict, self.microseconds_precision)?
+                .unpack(state),
+        };
         let py_timedelta = timedelta.clone().into_pyobject(py)?;
         if let Some(constraints) = &self.constraints {
             let raw_timedelta = timedelta.to_duration()?;
@@ -91,7 +129,13 @@ impl Validator for TimeDeltaValidator {
                         if !raw_timedelta.$constraint(constraint) {
                             return Err(ValError::new(
                                 ErrorType::$error {
-                                    context: None,
+                                    context: Some(
+                                        comparison_context(
+                                            py,
+                                            pydelta_to_human_readable(duration_as_pytimedelta(py, &raw_timedelta)?),
+                                        )?
+                                        .unbind(),
+                                    ),
                                     $constraint: pydelta_to_human_readable(duration_as_pytimedelta(py, constraint)?)
                                         .into(),
                                 },
@@ -114,6 +158,49 @@ impl Validator for TimeDeltaValidator {
         Self::EXPECTED_TYPE
     }
 }
+
+impl TimeDeltaValidator {
+    /// The string to parse according to `duration_format`, if one is set and the input is a string
+    /// which may be parsed as a timedelta
+    fn duration_str<'py>(
+        &self,
+        input: &(impl Input<'py> + ?Sized),
+        strict: bool,
+        input_type: InputType,
+    ) -> Option<String> {
+        self.duration_format?;
+        if strict && input_type == InputType::Python {
+            return None;
+        }
+        let either_str = input.validate_str(false, false).ok()?.into_inner();
+        either_str.as_cow().ok().map(Cow::into_owned)
+    }
+
+    fn parse_duration_str<'py>(
+        &self,
+        input: &(impl Input<'py> + ?Sized),
+        duration_str: &str,
+        strict: bool,
+    ) -> ValResult<EitherTimedelta<'py>> {
+        let is_iso8601 = duration_str.trim_start_matches(['-', '+']).starts_with(['P', 'p']);
+        match self.duration_format {
+            Some(DurationFormat::Iso8601) => {
+                Ok(iso8601_as_duration(input, duration_str, self.microseconds_precision)?.into())
+            }
+            Some(DurationFormat::NumberOrColon) if is_iso8601 => Err(ValError::new(
+                ErrorType::TimeDeltaParsing {
+                    error: format!("ISO 8601 duration '{duration_str}' is not accepted").into(),
+                    context: None,
+                },
+                input,
+            )),
+            _ => Ok(input
+                .validate_timedelta(strict, self.microseconds_precision)?
+                .into_inner()),
+        }
+    }
+}
+
 fn pydelta_to_human_readable(py_delta: Bound<'_, PyDelta>) -> String {
     let total_seconds = py_delta.get_seconds();
     let hours = total_seconds / 3600;
@@ -1,27 +1,61 @@
 use std::collections::VecDeque;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
 
-use crate::build_tools::is_strict;
-use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, LocItem, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
 use crate::input::{BorrowInput, Input, ValidatedTuple};
 use crate::tools::SchemaDict;
 
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
+/// When non-tuple sequences (e.g. lists) are accepted as tuple input,
+/// strings, bytes and mappings are never accepted whatever the mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SequenceCoercion {
+    /// only in lax mode
+    Lax,
+    /// in both lax and strict mode
+    Always,
+    /// never, only tuples are accepted
+    Never,
+}
+
+impl FromStr for SequenceCoercion {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lax" => Ok(Self::Lax),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            s => py_schema_err!(
+                "Invalid sequence coercion: `{}`, expected `lax`, `always` or `never`",
+                s
+            ),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct TupleValidator {
     strict: bool,
+    sequence_coercion: SequenceCoercion,
     validators: Vec<Arc<CombinedValidator>>,
     variadic_item_index: Option<usize>,
     min_length: Option<usize>,
     max_length: Option<usize>,
     name: String,
     fail_fast: bool,
+    // with `namedtuple_cls`, the output is built with `namedtuple_cls._make(items)`
+    namedtuple_cls: Option<Py<PyType>>,
+    // the class's `_fields`, used in place of indices in error locations
+    field_names: Vec<String>,
 }
 
 impl BuildValidator for TupleValidator {
@@ -46,22 +80,69 @@ impl BuildValidator for TupleValidator {
         }
         let name = format!("tuple[{}]", validator_names.join(", "));
 
+        let namedtuple_cls: Option<Bound<'_, PyType>> = schema.get_as(intern!(py, "namedtuple_cls"))?;
+        let field_names = match namedtuple_cls {
+            Some(ref cls) => {
+                let (Some(fields), true) = (
+                    cls.getattr_opt(intern!(py, "_fields"))?,
+                    cls.hasattr(intern!(py, "_make"))?,
+                ) else {
+                    return py_schema_err!(
+                        "`namedtuple_cls` must have `_fields` and a `_make` method, e.g. a `NamedTuple` subclass"
+                    );
+                };
+                let Ok(field_names) = fields.extract::<Vec<String>>() else {
+                    return py_schema_err!("`namedtuple_cls._fields` must be a sequence of strings");
+                };
+                if variadic_item_index.is_some() {
+                    return py_schema_err!("`namedtuple_cls` can't be used with `variadic_item_index`");
+                }
+                if field_names.len() != validators.len() {
+                    return py_schema_err!(
+                        "`namedtuple_cls` has {} fields but `items_schema` has {} items",
+                        field_names.len(),
+                        validators.len()
+                    );
+                }
+                field_names
+            }
+            None => Vec::new(),
+        };
+
         Ok(CombinedValidator::Tuple(Self {
             strict: is_strict(schema, config)?,
+            sequence_coercion: schema
+                .get_as::<Bound<'_, PyString>>(intern!(py, "sequence_coercion"))?
+                .map_or(Ok(SequenceCoercion::Lax), |mode| {
+                    mode.to_str().and_then(SequenceCoercion::from_str)
+                })?,
             validators,
             variadic_item_index,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
             name,
             fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
+            namedtuple_cls: namedtuple_cls.map(Bound::unbind),
+            field_names,
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(TupleValidator { validators });
+impl_py_gc_traverse!(TupleValidator {
+    validators,
+    namedtuple_cls
+});
 
 impl TupleValidator {
+    /// The location of the item at `index`, the field name if the named tuple class has one
+    fn item_loc(&self, index: usize) -> LocItem {
+        match self.field_names.get(index) {
+            Some(field_name) => field_name.into(),
+            None => index.into(),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn validate_tuple_items<'py, I: BorrowInput<'py>>(
         &self,
@@ -78,10 +159,16 @@ impl TupleValidator {
         // Validate the head:
         for validator in item_validators {
             match collection_iter.next() {
-                Some((index, input_item)) => match validator.validate(py, input_item.borrow_input(), state) {
+                Some((index, input_item)) => match state.validate_item(self.item_loc(index), |state| {
+                    validator.validate(py, input_item.borrow_input(), state)
+                }) {
                     Ok(item) => self.push_output_item(input, output, item, actual_length)?,
                     Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        errors.extend(
+                            line_errors
+                                .into_iter()
+                                .map(|err| err.with_outer_location(self.item_loc(index))),
+                        );
                     }
                     Err(ValError::Omit) => (),
                     Err(err) => return Err(err),
@@ -91,7 +178,11 @@ impl TupleValidator {
                     if let Some(value) = validator.default_value(py, Some(index), state)? {
                         output.push(value);
                     } else {
-                        errors.push(ValLineError::new_with_loc(ErrorTypeDefaults::Missing, input, index));
+                        errors.push(ValLineError::new_with_loc(
+                            ErrorTypeDefaults::Missing,
+                            input,
+                            self.item_loc(index),
+                        ));
                     }
                 }
             }
@@ -119,6 +210,7 @@ impl TupleValidator {
             self.validators.len()
         };
         let mut output = Vec::with_capacity(expected_length);
+        let fail_fast = self.fail_fast || state.fail_fast;
         if let Some(variable_validator_index) = self.variadic_item_index {
             let (head_validators, [variable_validator, tail_validators @ ..]) =
                 self.validators.split_at(variable_validator_index)
@@ -136,26 +228,32 @@ impl TupleValidator {
                 head_validators,
                 collection_iter,
                 actual_length,
-                self.fail_fast,
+                fail_fast,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if fail_fast && !errors.is_empty() {
                 return Ok(output);
             }
 
             let n_tail_validators = tail_validators.len();
             if n_tail_validators == 0 {
                 for (index, input_item) in collection_iter {
-                    match variable_validator.validate(py, input_item.borrow_input(), state) {
+                    match state.validate_item(self.item_loc(index), |state| {
+                        variable_validator.validate(py, input_item.borrow_input(), state)
+                    }) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
-                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                            errors.extend(
+                                line_errors
+                                    .into_iter()
+                                    .map(|err| err.with_outer_location(self.item_loc(index))),
+                            );
                         }
                         Err(ValError::Omit) => (),
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if fail_fast && !errors.is_empty() {
                         return Ok(output);
                     }
                 }
@@ -177,20 +275,22 @@ impl TupleValidator {
                     let buffered_item = tail_buffer.pop_front().unwrap();
                     tail_buffer.push_back(input_item);
 
-                    match variable_validator.validate(py, buffered_item.borrow_input(), state) {
+                    match state.validate_item(self.item_loc(buffer_item_index), |state| {
+                        variable_validator.validate(py, buffered_item.borrow_input(), state)
+                    }) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
                                 line_errors
                                     .into_iter()
-                                    .map(|err| err.with_outer_location(buffer_item_index)),
+                                    .map(|err| err.with_outer_location(self.item_loc(buffer_item_index))),
                             );
                         }
                         Err(ValError::Omit) => (),
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if fail_fast && !errors.is_empty() {
                         return Ok(output);
                     }
                 }
@@ -205,7 +305,7 @@ impl TupleValidator {
                     tail_validators,
                     &mut NextCountingIterator::new(tail_buffer.into_iter(), index),
                     actual_length,
-                    self.fail_fast,
+                    fail_fast,
                 )?;
             }
         } else {
@@ -219,10 +319,10 @@ impl TupleValidator {
                 &self.validators,
                 collection_iter,
                 actual_length,
-                self.fail_fast,
+                fail_fast,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if fail_fast && !errors.is_empty() {
                 return Ok(output);
             }
 
@@ -277,7 +377,15 @@ impl Validator for TupleValidator {
         // this validator does not yet support partial validation, disable it to avoid incorrect results
         state.allow_partial = false.into();
 
-        let collection = input.validate_tuple(state.strict_or(self.strict))?.unpack(state);
+        let strict = match self.sequence_coercion {
+            SequenceCoercion::Lax => state.strict_or(self.strict),
+            // lists are accepted in strict mode too, but not the other iterables which lax mode accepts
+            SequenceCoercion::Always => {
+                state.strict_or(self.strict) && !input.as_python().is_some_and(PyAnyMethods::is_instance_of::<PyList>)
+            }
+            SequenceCoercion::Never => true,
+        };
+        let collection = input.validate_tuple(strict)?.unpack(state);
         let actual_length = collection.len();
 
         let mut errors: Vec<ValLineError> = Vec::new();
@@ -306,10 +414,12 @@ impl Validator for TupleValidator {
             }
         }
 
-        if errors.is_empty() {
-            Ok(PyTuple::new(py, output)?.into())
-        } else {
-            Err(ValError::LineErrors(errors))
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+        match self.namedtuple_cls {
+            Some(ref cls) => Ok(cls.call_method1(py, intern!(py, "_make"), (output,))?),
+            None => Ok(PyTuple::new(py, output)?.into()),
         }
     }
 
@@ -2,19 +2,19 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString, PyType};
+use pyo3::types::{PyBool, PyDict, PyInt, PyString, PyType};
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{is_strict, schema_or_config, ExtraBehavior};
 use crate::errors::LocItem;
-use crate::errors::{ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
 use crate::input::ValidationMatch;
 use crate::input::{Input, ValidatedDict};
-use crate::lookup_key::LookupKeyCollection;
-use crate::tools::SchemaDict;
-use ahash::AHashSet;
+use crate::lookup_key::{LookupKey, LookupKeyCollection};
+use crate::tools::{levenshtein_distance, SchemaDict};
+use ahash::{AHashMap, AHashSet};
 use jiter::PartialMode;
 
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -35,11 +35,17 @@ pub struct TypedDictValidator {
     fields: Vec<TypedDictField>,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Arc<CombinedValidator>>,
+    allowed_extra_keys: AHashSet<String>,
     strict: bool,
     loc_by_alias: bool,
     validate_by_alias: Option<bool>,
     validate_by_name: Option<bool>,
     cls_name: Option<String>,
+    // `title` from the typed dict's config, used in place of `cls_name` in `ValidationError` titles
+    title: Option<String>,
+    coerce_keys: bool,
+    // whether keys are matched to fields ignoring case
+    case_insensitive_keys: bool,
 }
 
 impl BuildValidator for TypedDictValidator {
@@ -68,6 +74,11 @@ impl BuildValidator for TypedDictValidator {
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let allowed_extra_keys: AHashSet<String> = schema
+            .get_as::<Vec<String>>(intern!(py, "allowed_extra_keys"))?
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
 
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<TypedDictField> = Vec::with_capacity(fields_dict.len());
@@ -131,15 +142,34 @@ impl BuildValidator for TypedDictValidator {
                 required,
             });
         }
+
+        let case_insensitive_keys = schema.get_as(intern!(py, "case_insensitive_keys"))?.unwrap_or(false);
+        if case_insensitive_keys {
+            let mut folded_names: AHashMap<String, &str> = AHashMap::with_capacity(fields.len());
+            for field in &fields {
+                if let Some(other) = folded_names.insert(field.name.to_lowercase(), &field.name) {
+                    return py_schema_err!(
+                        "Fields '{}' and '{}' only differ in case, so cannot be matched with case_insensitive_keys",
+                        other,
+                        field.name
+                    );
+                }
+            }
+        }
+
         Ok(CombinedValidator::TypedDict(Self {
             fields,
             extra_behavior,
             extras_validator,
+            allowed_extra_keys,
             strict,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
             validate_by_alias: config.get_as(intern!(py, "validate_by_alias"))?,
             validate_by_name: config.get_as(intern!(py, "validate_by_name"))?,
             cls_name,
+            title: config.get_as(intern!(py, "title"))?,
+            coerce_keys: schema.get_as(intern!(py, "coerce_keys"))?.unwrap_or(false),
+            case_insensitive_keys,
         })
         .into())
     }
@@ -150,16 +180,15 @@ impl_py_gc_traverse!(TypedDictValidator {
     extras_validator
 });
 
-impl Validator for TypedDictValidator {
-    fn validate<'py>(
+impl TypedDictValidator {
+    fn validate_fields<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
+        dict: impl ValidatedDict<'py>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let strict = state.strict_or(self.strict);
         let extra_behavior = state.extra_behavior_or(self.extra_behavior);
-        let dict = input.validate_dict(strict)?;
 
         let output_dict = PyDict::new(py);
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
@@ -174,6 +203,28 @@ impl Validator for TypedDictValidator {
         let validate_by_alias = state.validate_by_alias_or(self.validate_by_alias);
         let validate_by_name = state.validate_by_name_or(self.validate_by_name);
 
+        // with `case_insensitive_keys`, the input's keys are folded once and each field is looked up by the input's
+        // own spelling of its keys
+        let folded_keys = if self.case_insensitive_keys {
+            Some(dict.iterate(FoldKeys)??)
+        } else {
+            None
+        };
+        let folded_lookup_keys = match folded_keys {
+            Some(ref folded_keys) => Some(
+                self.fields
+                    .iter()
+                    .map(|field| {
+                        let lookup_key = field
+                            .lookup_key_collection
+                            .select(validate_by_alias, validate_by_name)?;
+                        Ok(folded_keys.resolve(py, lookup_key))
+                    })
+                    .collect::<PyResult<Vec<_>>>()?,
+            ),
+            None => None,
+        };
+
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
         let mut used_keys: Option<AHashSet<&str>> = if extra_behavior == ExtraBehavior::Ignore || dict.is_py_get_attr()
@@ -189,10 +240,26 @@ impl Validator for TypedDictValidator {
 
             let mut fields_set_count: usize = 0;
 
-            for field in &self.fields {
-                let lookup_key = field
-                    .lookup_key_collection
-                    .select(validate_by_alias, validate_by_name)?;
+            for (index, field) in self.fields.iter().enumerate() {
+                let lookup_key = match folded_lookup_keys.as_ref().map(|lookup_keys| &lookup_keys[index]) {
+                    None => field
+                        .lookup_key_collection
+                        .select(validate_by_alias, validate_by_name)?,
+                    Some(Ok(lookup_key)) => lookup_key,
+                    Some(Err(ambiguous_keys)) => {
+                        // several keys match the field ignoring case, so it's reported against the whole input
+                        // and the keys aren't extra
+                        if let Some(ref mut used_keys) = used_keys {
+                            used_keys.extend(ambiguous_keys.iter().map(String::as_str));
+                        }
+                        errors.push(ValLineError::new_with_loc(
+                            ErrorTypeDefaults::AmbiguousKey,
+                            input,
+                            field.name.clone(),
+                        ));
+                        continue;
+                    }
+                };
                 let op_key_value = match dict.get_item(lookup_key) {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
@@ -225,7 +292,9 @@ impl Validator for TypedDictValidator {
                     let state =
                         &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
 
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    match state.validate_field(&field.name, |state| {
+                        field.validator.validate(py, value.borrow_input(), state)
+                    }) {
                         Ok(value) => {
                             output_dict.set_item(&field.name_py, value)?;
                             fields_set_count += 1;
@@ -290,13 +359,17 @@ impl Validator for TypedDictValidator {
             struct ValidateExtras<'a, 's, 'py> {
                 py: Python<'py>,
                 used_keys: AHashSet<&'a str>,
+                fields: &'a [TypedDictField],
                 errors: &'a mut Vec<ValLineError>,
                 extras_validator: Option<&'a CombinedValidator>,
+                allowed_extra_keys: &'a AHashSet<String>,
                 output_dict: &'a Bound<'py, PyDict>,
                 state: &'a mut ValidationState<'s, 'py>,
                 extra_behavior: ExtraBehavior,
                 partial_last_key: Option<LocItem>,
                 allow_partial: PartialMode,
+                validate_by_alias: bool,
+                validate_by_name: bool,
             }
 
             impl<'py, Key, Value> ConsumeIterator<ValResult<(Key, Value)>> for ValidateExtras<'_, '_, 'py>
@@ -333,12 +406,30 @@ impl Validator for TypedDictValidator {
                         let value = value.borrow_input();
                         // Unknown / extra field
                         match self.extra_behavior {
+                            ExtraBehavior::Forbid if self.allowed_extra_keys.contains(cow.as_ref()) => {
+                                let py_key = either_str.as_py_string(self.py, self.state.cache_str());
+                                self.output_dict
+                                    .set_item(py_key, self.state.input_to_object(self.py, value)?)?;
+                            }
                             ExtraBehavior::Forbid => {
-                                self.errors.push(ValLineError::new_with_loc(
-                                    ErrorTypeDefaults::ExtraForbidden,
-                                    value,
-                                    raw_key.clone(),
-                                ));
+                                let error_type = match nearest_field_key(
+                                    self.fields,
+                                    self.validate_by_alias,
+                                    self.validate_by_name,
+                                    &self.used_keys,
+                                    cow.as_ref(),
+                                )? {
+                                    Some(suggestion) => {
+                                        let context = PyDict::new(self.py);
+                                        context.set_item(intern!(self.py, "suggestion"), suggestion)?;
+                                        ErrorType::ExtraForbidden {
+                                            context: Some(context.unbind()),
+                                        }
+                                    }
+                                    None => ErrorTypeDefaults::ExtraForbidden,
+                                };
+                                self.errors
+                                    .push(ValLineError::new_with_loc(error_type, value, raw_key.clone()));
                             }
                             ExtraBehavior::Ignore => {}
                             ExtraBehavior::Allow => {
@@ -363,7 +454,8 @@ impl Validator for TypedDictValidator {
                                         Err(err) => return Err(err),
                                     }
                                 } else {
-                                    self.output_dict.set_item(py_key, value.to_object(self.py)?)?;
+                                    self.output_dict
+                                        .set_item(py_key, self.state.input_to_object(self.py, value)?)?;
                                 }
                             }
                         }
@@ -375,14 +467,18 @@ impl Validator for TypedDictValidator {
 
             dict.iterate(ValidateExtras {
                 used_keys,
+                fields: &self.fields,
                 py,
                 errors: &mut errors,
                 extras_validator: self.extras_validator.as_deref(),
+                allowed_extra_keys: &self.allowed_extra_keys,
                 output_dict: &output_dict,
                 state,
                 extra_behavior,
                 partial_last_key,
                 allow_partial,
+                validate_by_alias,
+                validate_by_name,
             })??;
         }
 
@@ -392,8 +488,140 @@ impl Validator for TypedDictValidator {
             Err(ValError::LineErrors(errors))
         }
     }
+}
+
+impl Validator for TypedDictValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let strict = state.strict_or(self.strict);
+        if self.coerce_keys && !strict {
+            if let Some(coerced) = coerce_int_keys(input)? {
+                // errors still report the original input rather than the coerced copy
+                return self.validate_fields(py, input, coerced.as_any().validate_dict(false)?, state);
+            }
+        }
+        let dict = input.validate_dict(strict)?;
+        self.validate_fields(py, input, dict, state)
+    }
 
     fn get_name(&self) -> &str {
         self.cls_name.as_deref().unwrap_or(Self::EXPECTED_TYPE)
     }
+
+    fn get_title(&self) -> Option<String> {
+        self.title.clone()
+    }
+}
+
+/// The key of the field closest to an unexpected `key`, if one is close enough to likely be a typo, compared with
+/// the keys each field is looked up by, so its alias when validating by alias, fields already present in the input
+/// are skipped
+fn nearest_field_key<'a>(
+    fields: &'a [TypedDictField],
+    validate_by_alias: bool,
+    validate_by_name: bool,
+    used_keys: &AHashSet<&str>,
+    key: &str,
+) -> PyResult<Option<&'a str>> {
+    let max_distance = (key.chars().count() / 3).max(1);
+    let field_paths = fields
+        .iter()
+        .map(|field| {
+            let lookup_key = field
+                .lookup_key_collection
+                .select(validate_by_alias, validate_by_name)?;
+            Ok(lookup_key.paths())
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(field_paths
+        .into_iter()
+        .filter(|paths| !paths.iter().any(|path| used_keys.contains(path.first_key())))
+        .flatten()
+        .map(|path| (levenshtein_distance(key, path.first_key()), path.first_key()))
+        .filter(|(distance, _)| (1..=max_distance).contains(distance))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, field_key)| field_key))
+}
+
+/// The string keys of an input grouped by their lower case form, used with `case_insensitive_keys`
+struct FoldedKeys(AHashMap<String, Vec<String>>);
+
+impl FoldedKeys {
+    /// `lookup_key` with its keys replaced by the input's keys which match them ignoring case, or the input's keys
+    /// matching one of its keys if they differ in case from each other
+    fn resolve(&self, py: Python, lookup_key: &LookupKey) -> Result<LookupKey, &[String]> {
+        for path in lookup_key.paths() {
+            match self.0.get(&path.first_key().to_lowercase()) {
+                Some(keys) if keys.len() > 1 => return Err(keys),
+                _ => {}
+            }
+        }
+        Ok(lookup_key.with_first_keys(py, |key| self.0.get(&key.to_lowercase()).map(|keys| keys[0].as_str())))
+    }
+}
+
+struct FoldKeys;
+
+impl<'py, Key, Value> ConsumeIterator<ValResult<(Key, Value)>> for FoldKeys
+where
+    Key: BorrowInput<'py>,
+{
+    type Output = ValResult<FoldedKeys>;
+    fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<FoldedKeys> {
+        let mut folded_keys: AHashMap<String, Vec<String>> = AHashMap::new();
+        for item_result in iterator {
+            let (raw_key, _) = item_result?;
+            // keys which aren't strings can't match a field, they're reported with the extra keys
+            let either_str = match raw_key.borrow_input().validate_str(true, false) {
+                Ok(either_str) => either_str.into_inner(),
+                Err(ValError::LineErrors(_)) => continue,
+                Err(err) => return Err(err),
+            };
+            let key = either_str.as_cow()?.into_owned();
+            let keys = folded_keys.entry(key.to_lowercase()).or_default();
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        Ok(FoldedKeys(folded_keys))
+    }
+}
+
+/// With `coerce_keys`, a copy of a python dict input with its integer keys converted to strings,
+/// None if the input isn't a dict or has no integer keys, an error for each key which becomes the same as another
+fn coerce_int_keys<'py>(input: &(impl Input<'py> + ?Sized)) -> ValResult<Option<Bound<'py, PyDict>>> {
+    let Some(dict) = input
+        .as_python()
+        .and_then(|py_input| py_input.downcast::<PyDict>().ok())
+    else {
+        return Ok(None);
+    };
+    let is_int_key = |key: &Bound<'_, PyAny>| key.is_instance_of::<PyInt>() && !key.is_instance_of::<PyBool>();
+    if !dict.keys().iter().any(|key| is_int_key(&key)) {
+        return Ok(None);
+    }
+    let coerced = PyDict::new(dict.py());
+    let mut errors: Vec<ValLineError> = Vec::new();
+    for (key, value) in dict {
+        let key = if is_int_key(&key) { key.str()?.into_any() } else { key };
+        // e.g. `1` and `'1'`, rather than one value silently replacing the other
+        if coerced.contains(&key)? {
+            errors.push(ValLineError::new_with_loc(
+                ErrorTypeDefaults::KeyCollision,
+                input,
+                key.str()?.to_string(),
+            ));
+        } else {
+            coerced.set_item(key, value)?;
+        }
+    }
+    if errors.is_empty() {
+        Ok(Some(coerced))
+    } else {
+        Err(ValError::LineErrors(errors))
+    }
 }
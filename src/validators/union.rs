@@ -10,7 +10,7 @@ use smallvec::SmallVec;
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config;
-use crate::common::union::{Discriminator, SMALL_UNION_THRESHOLD};
+use crate::common::union::{Discriminator, UnionMode, SMALL_UNION_THRESHOLD};
 use crate::errors::{ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
 use crate::input::{BorrowInput, Input, ValidatedDict};
 use crate::tools::SchemaDict;
@@ -18,27 +18,10 @@ use crate::tools::SchemaDict;
 use super::custom_error::CustomError;
 use super::literal::LiteralLookup;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, MemberRecordings,
+    RecordingsCheckpoint, ValidationState, Validator,
 };
 
-#[derive(Debug)]
-enum UnionMode {
-    Smart,
-    LeftToRight,
-}
-
-impl FromStr for UnionMode {
-    type Err = PyErr;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "smart" => Ok(Self::Smart),
-            "left_to_right" => Ok(Self::LeftToRight),
-            s => py_schema_err!("Invalid union mode: `{}`, expected `smart` or `left_to_right`", s),
-        }
-    }
-}
-
 #[derive(Debug)]
 pub struct UnionValidator {
     mode: UnionMode,
@@ -105,6 +88,7 @@ impl UnionValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
+        checkpoint: &RecordingsCheckpoint,
     ) -> ValResult<Py<PyAny>> {
         let old_exactness = state.exactness;
         let old_fields_set_count = state.fields_set_count;
@@ -112,11 +96,14 @@ impl UnionValidator {
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
 
         let mut best_match: Option<(Py<PyAny>, Exactness, Option<usize>)> = None;
+        // what was recorded while validating the best match so far, along with its index
+        let mut best_match_recordings: (usize, MemberRecordings) = (0, MemberRecordings::default());
 
-        for (choice, label) in &self.choices {
+        for (index, (choice, label)) in self.choices.iter().enumerate() {
             state.exactness = Some(Exactness::Exact);
             state.fields_set_count = None;
             let result = choice.validate(py, input, state);
+            let recordings = state.take_member_recordings(checkpoint);
             match result {
                 Ok(new_success) => match (state.exactness, state.fields_set_count) {
                     (Some(Exactness::Exact), None) => {
@@ -125,6 +112,8 @@ impl UnionValidator {
                             // exact match, return, restore any previous exactness
                             state.exactness = old_exactness;
                             state.fields_set_count = old_fields_set_count;
+                            state.restore_member_recordings(recordings);
+                            state.record_union_match(index);
                             Ok(new_success)
                         };
                     }
@@ -153,6 +142,7 @@ impl UnionValidator {
 
                         if new_success_is_best_match {
                             best_match = Some((new_success, new_exactness, new_fields_set_count));
+                            best_match_recordings = (index, recordings);
                         }
                     }
                 },
@@ -175,6 +165,9 @@ impl UnionValidator {
             if let Some(count) = fields_set_count {
                 state.add_fields_set(count);
             }
+            let (index, recordings) = best_match_recordings;
+            state.restore_member_recordings(recordings);
+            state.record_union_match(index);
             return Ok(best_match);
         }
 
@@ -187,12 +180,20 @@ impl UnionValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
+        checkpoint: &RecordingsCheckpoint,
     ) -> ValResult<Py<PyAny>> {
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
 
-        for (validator, label) in &self.choices {
-            match validator.validate(py, input, state) {
+        for (index, (validator, label)) in self.choices.iter().enumerate() {
+            let result = validator.validate(py, input, state);
+            let recordings = state.take_member_recordings(checkpoint);
+            match result {
                 Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
+                Ok(output) => {
+                    state.restore_member_recordings(recordings);
+                    state.record_union_match(index);
+                    return Ok(output);
+                }
                 otherwise => return otherwise,
             }
         }
@@ -215,10 +216,13 @@ impl Validator for UnionValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        match self.mode {
-            UnionMode::Smart => self.validate_smart(py, input, state),
-            UnionMode::LeftToRight => self.validate_left_to_right(py, input, state),
-        }
+        let checkpoint = state.recordings_checkpoint();
+        let result = match self.mode {
+            UnionMode::Smart => self.validate_smart(py, input, state, &checkpoint),
+            UnionMode::LeftToRight => self.validate_left_to_right(py, input, state, &checkpoint),
+        };
+        state.end_member_recordings(py, checkpoint)?;
+        result
     }
 
     fn get_name(&self) -> &str {
@@ -363,7 +367,7 @@ impl Validator for TaggedUnionValidator {
                 self.find_call_validator(py, &tag.borrow_input().to_object(py)?, input, state)
             }
             Discriminator::Function(func) => {
-                let tag: Py<PyAny> = func.call1(py, (input.to_object(py)?,))?;
+                let tag: Py<PyAny> = func.call1(py, (state.input_to_object(py, input)?,))?;
                 if tag.is_none(py) {
                     Err(self.tag_not_found(input))
                 } else {
@@ -388,7 +392,10 @@ impl TaggedUnionValidator {
     ) -> ValResult<Py<PyAny>> {
         if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
             return match validator.validate(py, input, state) {
-                Ok(res) => Ok(res),
+                Ok(res) => {
+                    state.record_union_match(tag);
+                    Ok(res)
+                }
                 Err(err) => Err(err.with_outer_location(tag)),
             };
         }
@@ -107,13 +107,11 @@ impl Validator for UuidValidator {
         if let Some(py_input) = input_as_python_instance(input, class) {
             if let Some(expected_version) = self.version {
                 let py_input_version: Option<usize> = py_input.getattr(intern!(py, "version"))?.extract()?;
-                if !match py_input_version {
-                    Some(py_input_version) => py_input_version == expected_version,
-                    None => false,
-                } {
+                if py_input_version != Some(expected_version) {
                     return Err(ValError::new(
                         ErrorType::UuidVersion {
                             expected_version,
+                            observed_version: py_input_version,
                             context: None,
                         },
                         input,
@@ -144,10 +142,16 @@ impl Validator for UuidValidator {
             // if the UUID variant conforms to RFC 9562 (superseding RFC 4122).
             // When dealing with Python inputs, UUIDs must adhere to RFC 9562 standards.
             if let Some(expected_version) = self.version {
-                if uuid.get_version_num() != expected_version || uuid.get_variant() != Variant::RFC4122 {
+                // like `UUID.version` in python, the version is only defined for RFC 9562 UUIDs
+                let observed_version = match uuid.get_variant() {
+                    Variant::RFC4122 => Some(uuid.get_version_num()),
+                    _ => None,
+                };
+                if observed_version != Some(expected_version) {
                     return Err(ValError::new(
                         ErrorType::UuidVersion {
                             expected_version,
+                            observed_version,
                             context: None,
                         },
                         input,
@@ -204,19 +208,6 @@ impl UuidValidator {
                 }
             }
         };
-
-        if let Some(expected_version) = self.version {
-            let v1 = uuid.get_version_num();
-            if v1 != expected_version {
-                return Err(ValError::new(
-                    ErrorType::UuidVersion {
-                        expected_version,
-                        context: None,
-                    },
-                    input,
-                ));
-            }
-        }
         Ok(uuid)
     }
 
@@ -1,16 +1,26 @@
+use std::ffi::CString;
 use std::ops::{Deref, DerefMut};
+use std::time::Instant;
 
+use pyo3::exceptions::{PyKeyError, PyUserWarning};
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyDict, PyString, PyType};
+use pyo3::PyTypeInfo;
 
+use ahash::AHashMap;
 use jiter::{PartialMode, StringCacheMode};
 
 use crate::build_tools::ExtraBehavior;
+use crate::errors::{LocItem, ValLineError};
+use crate::input::Input;
 use crate::recursion_guard::{ContainsRecursionState, RecursionState};
 use crate::tools::new_py_string;
 
 use super::Extra;
 
+// the number of spare line error vectors kept, enough for sequences nested a few levels deep
+const LINE_ERRORS_POOL_SIZE: usize = 8;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum Exactness {
     Lax,
@@ -18,6 +28,50 @@ pub enum Exactness {
     Exact,
 }
 
+/// What's recorded during validation when `validate_python` asks for it, each entry is keyed by the location
+/// of the value it was recorded for
+#[derive(Default)]
+pub struct Recordings {
+    /// Nanoseconds spent validating each model and typed dict field, with `profile=True`
+    pub field_timings: Option<Vec<(Vec<LocItem>, u64)>>,
+    /// The member chosen by each union, its index or the tag of a tagged union, with `union_matches=True`
+    pub union_matches: Option<Vec<(Vec<LocItem>, LocItem)>>,
+    /// The type of each extra field of the models validated, keyed by the extra field's location,
+    /// with `extra_types=True`
+    pub extra_types: Option<Vec<(Vec<LocItem>, Py<PyType>)>>,
+    /// The location of each field set from its default, with `defaults_used=True`
+    pub defaults_used: Option<Vec<Vec<LocItem>>>,
+}
+
+impl Recordings {
+    fn is_active(&self) -> bool {
+        self.field_timings.is_some()
+            || self.union_matches.is_some()
+            || self.extra_types.is_some()
+            || self.defaults_used.is_some()
+    }
+}
+
+/// Where the recordings made while validating a union member start
+pub struct RecordingsCheckpoint {
+    union_matches: usize,
+    extra_types: usize,
+    defaults_used: usize,
+    warnings: usize,
+    // whether warnings started being deferred at this checkpoint, i.e. it's the outermost union
+    defers_warnings: bool,
+}
+
+/// The recordings and warnings made while validating a union member, which are only kept if that member
+/// is chosen, field timings aren't included as the time was spent either way
+#[derive(Default)]
+pub struct MemberRecordings {
+    union_matches: Vec<(Vec<LocItem>, LocItem)>,
+    extra_types: Vec<(Vec<LocItem>, Py<PyType>)>,
+    defaults_used: Vec<Vec<LocItem>>,
+    warnings: Vec<String>,
+}
+
 pub struct ValidationState<'a, 'py> {
     pub recursion_guard: &'a mut RecursionState,
     pub exactness: Option<Exactness>,
@@ -35,6 +89,19 @@ pub struct ValidationState<'a, 'py> {
     // TODO: this should probably be moved directly into the structured types which need it, but that
     // requires some refactoring to make them have knowledge of default (factories).
     pub has_field_error: bool,
+    // Stop at the first error in sequences and mappings regardless of their `fail_fast` setting,
+    // used when only whether validation passes matters, e.g. `isinstance_json`.
+    pub fail_fast: bool,
+    // Spare vectors for collecting line errors, the vector of a nested sequence's errors is kept here once
+    // they've been moved to the outer sequence's errors, so the next nested sequence doesn't allocate its own
+    line_errors_pool: Vec<Vec<ValLineError>>,
+    // With `intern_strings`, validated strings by their value so equal strings share one object
+    string_cache: Option<AHashMap<String, Bound<'py, PyString>>>,
+    pub recordings: Recordings,
+    // The location of the value being validated, only tracked while something is being recorded
+    loc_path: Vec<LocItem>,
+    // Warnings held back while validating union members, see `warn`
+    deferred_warnings: Option<Vec<String>>,
     // deliberately make Extra readonly
     extra: Extra<'a, 'py>,
 }
@@ -47,6 +114,12 @@ impl<'a, 'py> ValidationState<'a, 'py> {
             fields_set_count: None,
             allow_partial,
             has_field_error: false,
+            fail_fast: false,
+            line_errors_pool: Vec::new(),
+            string_cache: None,
+            recordings: Recordings::default(),
+            loc_path: Vec::new(),
+            deferred_warnings: None,
             extra,
         }
     }
@@ -91,6 +164,18 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         EnumerateLastPartial::new(iter, self.allow_partial)
     }
 
+    /// Look up `key` in the validation context, `None` if there's no context or the key is missing
+    pub fn context_item(&self, key: &Bound<'py, PyString>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let Some(context) = self.extra.context else {
+            return Ok(None);
+        };
+        match context.get_item(key) {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if err.is_instance_of::<PyKeyError>(key.py()) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     pub fn strict_or(&self, default: bool) -> bool {
         self.extra.strict.unwrap_or(default)
     }
@@ -135,6 +220,192 @@ impl<'a, 'py> ValidationState<'a, 'py> {
     pub fn maybe_cached_str(&self, py: Python<'py>, s: &str) -> Bound<'py, PyString> {
         new_py_string(py, s, self.extra.cache_str)
     }
+
+    /// Convert `input` to a python object, JSON floats become exact `Decimal`s with `json_number_mode='decimal'`
+    pub fn input_to_object(&self, py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> PyResult<Bound<'py, PyAny>> {
+        match (self.extra.json_decimals, input.as_json()) {
+            (Some(decimals), Some(json_value)) => decimals.to_object(py, json_value),
+            _ => input.to_object(py),
+        }
+    }
+
+    /// An empty vector for collecting line errors, reusing a spare one if possible
+    pub fn take_line_errors(&mut self) -> Vec<ValLineError> {
+        self.line_errors_pool.pop().unwrap_or_default()
+    }
+
+    /// Keep a vector whose errors have been consumed for reuse by `take_line_errors`
+    pub fn recycle_line_errors(&mut self, mut errors: Vec<ValLineError>) {
+        if errors.capacity() > 0 && self.line_errors_pool.len() < LINE_ERRORS_POOL_SIZE {
+            errors.clear();
+            self.line_errors_pool.push(errors);
+        }
+    }
+
+    /// Validate the field `field_name` with `f`, when profiling the time taken is added to the field's timing,
+    /// which includes the time spent validating any fields nested within it
+    pub fn validate_field<T>(&mut self, field_name: &str, f: impl FnOnce(&mut Self) -> T) -> T {
+        if !self.recordings.is_active() {
+            return f(self);
+        }
+        self.loc_path.push(field_name.into());
+        let start = self.recordings.field_timings.is_some().then(Instant::now);
+        let result = f(self);
+        if let (Some(start), Some(field_timings)) = (start, self.recordings.field_timings.as_mut()) {
+            field_timings.push((self.loc_path.clone(), start.elapsed().as_nanos() as u64));
+        }
+        self.loc_path.pop();
+        result
+    }
+
+    /// Record that the union being validated chose `choice`
+    pub fn record_union_match(&mut self, choice: impl Into<LocItem>) {
+        if let Some(union_matches) = self.recordings.union_matches.as_mut() {
+            union_matches.push((self.loc_path.clone(), choice.into()));
+        }
+    }
+
+    /// Record the type of each of the extra fields of the model being validated
+    pub fn record_extra_types(&mut self, model_extra: &Bound<'_, PyAny>) -> PyResult<()> {
+        let (Some(extra_types), Ok(model_extra)) =
+            (self.recordings.extra_types.as_mut(), model_extra.downcast::<PyDict>())
+        else {
+            return Ok(());
+        };
+        for (name, value) in model_extra {
+            let mut loc_path = self.loc_path.clone();
+            loc_path.push(name.downcast::<PyString>()?.to_str()?.into());
+            extra_types.push((loc_path, value.get_type().unbind()));
+        }
+        Ok(())
+    }
+
+    /// Record that the field at `outer_loc` of the value being validated was set from its default,
+    /// or the value itself without `outer_loc`
+    pub fn record_default_used(&mut self, outer_loc: Option<&LocItem>) {
+        if let Some(defaults_used) = self.recordings.defaults_used.as_mut() {
+            let mut loc_path = self.loc_path.clone();
+            loc_path.extend(outer_loc.cloned());
+            defaults_used.push(loc_path);
+        }
+    }
+
+    /// Emit a `UserWarning` with `message`, within a union it's held back until the union has chosen a member
+    /// and only emitted if it was raised while validating the chosen member
+    pub fn warn(&mut self, py: Python<'_>, message: String) -> PyResult<()> {
+        match self.deferred_warnings.as_mut() {
+            Some(deferred_warnings) => {
+                deferred_warnings.push(message);
+                Ok(())
+            }
+            None => emit_warning(py, &message),
+        }
+    }
+
+    /// Mark the start of a union's members' validation, see `take_member_recordings`, once the union is
+    /// validated `end_member_recordings` must be called with the checkpoint
+    pub fn recordings_checkpoint(&mut self) -> RecordingsCheckpoint {
+        let defers_warnings = self.deferred_warnings.is_none();
+        RecordingsCheckpoint {
+            union_matches: self.recordings.union_matches.as_ref().map_or(0, Vec::len),
+            extra_types: self.recordings.extra_types.as_ref().map_or(0, Vec::len),
+            defaults_used: self.recordings.defaults_used.as_ref().map_or(0, Vec::len),
+            warnings: self.deferred_warnings.get_or_insert_with(Vec::new).len(),
+            defers_warnings,
+        }
+    }
+
+    /// Emit the warnings kept from the chosen member when leaving the outermost union
+    pub fn end_member_recordings(&mut self, py: Python<'_>, checkpoint: RecordingsCheckpoint) -> PyResult<()> {
+        if checkpoint.defers_warnings {
+            for message in self.deferred_warnings.take().unwrap_or_default() {
+                emit_warning(py, &message)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the recordings made since `checkpoint`, to be restored with `restore_member_recordings`
+    /// if the member is chosen
+    pub fn take_member_recordings(&mut self, checkpoint: &RecordingsCheckpoint) -> MemberRecordings {
+        MemberRecordings {
+            union_matches: self
+                .recordings
+                .union_matches
+                .as_mut()
+                .map(|matches| matches.split_off(checkpoint.union_matches))
+                .unwrap_or_default(),
+            extra_types: self
+                .recordings
+                .extra_types
+                .as_mut()
+                .map(|extra_types| extra_types.split_off(checkpoint.extra_types))
+                .unwrap_or_default(),
+            defaults_used: self
+                .recordings
+                .defaults_used
+                .as_mut()
+                .map(|defaults_used| defaults_used.split_off(checkpoint.defaults_used))
+                .unwrap_or_default(),
+            warnings: self
+                .deferred_warnings
+                .as_mut()
+                .map(|warnings| warnings.split_off(checkpoint.warnings))
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn restore_member_recordings(&mut self, member_recordings: MemberRecordings) {
+        if let Some(union_matches) = self.recordings.union_matches.as_mut() {
+            union_matches.extend(member_recordings.union_matches);
+        }
+        if let Some(extra_types) = self.recordings.extra_types.as_mut() {
+            extra_types.extend(member_recordings.extra_types);
+        }
+        if let Some(defaults_used) = self.recordings.defaults_used.as_mut() {
+            defaults_used.extend(member_recordings.defaults_used);
+        }
+        if let Some(warnings) = self.deferred_warnings.as_mut() {
+            warnings.extend(member_recordings.warnings);
+        }
+    }
+
+    /// Validate the item at `loc` of a sequence or mapping with `f`
+    pub fn validate_item<T>(&mut self, loc: impl Into<LocItem>, f: impl FnOnce(&mut Self) -> T) -> T {
+        if !self.recordings.is_active() {
+            return f(self);
+        }
+        self.loc_path.push(loc.into());
+        let result = f(self);
+        self.loc_path.pop();
+        result
+    }
+
+    /// Deduplicate validated strings for the rest of this validation, see `intern_str`
+    pub fn enable_string_interning(&mut self) {
+        self.string_cache = Some(AHashMap::new());
+    }
+
+    /// With string interning enabled, return the first string validated with the same value as `py_string`,
+    /// so repeated values share one object
+    pub fn intern_str(&mut self, py_string: Bound<'py, PyString>) -> Bound<'py, PyString> {
+        let Some(cache) = self.string_cache.as_mut() else {
+            return py_string;
+        };
+        // strings which can't be encoded as UTF-8, e.g. with lone surrogates, are left alone
+        let Ok(s) = py_string.to_str() else {
+            return py_string;
+        };
+        if let Some(interned) = cache.get(s) {
+            return interned.clone();
+        }
+        cache.insert(s.to_owned(), py_string.clone());
+        py_string
+    }
+}
+
+fn emit_warning(py: Python<'_>, message: &str) -> PyResult<()> {
+    PyErr::warn(py, &PyUserWarning::type_object(py), &CString::new(message)?, 0)
 }
 
 impl ContainsRecursionState for ValidationState<'_, '_> {
@@ -23,6 +23,12 @@ fn get_deepcopy(py: Python) -> PyResult<Py<PyAny>> {
     Ok(py.import("copy")?.getattr("deepcopy")?.unbind())
 }
 
+/// Deep copy a stored default, used for defaults which aren't hashable and might therefore be mutated
+pub(super) fn deepcopy_default(py: Python, default: &Py<PyAny>) -> PyResult<Py<PyAny>> {
+    let deepcopy_func = COPY_DEEPCOPY.get_or_init(py, || get_deepcopy(py).unwrap());
+    deepcopy_func.call1(py, (default,))
+}
+
 #[derive(Debug, Clone)]
 pub enum DefaultType {
     None,
@@ -135,8 +141,14 @@ impl BuildValidator for WithDefaultValidator {
         };
 
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        // the inner validator might reference definitions which aren't filled yet, so checking the default
+        // has to wait until the whole validator is built
+        let validate_on_build = matches!(default, DefaultType::Default(_))
+            && config
+                .get_as(intern!(py, "validate_defaults_on_build"))?
+                .unwrap_or(false);
 
-        Ok(CombinedValidator::WithDefault(Self {
+        let validator: Arc<CombinedValidator> = CombinedValidator::WithDefault(Self {
             default,
             on_error,
             validator,
@@ -145,7 +157,11 @@ impl BuildValidator for WithDefaultValidator {
             name,
             undefined: PydanticUndefinedType::get(py).clone_ref(schema.py()).into_any(),
         })
-        .into())
+        .into();
+        if validate_on_build {
+            definitions.defer(validator.clone());
+        }
+        Ok(validator)
     }
 }
 
@@ -181,6 +197,7 @@ impl Validator for WithDefaultValidator {
         outer_loc: Option<impl Into<LocItem>>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Option<Py<PyAny>>> {
+        let outer_loc: Option<LocItem> = outer_loc.map(Into::into);
         if matches!(self.default, DefaultType::DefaultFactory(_, true)) && state.has_field_error {
             // The default factory might use data from fields that failed to validate, and this results
             // in an unhelpul error.
@@ -195,9 +212,9 @@ impl Validator for WithDefaultValidator {
         }
         match self.default.default_value(py, state.extra().data.as_ref())? {
             Some(stored_dft) => {
+                state.record_default_used(outer_loc.as_ref());
                 let dft: Py<PyAny> = if self.copy_default {
-                    let deepcopy_func = COPY_DEEPCOPY.get_or_init(py, || get_deepcopy(py).unwrap());
-                    deepcopy_func.call1(py, (&stored_dft,))?
+                    deepcopy_default(py, &stored_dft)?
                 } else {
                     stored_dft
                 };
@@ -233,4 +250,16 @@ impl WithDefaultValidator {
     pub fn omit_on_error(&self) -> bool {
         matches!(self.on_error, OnError::Omit)
     }
+
+    /// Validate the `default` against the inner validator, used by `validate_defaults_on_build`
+    pub fn validate_default_on_build<'py>(
+        &self,
+        py: Python<'py>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<()> {
+        if let DefaultType::Default(default) = &self.default {
+            self.validator.validate(py, default.bind(py), state)?;
+        }
+        Ok(())
+    }
 }
@@ -64,6 +64,42 @@ def test_datetime_json(value, expected):
     assert v.to_json(value).decode() == f'"{expected}"'
 
 
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (datetime(2024, 1, 1, 1, 1, 1), 1704070861),
+        (datetime(2024, 1, 1, 1, 1, 1, tzinfo=timezone.utc), 1704070861),
+        (datetime(2024, 1, 1, 3, 1, 1, tzinfo=tz(hours=2)), 1704070861),
+        (datetime(2023, 12, 31, 22, 31, 1, tzinfo=tz(hours=-2, minutes=-30)), 1704070861),
+        (datetime(2024, 1, 1, 1, 1, 1, 500_000), 1704070861.5),
+        (datetime(1969, 12, 31, 23, 59, 59), -1),
+    ],
+)
+def test_datetime_epoch(value, expected):
+    s = SchemaSerializer(core_schema.datetime_schema(datetime_mode='epoch'))
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == expected
+    assert type(s.to_python(value, mode='json')) is type(expected)
+    assert s.to_json(value) == str(expected).encode()
+
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.datetime_schema(datetime_mode='epoch'), core_schema.str_schema())
+    )
+    assert s.to_json({value: 'foo'}) == f'{{"{expected}":"foo"}}'.encode()
+
+
+def test_datetime_epoch_overrides_config():
+    s = SchemaSerializer(
+        core_schema.datetime_schema(datetime_mode='epoch'), config={'ser_json_temporal': 'milliseconds'}
+    )
+    assert s.to_json(datetime(2024, 1, 1, 1, 1, 1)) == b'1704070861'
+
+    s = SchemaSerializer(
+        core_schema.datetime_schema(datetime_mode='temporal'), config={'ser_json_temporal': 'milliseconds'}
+    )
+    assert s.to_json(datetime(2024, 1, 1, 1, 1, 1)) == b'1704070861000.0'
+
+
 def test_date():
     v = SchemaSerializer(core_schema.date_schema())
     assert v.to_python(date(2022, 12, 2)) == date(2022, 12, 2)
@@ -3,7 +3,7 @@ import json
 import pytest
 from dirty_equals import IsStrictDict
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
 
 
 def test_dict_str_int():
@@ -143,3 +143,18 @@ def test_filter_runtime_int():
         core_schema.dict_schema(core_schema.any_schema(), serialization=core_schema.filter_dict_schema(exclude={0, 1}))
     )
     assert s.to_python({0: 0, 1: 1, 2: 2, 3: 3}, include={1, 2}) == {1: 1, 2: 2}
+
+
+def test_key_mode():
+    s = SchemaSerializer(core_schema.dict_schema(key_mode='str'))
+    assert s.to_python({1: 'a'}, mode='json') == {'1': 'a'}
+    assert s.to_json({1: 'a'}) == b'{"1":"a"}'
+
+    s = SchemaSerializer(core_schema.dict_schema(key_mode='error'))
+    assert s.to_json({'a': 1}) == b'{"a":1}'
+    # python mode keeps the keys as they are
+    assert s.to_python({1: 'a'}) == {1: 'a'}
+    with pytest.raises(TypeError, match=r"Dict key 1 is not a string, and `key_mode` is 'error'"):
+        s.to_python({1: 'a'}, mode='json')
+    with pytest.raises(PydanticSerializationError, match=r"Dict key 1 is not a string, and `key_mode` is 'error'"):
+        s.to_json({1: 'a'})
@@ -133,3 +133,37 @@ def test_int_dict_key():
         match=r"Expected `enum` - serialized value may not be as expected \[input_value='x', input_type=str\]",
     ):
         assert v.to_json({'x': 'x'}) == b'{"x":"x"}'
+
+
+def test_value_map():
+    class MyEnum(int, Enum):
+        a = 1
+        b = 2
+        c = 3
+
+    v = SchemaSerializer(
+        core_schema.enum_schema(
+            MyEnum,
+            list(MyEnum.__members__.values()),
+            sub_type='int',
+            value_map={MyEnum.a: 'alpha', MyEnum.b: {'code': 'B'}},
+        )
+    )
+
+    assert v.to_python(MyEnum.a) is MyEnum.a
+    assert v.to_python(MyEnum.a, mode='json') == 'alpha'
+    assert v.to_python(MyEnum.b, mode='json') == {'code': 'B'}
+    # unmapped members fall back to `.value`
+    assert v.to_python(MyEnum.c, mode='json') == 3
+    assert v.to_json(MyEnum.a) == b'"alpha"'
+    assert v.to_json(MyEnum.b) == b'{"code":"B"}'
+    assert v.to_json(MyEnum.c) == b'3'
+
+    v = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), value_map={MyEnum.a: 'alpha'}),
+            core_schema.str_schema(),
+        )
+    )
+    assert v.to_python({MyEnum.a: 'x', MyEnum.c: 'z'}, mode='json') == {'alpha': 'x', '3': 'z'}
+    assert v.to_json({MyEnum.a: 'x', MyEnum.c: 'z'}) == b'{"alpha":"x","3":"z"}'
@@ -1,4 +1,6 @@
 import json
+import warnings
+from collections import deque
 from functools import partial
 
 import pytest
@@ -19,6 +21,53 @@ def test_list_any():
     assert v.to_json(['a', 'b', 'c'], indent=2) == b'[\n  "a",\n  "b",\n  "c"\n]'
 
 
+def test_list_prefix_items():
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.int_schema(),
+            prefix_items=[
+                core_schema.str_schema(),
+                core_schema.str_schema(
+                    serialization=core_schema.plain_serializer_function_ser_schema(lambda v: v.upper())
+                ),
+            ],
+        )
+    )
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert s.to_python(['a', 'b', 1, 2]) == ['a', 'B', 1, 2]
+        assert s.to_python(['a', 'b', 1], mode='json') == ['a', 'B', 1]
+        assert s.to_json(['a', 'b', 1, 2]) == b'["a","B",1,2]'
+        assert s.to_json(['a']) == b'["a"]'
+
+    with pytest.warns(UserWarning, match='Expected `int`'):
+        assert s.to_json(['a', 'b', 'c']) == b'["a","B","c"]'
+
+
+def test_list_deque():
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.int_schema(serialization=core_schema.plain_serializer_function_ser_schema(lambda v: v * 2)),
+            collection='deque',
+        )
+    )
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        output = s.to_python(deque([1, 2, 3], maxlen=3))
+        assert type(output) is deque
+        assert output == deque([2, 4, 6])
+        assert output.maxlen == 3
+        assert s.to_python(deque([1, 2]), mode='json') == [2, 4]
+        assert s.to_json(deque([1, 2])) == b'[2,4]'
+        assert s.to_python([1, 2]) == [2, 4]
+        assert s.to_python(deque([1, 2, 3]), exclude={1}) == deque([2, 6])
+
+    # only a list schema with `collection='deque'` expects a deque
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.warns(UserWarning, match='Expected `list\\[int\\]`'):
+        assert s.to_python(deque([1, 2])) == deque([1, 2])
+
+
 def test_list_fallback():
     v = SchemaSerializer(core_schema.list_schema(core_schema.any_schema()))
     with pytest.warns(
@@ -41,16 +90,16 @@ def test_list_fallback():
 
     with pytest.warns(
         UserWarning,
-        match=r'Expected `list\[any\]` - serialized value may not be as expected \[input_value=\(1, 2, 3\), input_type=tuple\]',
+        match=r'Expected `list\[any\]` - serialized value may not be as expected \[input_value=\{1, 2, 3\}, input_type=set\]',
     ):
-        assert v.to_python((1, 2, 3)) == (1, 2, 3)
+        assert v.to_python({1, 2, 3}) == {1, 2, 3}
 
     # # even though we're in the fallback state, non JSON types should still be converted to JSON here
     with pytest.warns(
         UserWarning,
-        match=r'Expected `list\[any\]` - serialized value may not be as expected \[input_value=\(1, 2, 3\), input_type=tuple\]',
+        match=r'Expected `list\[any\]` - serialized value may not be as expected \[input_value=\{1, 2, 3\}, input_type=set\]',
     ):
-        assert v.to_python((1, 2, 3), mode='json') == [1, 2, 3]
+        assert v.to_python({1, 2, 3}, mode='json') == [1, 2, 3]
 
 
 def test_list_str_fallback():
@@ -62,6 +62,26 @@ def test_other_literal():
     assert s.to_json(1) == b'1'
 
 
+def test_check_serialization():
+    s = SchemaSerializer(core_schema.literal_schema(['a', 1], check_serialization=True))
+
+    assert s.to_python('a') == 'a'
+    assert s.to_python(1, mode='json') == 1
+    assert s.to_json('a') == b'"a"'
+
+    with pytest.warns(
+        UserWarning,
+        match=r"Expected `literal\['a',1\]` - serialized value may not be as expected \[input_value='b', input_type=str\]",
+    ):
+        assert s.to_python('b') == 'b'
+
+    with pytest.warns(
+        UserWarning,
+        match=r"Expected `literal\['a',1\]` - serialized value may not be as expected \[input_value=2, input_type=int\]",
+    ):
+        assert s.to_json(2) == b'2'
+
+
 def test_empty_literal():
     with pytest.raises(SchemaError, match='`expected` should have length > 0'):
         SchemaSerializer(core_schema.literal_schema([]))
@@ -143,6 +143,28 @@ def test_model_allow_extra():
         assert j == b'{"bar":"more","foo":1,"c":3}'
 
 
+def test_model_forbid_extra_allowed_keys():
+    schema = core_schema.model_schema(
+        BasicModel,
+        core_schema.model_fields_schema(
+            {'foo': core_schema.model_field(core_schema.int_schema())},
+            extra_behavior='forbid',
+            allowed_extra_keys=['_meta'],
+        ),
+        extra_behavior='forbid',
+    )
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+
+    m = v.validate_python({'foo': 1, '_meta': {'x': 1}})
+    assert s.to_python(m) == {'foo': 1, '_meta': {'x': 1}}
+    assert s.to_json(m) == b'{"foo":1,"_meta":{"x":1}}'
+    # the allowlisted key survives a round trip through JSON
+    m2 = v.validate_json(s.to_json(m))
+    assert m2.foo == 1
+    assert m2.__pydantic_extra__ == {'_meta': {'x': 1}}
+
+
 def test_model_recursive_in_extra():
     # See https://github.com/pydantic/pydantic/issues/6571
 
@@ -246,6 +268,88 @@ def test_alias():
     assert s.to_python(value, by_alias=True) == IsStrictDict(Meow=0, Woof=1, bird=2)
 
 
+def test_flatten_prefix():
+    addr_schema = core_schema.model_schema(
+        BasicSubModel,
+        core_schema.model_fields_schema(
+            {
+                'city': core_schema.model_field(core_schema.str_schema()),
+                'zip': core_schema.model_field(core_schema.str_schema()),
+            }
+        ),
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'name': core_schema.model_field(core_schema.str_schema()),
+                    'addr': core_schema.model_field(addr_schema, serialization_flatten_prefix='addr_'),
+                    'age': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+        )
+    )
+    value = BasicModel(name='x', addr=BasicSubModel(city='Paris', zip='75001'), age=3)
+    assert s.to_python(value) == IsStrictDict(name='x', addr_city='Paris', addr_zip='75001', age=3)
+    assert s.to_python(value, mode='json') == IsStrictDict(name='x', addr_city='Paris', addr_zip='75001', age=3)
+    assert s.to_json(value) == b'{"name":"x","addr_city":"Paris","addr_zip":"75001","age":3}'
+    assert s.to_python(value, exclude={'addr': {'zip'}}) == {'name': 'x', 'addr_city': 'Paris', 'age': 3}
+
+
+def test_flatten_prefix_alias_exclude_none():
+    addr_schema = core_schema.model_schema(
+        BasicSubModel,
+        core_schema.model_fields_schema(
+            {
+                'city': core_schema.model_field(core_schema.str_schema(), serialization_alias='City'),
+                'zip': core_schema.model_field(core_schema.str_schema()),
+            }
+        ),
+        config=core_schema.CoreConfig(serialize_by_alias=True),
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'addr': core_schema.model_field(addr_schema, serialization_flatten_prefix='addr_'),
+                    'extra': core_schema.model_field(
+                        core_schema.dict_schema(
+                            core_schema.str_schema(), core_schema.nullable_schema(core_schema.int_schema())
+                        ),
+                        serialization_flatten_prefix='x_',
+                    ),
+                }
+            ),
+        )
+    )
+    value = BasicModel(addr=BasicSubModel(city='Paris', zip='75001'), extra={'a': 1, 'b': None})
+    # the parent doesn't serialize by alias, so neither do the flattened keys
+    assert s.to_python(value) == {'addr_city': 'Paris', 'addr_zip': '75001', 'x_a': 1, 'x_b': None}
+    assert s.to_python(value, by_alias=True) == {'addr_City': 'Paris', 'addr_zip': '75001', 'x_a': 1, 'x_b': None}
+    assert s.to_python(value, exclude_none=True) == {'addr_city': 'Paris', 'addr_zip': '75001', 'x_a': 1}
+    assert s.to_json(value, exclude_none=True) == b'{"addr_city":"Paris","addr_zip":"75001","x_a":1}'
+
+
+@pytest.mark.parametrize('flattened_first', [True, False])
+def test_flatten_prefix_collision(flattened_first):
+    fields = {
+        'addr': core_schema.model_field(core_schema.dict_schema(), serialization_flatten_prefix='addr_'),
+        'addr_city': core_schema.model_field(core_schema.str_schema()),
+    }
+    if not flattened_first:
+        fields = dict(reversed(fields.items()))
+    s = SchemaSerializer(core_schema.model_schema(BasicModel, core_schema.model_fields_schema(fields)))
+    value = BasicModel(**{k: {'city': 'Paris'} if k == 'addr' else 'London' for k in fields})
+    msg = 'Key `addr_city` from flattening field `addr` collides with another key'
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python(value)
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json(value)
+    assert s.to_python(value, exclude={'addr_city'}) == {'addr_city': 'Paris'}
+
+
 def test_model_wrong_warn():
     s = SchemaSerializer(
         core_schema.model_schema(
@@ -316,6 +420,71 @@ def test_exclude_none():
     assert s.to_json(BasicModel(foo=None, bar=b'more'), exclude_none=True) == b'{"bar":"more"}'
 
 
+def test_diff_against():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(core_schema.int_schema()),
+                    'bar': core_schema.model_field(core_schema.bytes_schema()),
+                    'spam': core_schema.model_field(core_schema.list_schema(core_schema.int_schema())),
+                }
+            ),
+        )
+    )
+    value = BasicModel(foo=1, bar=b'more', spam=[1, 2])
+    assert s.to_python(value, diff_against={'foo': 1, 'bar': b'less', 'spam': [1, 2]}) == {'bar': b'more'}
+    assert s.to_python(value, diff_against={'foo': 1, 'bar': b'more', 'spam': [1, 2]}) == {}
+    assert s.to_python(value, diff_against={}) == {'foo': 1, 'bar': b'more', 'spam': [1, 2]}
+
+    # in JSON mode, the baseline is compared against JSON compatible values
+    assert s.to_python(value, mode='json', diff_against={'foo': 1, 'bar': 'more', 'spam': [1]}) == {'spam': [1, 2]}
+    assert s.to_json(value, diff_against={'foo': 2, 'bar': 'more', 'spam': [1, 2]}) == b'{"foo":1}'
+
+
+def test_emit_null_for_excluded():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(core_schema.int_schema()),
+                    'bar': core_schema.model_field(core_schema.bytes_schema(), serialization_alias='Bar'),
+                    'spam': core_schema.model_field(core_schema.int_schema(), serialization_exclude=True),
+                }
+            ),
+        )
+    )
+    value = BasicModel(foo=1, bar=b'more', spam=3)
+    assert s.to_python(value, exclude={'bar'}) == {'foo': 1}
+    # fields excluded by the schema are still omitted
+    assert s.to_python(value, exclude={'bar'}, emit_null_for_excluded=True) == {'foo': 1, 'bar': None}
+    assert s.to_python(value, include={'foo'}, by_alias=True, emit_null_for_excluded=True) == {'foo': 1, 'Bar': None}
+    assert s.to_json(value, exclude={'bar'}, emit_null_for_excluded=True) == b'{"foo":1,"bar":null}'
+
+
+def test_emit_null_for_excluded_defaults():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(core_schema.int_schema()),
+                    'bar': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.int_schema(), default=5)
+                    ),
+                }
+            ),
+        )
+    )
+    value = BasicModel(foo=1, bar=5)
+    # fields left out by `exclude_defaults` aren't excluded by `include` or `exclude`, so they're still omitted
+    assert s.to_python(value, exclude_defaults=True, emit_null_for_excluded=True) == {'foo': 1}
+    assert s.to_json(value, exclude_defaults=True, emit_null_for_excluded=True) == b'{"foo":1}'
+    assert s.to_python(value, exclude={'foo'}, exclude_defaults=True, emit_null_for_excluded=True) == {'foo': None}
+
+
 class FieldsSetModel:
     __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
 
@@ -9,7 +9,7 @@ def test_chain():
     s = SchemaSerializer(core_schema.chain_schema([core_schema.str_schema(), core_schema.int_schema()]))
 
     # insert_assert(plain_repr(s))
-    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer),definitions=[])'
+    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer{int_as_string_threshold:None}),definitions=[])'
 
     assert s.to_python(1) == 1
     assert s.to_json(1) == b'1'
@@ -27,7 +27,7 @@ def test_function_before():
         core_schema.with_info_before_validator_function(lambda v, info: v + 1, core_schema.int_schema())
     )
     # insert_assert(plain_repr(s))
-    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer),definitions=[])'
+    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer{int_as_string_threshold:None}),definitions=[])'
 
 
 def test_function_after():
@@ -35,7 +35,7 @@ def test_function_after():
         core_schema.with_info_after_validator_function(lambda v, info: v + 1, core_schema.int_schema())
     )
     # insert_assert(plain_repr(s))
-    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer),definitions=[])'
+    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer{int_as_string_threshold:None}),definitions=[])'
 
 
 def test_lax_or_strict():
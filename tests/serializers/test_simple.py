@@ -105,6 +105,27 @@ def test_int_to_float_key():
     assert s.to_json({1: 1}) == b'{"1":1.0}'
 
 
+def test_int_as_string_threshold():
+    s = SchemaSerializer(core_schema.int_schema(int_as_string_threshold=2**53))
+    assert s.to_json(2**60) == b'"1152921504606846976"'
+    assert s.to_json(-(2**60)) == b'"-1152921504606846976"'
+    assert s.to_json(2**100) == b'"1267650600228229401496703205376"'
+    assert s.to_json(2**53) == b'9007199254740992'
+    assert s.to_json(123) == b'123'
+
+    assert s.to_python(2**60, mode='json') == '1152921504606846976'
+    assert s.to_python(123, mode='json') == 123
+    assert s.to_python(2**60) == 2**60
+
+
+def test_int_as_string_threshold_above_i64():
+    s = SchemaSerializer(core_schema.int_schema(int_as_string_threshold=2**64 - 1))
+    assert s.to_json(2**63 + 5) == b'9223372036854775813'
+    assert s.to_json(-(2**64 - 1)) == b'-18446744073709551615'
+    assert s.to_json(2**64) == b'"18446744073709551616"'
+    assert s.to_python(2**63 + 5, mode='json') == 2**63 + 5
+
+
 @pytest.mark.parametrize('schema_type', ['int', 'bool', 'float', 'none'])
 def test_simple_serializers_fallback(schema_type):
     s = SchemaSerializer({'type': schema_type})
@@ -169,3 +190,13 @@ def test_float_inf_and_nan_serializers(value, expected_json, config):
 
     # Serialized JSON value respects the ser_json_inf_nan setting
     assert s.to_json(value).decode() == expected_json
+
+
+def test_float_round_to():
+    s = SchemaSerializer(core_schema.float_schema(round_to=2))
+
+    assert s.to_python(3.14159) == 3.14
+    assert s.to_python(3.14159, mode='json') == 3.14
+    assert s.to_json(3.14159) == b'3.14'
+    assert s.to_json(-1.2345) == b'-1.23'
+    assert s.to_json(float('nan')) == b'null'
@@ -38,6 +38,29 @@ def test_timedelta_float():
     assert v.to_json(timedelta(seconds=4, microseconds=500_000)) == b'4.5'
 
 
+@pytest.mark.parametrize('config', [{'ser_json_timedelta': 'float'}, {'ser_json_temporal': 'seconds'}])
+def test_timedelta_seconds_int(config):
+    v = SchemaSerializer(core_schema.timedelta_schema(timedelta_seconds_int=True), config=config)
+    assert v.to_python(timedelta(seconds=60)) == timedelta(seconds=60)
+
+    assert v.to_python(timedelta(seconds=60), mode='json') == 60
+    assert type(v.to_python(timedelta(seconds=60), mode='json')) is int
+    assert v.to_json(timedelta(seconds=60)) == b'60'
+    assert v.to_json(-timedelta(days=1, seconds=1)) == b'-86401'
+    assert v.to_python(timedelta(seconds=1.5), mode='json') == 1.5
+    assert v.to_json(timedelta(seconds=1.5)) == b'1.5'
+
+    v = SchemaSerializer(
+        core_schema.dict_schema(core_schema.timedelta_schema(timedelta_seconds_int=True), core_schema.int_schema()),
+        config=config,
+    )
+    assert v.to_json({timedelta(seconds=60): 1, timedelta(seconds=1.5): 2}) == b'{"60":1,"1.5":2}'
+
+    # only applies when serializing as seconds
+    v = SchemaSerializer(core_schema.timedelta_schema(timedelta_seconds_int=True))
+    assert v.to_json(timedelta(seconds=60)) == b'"PT1M"'
+
+
 def test_timedelta_key():
     v = SchemaSerializer(core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.int_schema()))
     assert v.to_python({timedelta(days=2, hours=3, minutes=4): 1}) == {timedelta(days=2, hours=3, minutes=4): 1}
@@ -5,7 +5,7 @@ import pytest
 from dirty_equals import IsStrictDict
 from typing_extensions import TypedDict
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
 
 
 @pytest.mark.parametrize('extra_behavior_kw', [{}, {'extra_behavior': 'ignore'}, {'extra_behavior': None}])
@@ -50,6 +50,22 @@ def test_typed_dict_allow_extra():
     assert v.to_json({'bar': b'more', 'c': 3, 'foo': 1, 'cc': 4}) == b'{"bar":"more","c":3,"foo":1,"cc":4}'
 
 
+def test_typed_dict_forbid_extra_allowed_keys():
+    schema = core_schema.typed_dict_schema(
+        {'foo': core_schema.typed_dict_field(core_schema.int_schema())},
+        extra_behavior='forbid',
+        allowed_extra_keys=['_meta'],
+    )
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+
+    output = v.validate_python({'foo': 1, '_meta': [1, 2]})
+    assert s.to_python(output) == {'foo': 1, '_meta': [1, 2]}
+    assert s.to_json(output) == b'{"foo":1,"_meta":[1,2]}'
+    # the allowlisted key survives a round trip through JSON
+    assert v.validate_json(s.to_json(output)) == output
+
+
 @pytest.mark.parametrize(
     'params',
     [
@@ -5,6 +5,7 @@ import json
 import uuid
 import warnings
 from decimal import Decimal
+from enum import Enum
 from typing import Any, ClassVar, Literal, Union
 
 import pytest
@@ -116,6 +117,40 @@ def test_model_b(model_serializer: SchemaSerializer, input_value):
     assert model_serializer.to_json(input_value) == b'{"c":"bite","d":"2.35"}'
 
 
+@pytest.mark.parametrize(
+    'union_serialization,expected',
+    [
+        (None, {'a': b'bite', 'b': '2.35'}),
+        ('smart', {'a': b'bite', 'b': '2.35'}),
+        ('left_to_right', {'a': b'bite', 'b': '2.3'}),
+    ],
+)
+def test_union_serialization_mode(union_serialization, expected):
+    def model_schema(cls, float_format: str) -> core_schema.ModelSchema:
+        return core_schema.model_schema(
+            cls,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.bytes_schema()),
+                    'b': core_schema.model_field(
+                        core_schema.float_schema(
+                            serialization=core_schema.format_ser_schema(float_format, when_used='always')
+                        )
+                    ),
+                }
+            ),
+        )
+
+    s = SchemaSerializer(
+        core_schema.union_schema(
+            [model_schema(ModelA, '0.1f'), model_schema(SubclassA, '0.2f')], union_serialization=union_serialization
+        )
+    )
+    # `SubclassA` is an instance of both choices, but an exact match for the second
+    assert s.to_python(SubclassA(b'bite', 2.3456)) == expected
+    assert s.to_python(ModelA(b'bite', 2.3456)) == {'a': b'bite', 'b': '2.3'}
+
+
 def test_keys():
     s = SchemaSerializer(
         core_schema.dict_schema(
@@ -669,6 +704,36 @@ def test_tagged_union() -> None:
     assert s.to_python(model_b) == {'field': 1, 'tag': 'b'}
 
 
+def test_tagged_union_enum_discriminator() -> None:
+    class PetType(str, Enum):
+        cat = 'cat'
+        dog = 'dog'
+
+    def pet_schema(kind: str) -> core_schema.TypedDictSchema:
+        # cats and dogs have the same shape, so only the tag can select the right serializer
+        return core_schema.typed_dict_schema(
+            {
+                'pet_type': core_schema.typed_dict_field(core_schema.str_schema()),
+                'name': core_schema.typed_dict_field(
+                    core_schema.str_schema(
+                        serialization=core_schema.plain_serializer_function_ser_schema(lambda v: f'{kind} {v}')
+                    )
+                ),
+            }
+        )
+
+    s = SchemaSerializer(
+        core_schema.tagged_union_schema(
+            choices={'cat': pet_schema('cat'), 'dog': pet_schema('dog')}, discriminator='pet_type'
+        )
+    )
+
+    dog = {'pet_type': PetType.dog, 'name': 'Rex'}
+    assert s.to_python(dog) == {'pet_type': PetType.dog, 'name': 'dog Rex'}
+    assert s.to_python(dog, mode='json') == {'pet_type': 'dog', 'name': 'dog Rex'}
+    assert s.to_python({'pet_type': 'cat', 'name': 'Tom'}) == {'pet_type': 'cat', 'name': 'cat Tom'}
+
+
 def test_union_float_int() -> None:
     s = SchemaSerializer(core_schema.union_schema([core_schema.float_schema(), core_schema.int_schema()]))
 
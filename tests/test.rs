@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use _pydantic_core::{SchemaSerializer, SchemaValidator, WarningsArg};
+    use jiter::FloatMode;
     use pyo3::ffi::c_str; // can switch to c"" literals on MSRV >= 1.77
     use pyo3::prelude::*;
     use pyo3::types::PyDict;
@@ -78,7 +79,23 @@ json_input = '{"a": "something"}'
             let json_input = locals.get_item("json_input").unwrap().unwrap();
             let binding = SchemaValidator::py_new(py, &schema, None)
                 .unwrap()
-                .validate_json(py, &json_input, None, None, None, None, false.into(), None, None)
+                .validate_json(
+                    py,
+                    &json_input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    FloatMode::Float,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
                 .unwrap();
             let validation_result: Bound<'_, PyAny> = binding.extract(py).unwrap();
             let repr = format!("{}", validation_result.repr().unwrap());
@@ -156,6 +173,8 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    None,
+                    false,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
@@ -179,10 +198,72 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    None,
+                    false,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
             assert_eq!(repr, "b'{\"a\":\"something\"}'");
         });
     }
+
+    #[test]
+    fn test_list_errors_reused() {
+        // line error vectors are reused within a validation, nested lists must still report their own errors
+        Python::attach(|py| {
+            let schema = py
+                .eval(
+                    c_str!("{'type': 'list', 'items_schema': {'type': 'list', 'items_schema': {'type': 'int'}}}"),
+                    None,
+                    None,
+                )
+                .unwrap();
+            let validator = SchemaValidator::py_new(py, &schema, None).unwrap();
+            let invalid = py.eval(c_str!("[['a', 1], [1, 'b'], [2]]"), None, None).unwrap();
+            let valid = py.eval(c_str!("[[1], [2, 3]]"), None, None).unwrap();
+
+            let validate = |input: &Bound<'_, PyAny>| {
+                validator.validate_python(
+                    py,
+                    input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    true,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
+            };
+            let errors = |input: &Bound<'_, PyAny>| {
+                let err = validate(input).unwrap_err();
+                err.value(py)
+                    .call_method0("errors")
+                    .unwrap()
+                    .repr()
+                    .unwrap()
+                    .to_string()
+            };
+
+            let first = errors(&invalid);
+            assert!(first.contains("(0, 0)") && first.contains("(1, 1)"), "{first}");
+            for _ in 0..3 {
+                assert_eq!(
+                    validate(&valid).unwrap().bind(py).repr().unwrap().to_string(),
+                    "[[1], [2, 3]]"
+                );
+                assert_eq!(errors(&invalid), first);
+            }
+        });
+    }
 }
@@ -111,6 +111,56 @@ def test_pydantic_value_error_invalid_type():
         v.validate_python(42)
 
 
+def test_pydantic_value_error_loc():
+    class MyModel:
+        pass
+
+    def check_model(m):
+        if m.a == m.b:
+            raise PydanticCustomError('same_value', '{field} should differ from a', {'field': 'b'}, ('b', 0))
+        return m
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'model': core_schema.typed_dict_field(
+                    core_schema.no_info_after_validator_function(
+                        check_model,
+                        core_schema.model_schema(
+                            MyModel,
+                            core_schema.model_fields_schema(
+                                {
+                                    'a': core_schema.model_field(core_schema.int_schema()),
+                                    'b': core_schema.model_field(core_schema.int_schema()),
+                                }
+                            ),
+                        ),
+                    )
+                )
+            }
+        )
+    )
+    assert v.validate_python({'model': {'a': 1, 'b': 2}})['model'].b == 2
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'model': {'a': 1, 'b': 1}})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'same_value',
+            'loc': ('model', 'b', 0),
+            'msg': 'b should differ from a',
+            'input': {'a': 1, 'b': 1},
+            'ctx': {'field': 'b'},
+        }
+    ]
+
+    e = PydanticCustomError('my_error', 'this is a custom error', None, ['a', 1])
+    assert e.loc == ('a', 1)
+    assert PydanticCustomError('my_error', 'this is a custom error').loc is None
+    with pytest.raises(TypeError, match='Location must be a list or tuple of strings and ints'):
+        PydanticCustomError('my_error', 'this is a custom error', None, 'a')
+
+
 def test_validator_instance_plain():
     class CustomValidator:
         def __init__(self):
@@ -236,6 +286,43 @@ def test_pydantic_custom_error_type_raise_custom_ctx(extra: dict):
     ]
 
 
+def test_pydantic_custom_error_nested_ctx_output():
+    ctx = {'limits': {'min': 1, 'max': [5, Decimal('7.5')]}, 'tags': ('a', 'b'), 'raw': b'xyz', 'cls': int}
+
+    def f(input_value):
+        raise PydanticCustomError('out_of_range', 'value outside {limits}', ctx)
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(4)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'out_of_range',
+            'loc': (),
+            'msg': "value outside {'min': 1, 'max': [5, Decimal('7.5')]}",
+            'input': 4,
+            'ctx': ctx,
+        }
+    ]
+    assert exc_info.value.json(include_url=False) == IsJson(
+        [
+            {
+                'type': 'out_of_range',
+                'loc': [],
+                'msg': "value outside {'min': 1, 'max': [5, Decimal('7.5')]}",
+                'input': 4,
+                'ctx': {
+                    'limits': {'min': 1, 'max': [5, '7.5']},
+                    'tags': ['a', 'b'],
+                    'raw': 'xyz',
+                    'cls': "<class 'int'>",
+                },
+            }
+        ]
+    )
+
+
 @pytest.mark.parametrize('ctx', [None, {}])
 def test_pydantic_custom_error_type_raise_custom_no_ctx(ctx: Optional[dict]):
     def f(input_value, info):
@@ -262,9 +349,20 @@ all_errors = [
         'Cannot check `isinstance` when validating from json, use a JsonOrPython validator instead',
         {'method_name': 'isinstance'},
     ),
+    ('json_duplicate_key', "Duplicate key 'a' in JSON object", {'key': 'a'}),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
+    (
+        'model_tuple_length',
+        'Input should have 2 items to match the model fields, not 3',
+        {'expected_length': 2, 'actual_length': 3},
+    ),
+    (
+        'exactly_one_required',
+        "Exactly one of 'a', 'b' should be provided, not 2",
+        {'fields': "'a', 'b'", 'actual_count': 2},
+    ),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
     ('dataclass_type', 'Input should be a dictionary or an instance of Foobar', {'class_name': 'Foobar'}),
     (
@@ -276,6 +374,8 @@ all_errors = [
     ('frozen_field', 'Field is frozen', None),
     ('frozen_instance', 'Instance is frozen', None),
     ('extra_forbidden', 'Extra inputs are not permitted', None),
+    ('ambiguous_key', 'Multiple keys match this field when ignoring case', None),
+    ('key_collision', 'Multiple keys are the same once coerced to a string', None),
     ('invalid_key', 'Keys should be strings', None),
     ('get_attribute_error', 'Error extracting attribute: foo', {'error': 'foo'}),
     ('none_required', 'Input should be None', None),
@@ -287,6 +387,7 @@ all_errors = [
     ('less_than', 'Input should be less than 42.1', {'lt': 42.1}),
     ('less_than_equal', 'Input should be less than or equal to 42.1', {'le': 42.1}),
     ('finite_number', 'Input should be a finite number', None),
+    ('float_subnormal', 'Input should not be a subnormal float', None),
     (
         'too_short',
         'Foobar should have at least 42 items after validation, not 40',
@@ -301,6 +402,9 @@ all_errors = [
     ('string_sub_type', 'Input should be a string, not an instance of a subclass of str', None),
     ('string_unicode', 'Input should be a valid string, unable to parse raw data as a unicode string', None),
     ('string_pattern_mismatch', "String should match pattern 'foo'", {'pattern': 'foo'}),
+    ('string_not_in_set', 'String should be one of the allowed values', None),
+    ('string_format', 'String should be a valid uri, Foobar', {'format': 'uri', 'error': 'Foobar'}),
+    ('regex_timeout', 'Matching the pattern would exceed the limit of 42 steps', {'step_limit': 42}),
     ('string_too_short', 'String should have at least 42 characters', {'min_length': 42}),
     ('string_too_short', 'String should have at least 1 character', {'min_length': 1}),
     ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
@@ -331,6 +435,7 @@ all_errors = [
     ('bytes_too_short', 'Data should have at least 1 byte', {'min_length': 1}),
     ('bytes_too_long', 'Data should have at most 42 bytes', {'max_length': 42}),
     ('bytes_too_long', 'Data should have at most 1 byte', {'max_length': 1}),
+    ('bytes_encoded_too_long', 'Encoded data should have at most 42 bytes', {'max_length': 42}),
     (
         'bytes_invalid_encoding',
         'Data should be valid base64: Invalid byte 1, offset 1',
@@ -341,6 +446,11 @@ all_errors = [
         'Data should be valid hex: Odd number of digits',
         {'encoding': 'hex', 'encoding_error': 'Odd number of digits'},
     ),
+    (
+        'bytes_invalid_utf8',
+        'Data should be valid UTF-8: invalid utf-8 sequence of 1 bytes from index 1',
+        {'utf8_error': 'invalid utf-8 sequence of 1 bytes from index 1'},
+    ),
     ('value_error', 'Value error, foobar', {'error': ValueError('foobar')}),
     ('assertion_error', 'Assertion failed, foobar', {'error': AssertionError('foobar')}),
     ('literal_error', 'Input should be foo', {'expected': 'foo'}),
@@ -390,7 +500,7 @@ all_errors = [
     ('url_scheme', 'URL scheme should be "foo", "bar" or "spam"', {'expected_schemes': '"foo", "bar" or "spam"'}),
     ('uuid_type', 'UUID input should be a string, bytes or UUID object', None),
     ('uuid_parsing', 'Input should be a valid UUID, Foobar', {'error': 'Foobar'}),
-    ('uuid_version', 'UUID version 42 expected', {'expected_version': 42}),
+    ('uuid_version', 'UUID version 42 expected', {'expected_version': 42, 'observed_version': 4}),
     ('decimal_type', 'Decimal input should be an integer, float, string or Decimal object', None),
     ('decimal_parsing', 'Input should be a valid decimal', None),
     ('decimal_max_digits', 'Decimal input should have no more than 42 digits in total', {'max_digits': 42}),
@@ -939,6 +1049,46 @@ def test_error_json_loc():
     )
 
 
+def test_error_deep_loc():
+    # locations deeper than a few items are no longer stored inline
+    schema = core_schema.int_schema()
+    for key in 'edcba':
+        schema = core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(schema))
+    s = SchemaValidator(schema)
+
+    value = 'x'
+    for key in 'edcba':
+        value = {key: [value]}
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(value)
+
+    loc = ('a', 0, 'b', 0, 'c', 0, 'd', 0, 'e', 0)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': loc,
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert exc_info.value.json(include_url=False) == IsJson(
+        [
+            {
+                'type': 'int_parsing',
+                'loc': list(loc),
+                'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                'input': 'x',
+            }
+        ]
+    )
+    assert str(exc_info.value).splitlines()[1] == 'a.0.b.0.c.0.d.0.e.0'
+
+    # locations given from python are also stored reversed and rendered in the original order
+    error = ValidationError.from_exception_data('Foobar', [{'type': 'int_parsing', 'loc': loc, 'input': 'x'}])
+    assert error.errors(include_url=False)[0]['loc'] == loc
+
+
 def test_raise_validation_error():
     with pytest.raises(ValidationError, match='1 validation error for Foobar\n') as exc_info:
         raise ValidationError.from_exception_data(
@@ -1094,6 +1244,76 @@ def test_loc_with_dots(pydantic_version):
     )
 
 
+@pytest.mark.parametrize(
+    'schema,input_value,limit',
+    [
+        (core_schema.int_schema(gt=10), 5, 10),
+        (core_schema.float_schema(gt=1.5), 1.5, 1.5),
+        (core_schema.decimal_schema(gt=Decimal('0.1')), Decimal('0.05'), Decimal('0.1')),
+    ],
+)
+def test_comparison_error_input_and_limit(schema, input_value, limit) -> None:
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+
+    # the offending value is reported alongside the bound from the context
+    [error] = exc_info.value.errors(include_url=False)
+    assert error['type'] == 'greater_than'
+    assert error['input'] == input_value
+    assert error['ctx'] == {'gt': limit, 'input': input_value}
+    assert error['msg'] == f'Input should be greater than {limit}'
+
+
+def test_errors_tree() -> None:
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {
+                            'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                            'c': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+                        }
+                    )
+                ),
+                'd': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': {'b': 'x', 'c': ['y', 1, 2]}, 'd': 'z'})
+
+    int_parsing = 'Input should be a valid integer, unable to parse string as an integer'
+    # insert_assert(exc_info.value.errors_tree(include_url=False, include_input=False))
+    assert exc_info.value.errors_tree(include_url=False, include_input=False) == {
+        'a': {
+            'b': [{'type': 'int_parsing', 'loc': ('a', 'b'), 'msg': int_parsing}],
+            'c': {
+                1: [{'type': 'string_type', 'loc': ('a', 'c', 1), 'msg': 'Input should be a valid string'}],
+                2: [{'type': 'string_type', 'loc': ('a', 'c', 2), 'msg': 'Input should be a valid string'}],
+            },
+        },
+        'd': [{'type': 'int_parsing', 'loc': ('d',), 'msg': int_parsing}],
+    }
+
+
+def test_errors_tree_root() -> None:
+    error = ValidationError.from_exception_data(
+        'Foobar',
+        [
+            {'type': 'missing', 'loc': (), 'input': None},
+            {'type': 'missing', 'loc': ('a',), 'input': None},
+            {'type': 'missing', 'loc': ('a', 'b'), 'input': None},
+        ],
+    )
+    missing = {'type': 'missing', 'msg': 'Field required'}
+    assert error.errors_tree(include_url=False, include_input=False) == {
+        '__root__': [{**missing, 'loc': ()}],
+        'a': {'__root__': [{**missing, 'loc': ('a',)}], 'b': [{**missing, 'loc': ('a', 'b')}]},
+    }
+
+
 def test_hide_input_in_error() -> None:
     s = SchemaValidator(core_schema.int_schema())
     with pytest.raises(ValidationError) as exc_info:
@@ -30,6 +30,32 @@ def test_isinstance_strict():
     assert v.isinstance_python('123') is False
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('[1, 2, 3]', True),
+        (b'[]', True),
+        (bytearray(b'[1]'), True),
+        ('[1, "2", 3]', True),
+        ('[1, "x", 3]', False),
+        ('["x", "y", "z"]', False),
+        ('{"a": 1}', False),
+        ('[1, 2', False),
+        ('not json', False),
+        (123, False),
+    ],
+)
+def test_isinstance_json(input_value, expected):
+    v = SchemaValidator(cs.list_schema(cs.int_schema()))
+    assert v.isinstance_json(input_value) is expected
+
+
+def test_isinstance_json_strict():
+    v = SchemaValidator(cs.list_schema(cs.int_schema()))
+    assert v.isinstance_json('[1, "2"]') is True
+    assert v.isinstance_json('[1, "2"]', strict=True) is False
+
+
 def test_isinstance_forbid_extra_fn_override():
     v = SchemaValidator(cs.typed_dict_schema({'f': cs.typed_dict_field(cs.str_schema())}))
 
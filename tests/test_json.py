@@ -1,6 +1,8 @@
 import json
 import platform
 import re
+from datetime import datetime
+from decimal import Decimal
 
 import pytest
 from dirty_equals import IsFloatNan, IsList
@@ -36,6 +38,23 @@ def test_input_types(input_value):
     assert v.validate_json(input_value) == [1, 2, 3]
 
 
+@pytest.mark.parametrize('to_input', [str, lambda s: s.encode(), lambda s: bytearray(s.encode())])
+@pytest.mark.parametrize(
+    'json_str,error',
+    [
+        ('["éé", x]', 'expected value at line 1 column 8'),
+        ('[1,\n "€😀" x]', 'expected `,` or `]` at line 2 column 7'),
+        ('["é",', 'EOF while parsing a value at line 1 column 5'),
+        ('["é"\n', 'EOF while parsing a list at line 2 column 0'),
+    ],
+)
+def test_input_types_error_position(to_input, json_str, error):
+    v = SchemaValidator(core_schema.list_schema(items_schema=core_schema.str_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(to_input(json_str))
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'error': error}
+
+
 def test_input_type_invalid():
     v = SchemaValidator(core_schema.list_schema(items_schema=core_schema.int_schema()))
     with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type,'):
@@ -205,6 +224,79 @@ def test_json_invalid():
     ]
 
 
+@pytest.mark.parametrize('input_type', [str, bytes])
+def test_validate_ndjson(input_type):
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    data = '{"a": 1}\n\n{"a": "x"}\n{"a": 3}\r\n{"a"\n  \n{"a": 5}\n'
+    if input_type is bytes:
+        data = data.encode()
+
+    results, errors = v.validate_ndjson(data)
+    assert results == [{'a': 1}, {'a': 3}, {'a': 5}]
+    assert [line_number for line_number, _ in errors] == [3, 5]
+    assert errors[0][1].errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (3, 'a'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert errors[1][1].errors(include_url=False) == [
+        {
+            'type': 'json_invalid',
+            'loc': (5,),
+            'msg': 'Invalid JSON: EOF while parsing an object at line 1 column 4',
+            'input': '{"a"',
+            'ctx': {'error': 'EOF while parsing an object at line 1 column 4'},
+        }
+    ]
+
+    assert v.validate_ndjson('') == ([], [])
+    with pytest.raises(ValidationError, match='JSON input should be string, bytes or bytearray'):
+        v.validate_ndjson(1)
+
+
+def test_validate_ndjson_options():
+    v = SchemaValidator(core_schema.list_schema(core_schema.float_schema()))
+    data = '[1, 2.5]\n[{"a": 1}]\n[1, 1]\n'
+    results, errors = v.validate_ndjson(data, preserve_number_type=True, deep_freeze=True)
+    assert results == [(1, 2.5), (1, 1)]
+    assert type(results[0][0]) is int
+    assert [line_number for line_number, _ in errors] == [2]
+
+    v = SchemaValidator(core_schema.any_schema())
+    results, errors = v.validate_ndjson('{"a": 1, "a": 2}\n{"b": 1.5}\n', forbid_duplicate_keys=True)
+    assert results == [{'b': 1.5}]
+    assert [(line_number, e.errors()[0]['type']) for line_number, e in errors] == [(1, 'json_duplicate_key')]
+
+    results, errors = v.validate_ndjson('1.5\n{"a": [1]}\n', parse_only=True, json_number_mode='decimal')
+    assert results == [Decimal('1.5'), {'a': [1]}]
+    assert errors == []
+
+    with pytest.warns(UserWarning):
+        results, errors = v.validate_ndjson('"\\ud800"\n', json_warnings=True)
+    assert results == ['\ufffd']
+
+    with pytest.raises(ValueError, match="`json_number_mode='lossless-float'` is only supported"):
+        v.validate_ndjson('1.5', json_number_mode='lossless-float')
+
+
+def test_validate_json_prefix():
+    v = SchemaValidator(core_schema.int_schema())
+
+    assert v.validate_json_prefix('123 456') == 123
+    value, consumed = v.validate_json_prefix('123 456', return_consumed=True)
+    assert (value, consumed) == (123, 3)
+    assert v.validate_json_prefix(b'123 456'[consumed:], return_consumed=True) == (456, 4)
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_json_prefix('[1, 2]\n[3]', return_consumed=True) == ([1, 2], 6)
+
+    with pytest.raises(ValidationError, match='Invalid JSON: EOF while parsing a list'):
+        v.validate_json_prefix('[1, 2')
+
+
 class Foobar:
     def __str__(self):
         return 'Foobar.__str__'
@@ -469,3 +561,147 @@ def test_json_bytes_hex_invalid():
             'input': wrong_input,
         }
     ]
+
+
+def test_json_number_mode_decimal():
+    inputs = []
+
+    def record_input(input_value):
+        inputs.append(input_value)
+        return input_value
+
+    v = SchemaValidator(core_schema.no_info_before_validator_function(record_input, core_schema.float_schema()))
+    assert v.validate_json('0.1') == 0.1
+    assert v.validate_json('0.1', json_number_mode='decimal') == 0.1
+    assert inputs == [0.1, Decimal('0.1')]
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()))
+    assert v.validate_json('[1, 1.10, 1e-30]', json_number_mode='decimal') == [1, Decimal('1.10'), Decimal('1e-30')]
+
+    # the data is still validated as JSON, so JSON-only coercions and strictness apply as normal
+    v = SchemaValidator(core_schema.float_schema(strict=True))
+    assert v.validate_json('0.1', json_number_mode='decimal') == 0.1
+    v = SchemaValidator(core_schema.datetime_schema(strict=True))
+    assert v.validate_json('"2020-01-01T00:00:00"', json_number_mode='decimal') == datetime(2020, 1, 1)
+    v = SchemaValidator(core_schema.decimal_schema())
+    assert v.validate_json('0.10000000000000000555', json_number_mode='decimal') == Decimal('0.10000000000000000555')
+    v = SchemaValidator(core_schema.dict_schema())
+    with pytest.raises(ValidationError, match='Input should be an object'):
+        v.validate_json('[0.1]', json_number_mode='decimal')
+
+    with pytest.raises(ValueError, match="Invalid float mode, should be `'float'`, `'decimal'` or `'lossless-float'`"):
+        v.validate_json('[]', json_number_mode='exact')
+    with pytest.raises(ValueError, match="`json_number_mode='lossless-float'` is only supported with `parse_only=True`"):
+        v.validate_json('[]', json_number_mode='lossless-float')
+
+
+def test_parse_only():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_json('[1, 2]') == [1, 2]
+    # the schema isn't applied, so data which would fail validation is returned unchanged
+    assert v.validate_json('{"a": [1, "x", null], "b": 1.5}', parse_only=True) == {'a': [1, 'x', None], 'b': 1.5}
+    assert v.validate_json('[1, 1.10]', parse_only=True, json_number_mode='decimal') == [1, Decimal('1.10')]
+
+    with pytest.raises(ValidationError, match='Invalid JSON'):
+        v.validate_json('[1, 2', parse_only=True)
+
+
+def test_forbid_duplicate_keys():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema()))
+    # by default the last value wins
+    assert v.validate_json('{"a":1,"a":2}') == {'a': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a":1,"a":2}', forbid_duplicate_keys=True)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'json_duplicate_key',
+            'loc': (),
+            'msg': "Duplicate key 'a' in JSON object",
+            'input': {'a': 2},
+            'ctx': {'key': 'a'},
+        }
+    ]
+
+    # nested objects are checked, each duplicated key is reported once
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"x": [{"b":1,"b":2,"b":3,"c":1}], "y": {"c":1,"c":2}}', forbid_duplicate_keys=True)
+    assert [(e['loc'], e['ctx']) for e in exc_info.value.errors()] == [
+        (('x', 0), {'key': 'b'}),
+        (('y',), {'key': 'c'}),
+    ]
+
+    assert v.validate_json('{"a":1,"b":{"a":2}}', forbid_duplicate_keys=True) == {'a': 1, 'b': {'a': 2}}
+    for kwargs in {'parse_only': True}, {'json_number_mode': 'decimal'}:
+        with pytest.raises(ValidationError, match="Duplicate key 'a' in JSON object"):
+            v.validate_json('{"a":1,"a":2}', forbid_duplicate_keys=True, **kwargs)
+
+
+def test_json_warnings():
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()))
+    with pytest.raises(ValidationError, match='Invalid JSON: unexpected end of hex escape'):
+        v.validate_json(r'["a\ud800b"]')
+
+    with pytest.warns(UserWarning) as record:
+        assert v.validate_json(r'["a\ud800b", "\ud83d\ude00", "\\ud800"]', json_warnings=True) == [
+            'a\ufffdb',
+            '\U0001f600',
+            '\\ud800',
+        ]
+    assert [str(w.message) for w in record] == [
+        'JSON string has a lone surrogate escape `\\ud800` at line 1 column 4, replaced with U+FFFD'
+    ]
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.dict_schema()))
+    with pytest.warns(UserWarning) as record:
+        assert v.validate_json(r'[{"\udc00": "\ud800\ud800"}]', json_warnings=True) == [{'�': '��'}]
+    assert [str(w.message) for w in record] == [
+        'JSON string has a lone surrogate escape `\\udc00` at line 1 column 4, replaced with U+FFFD',
+        'JSON string has a lone surrogate escape `\\ud800` at line 1 column 14, replaced with U+FFFD',
+        'JSON string has a lone surrogate escape `\\ud800` at line 1 column 20, replaced with U+FFFD',
+    ]
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()))
+    with pytest.warns(UserWarning) as record:
+        v.validate_json('[0.10000000000000000555, 0.1000000000000000000, 12345678901234567890]', json_warnings=True)
+    assert [str(w.message) for w in record] == [
+        'JSON number `0.10000000000000000555` at line 1 column 2 has more significant digits than a float can hold, '
+        'so may lose precision'
+    ]
+
+    # decimals are decoded exactly
+    assert v.validate_json('[0.10000000000000000555]', json_warnings=True, json_number_mode='decimal') == [
+        Decimal('0.10000000000000000555')
+    ]
+
+
+def test_preserve_number_type():
+    v = SchemaValidator(core_schema.list_schema(core_schema.float_schema()))
+    assert v.validate_json('[1, 1.0, 2.5]') == [1.0, 1.0, 2.5]
+
+    output = v.validate_json('[1, 1.0, 2.5, 12345678901234567890123]', preserve_number_type=True)
+    assert [(type(x), x) for x in output] == [(int, 1), (float, 1.0), (float, 2.5), (int, 12345678901234567890123)]
+    output = v.validate_json('[1, 1.0]', preserve_number_type=True, json_number_mode='decimal')
+    assert [type(x) for x in output] == [int, float]
+
+    # constraints still apply to integer numbers
+    v = SchemaValidator(core_schema.float_schema(le=10))
+    assert type(v.validate_json('3', preserve_number_type=True)) is int
+    with pytest.raises(ValidationError, match='Input should be less than or equal to 10'):
+        v.validate_json('11', preserve_number_type=True)
+
+    # only integer JSON numbers keep their type, python ints produced during validation are still converted
+    inner = SchemaValidator(core_schema.float_schema())
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.no_info_before_validator_function(
+                lambda x: int(x) if isinstance(x, str) else x, core_schema.float_schema()
+            )
+        )
+    )
+    output = v.validate_json('["2"]', preserve_number_type=True)
+    assert [(type(x), x) for x in output] == [(float, 2.0)]
+    v = SchemaValidator(core_schema.list_schema(core_schema.no_info_plain_validator_function(inner.validate_python)))
+    output = v.validate_json('[1]', preserve_number_type=True)
+    assert [(type(x), x) for x in output] == [(float, 1.0)]
@@ -1,5 +1,9 @@
 import copy
 import pickle
+import warnings
+from datetime import date
+from decimal import Decimal
+from types import MappingProxyType
 
 import pytest
 from typing_extensions import (  # noqa: UP035 (https://github.com/astral-sh/ruff/pull/18476)
@@ -13,8 +17,10 @@ from typing_inspection.introspection import UNKNOWN, AnnotationSource, inspect_a
 from pydantic_core import CoreConfig, CoreSchema, CoreSchemaType, PydanticUndefined, core_schema
 from pydantic_core._pydantic_core import (
     SchemaError,
+    SchemaSerializer,
     SchemaValidator,
     ValidationError,
+    ValidationResult,
     __version__,
     build_info,
     build_profile,
@@ -113,6 +119,276 @@ def test_custom_title():
     assert exc_info.value.title == 'MyInt'
 
 
+def test_report_changes():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python(1) == 1
+    result = v.validate_python(1, report_changes=True)
+    assert isinstance(result, ValidationResult)
+    assert (result.value, result.changed) == (1, False)
+    assert result.hash is None
+    assert result.field_timings is None
+    assert v.validate_python('1', report_changes=True).changed is True
+    assert v.validate_python(True, report_changes=True).changed is True
+
+
+def test_with_hash():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.set_schema(core_schema.str_schema())),
+            }
+        )
+    )
+    result = v.validate_python({'a': 1, 'b': ['x', 'y']}, with_hash=True)
+    assert result.value == {'a': 1, 'b': {'x', 'y'}}
+    assert result.changed is None
+    h = result.hash
+    assert isinstance(h, int)
+    assert 0 <= h < 2**64
+    # equal inputs give equal hashes, regardless of key and item order or lax conversions
+    assert v.validate_python({'b': ['y', 'x'], 'a': '1'}, with_hash=True).hash == h
+    # changing a field changes the hash
+    assert v.validate_python({'a': 2, 'b': ['x', 'y']}, with_hash=True).hash != h
+    assert v.validate_python({'a': 1, 'b': ['x', 'z']}, with_hash=True).hash != h
+    result = v.validate_python({'a': 1, 'b': ['x', 'y']}, report_changes=True, with_hash=True)
+    assert (result.value, result.changed, result.hash) == ({'a': 1, 'b': {'x', 'y'}}, True, h)
+    # the hash doesn't depend on python's randomized string hashing, so it's the same in every process
+    assert h == 8281093441537201316
+
+
+def test_with_hash_unhashable():
+    v = SchemaValidator(core_schema.list_schema())
+    assert isinstance(v.validate_python([1, Decimal('1.5'), date(2020, 1, 1)], with_hash=True).hash, int)
+
+    with pytest.raises(TypeError, match='Unable to compute a stable hash of a `object` object'):
+        v.validate_python([1, object()], with_hash=True)
+
+
+def test_profile():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.model_fields_schema(
+                        {
+                            'c': core_schema.model_field(core_schema.str_schema()),
+                            'd': core_schema.model_field(core_schema.list_schema(core_schema.int_schema())),
+                        }
+                    )
+                ),
+            }
+        )
+    )
+    input_value = {'a': 1, 'b': {'c': 'x', 'd': list(range(100))}}
+    result = v.validate_python(input_value, profile=True)
+    assert result.value == v.validate_python(input_value)
+    timings = result.field_timings
+    assert set(timings) == {('a',), ('b',), ('b', 'c'), ('b', 'd')}
+    assert all(isinstance(t, int) and 0 < t < 10**10 for t in timings.values())
+    # a field's timing includes the fields nested within it
+    assert timings[('b',)] >= timings[('b', 'c')] + timings[('b', 'd')]
+
+    result = v.validate_python(input_value, report_changes=True, with_hash=True, profile=True)
+    assert result.changed is True
+    assert result.hash == v.validate_python(input_value, with_hash=True).hash
+    assert set(result.field_timings) == {('a',), ('b',), ('b', 'c'), ('b', 'd')}
+
+
+def test_profile_nested_locations():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'x': core_schema.typed_dict_field(
+                        core_schema.dict_schema(
+                            core_schema.str_schema(),
+                            core_schema.typed_dict_schema(
+                                {'y': core_schema.typed_dict_field(core_schema.int_schema())}
+                            ),
+                        )
+                    )
+                }
+            )
+        )
+    )
+    result = v.validate_python([{'x': {'k': {'y': 1}}}, {'x': {}}], profile=True)
+    # fields of the same name are timed separately at each location, including list indices and dict keys
+    assert set(result.field_timings) == {(0, 'x'), (0, 'x', 'k', 'y'), (1, 'x')}
+    # nothing is timed without `profile`
+    assert v.validate_python([{'x': {}}], report_changes=True).field_timings is None
+
+
+def test_deep_freeze():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.list_schema())),
+            'b': core_schema.typed_dict_field(
+                core_schema.dict_schema(core_schema.str_schema(), core_schema.set_schema(core_schema.int_schema()))
+            ),
+            'c': core_schema.typed_dict_field(core_schema.any_schema()),
+        }
+    )
+    v = SchemaValidator(schema)
+    input_value = {'a': [[1, 2], []], 'b': {'x': [1, '2']}, 'c': [{'d': [1, {2}]}]}
+    output = v.validate_python(input_value, deep_freeze=True)
+    assert output['a'] == ((1, 2), ())
+    assert output['b'] == {'x': frozenset({1, 2})}
+    assert isinstance(output['b'], MappingProxyType)
+    with pytest.raises(TypeError):
+        output['b']['y'] = frozenset()
+    # values which aren't validated by a collection validator are frozen all the way down
+    assert output['c'] == ({'d': (1, frozenset({2}))},)
+    assert isinstance(output['c'][0], MappingProxyType)
+
+    json_output = v.validate_json(
+        '{"a": [[1, 2], []], "b": {"x": [1, 2]}, "c": [{"d": [1, [2]]}]}', deep_freeze=True
+    )
+    assert json_output['a'] == ((1, 2), ())
+    assert isinstance(json_output['b'], MappingProxyType)
+    assert json_output['c'] == ({'d': (1, (2,))},)
+
+    # frozen output serializes like the unfrozen output, without warnings
+    s = SchemaSerializer(schema)
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert s.to_python(output) == {'a': [[1, 2], []], 'b': {'x': {1, 2}}, 'c': ({'d': (1, frozenset({2}))},)}
+        assert s.to_json(output) == b'{"a":[[1,2],[]],"b":{"x":[1,2]},"c":[{"d":[1,[2]]}]}'
+
+    output = v.validate_python(input_value)
+    assert output == {'a': [[1, 2], []], 'b': {'x': {1, 2}}, 'c': [{'d': [1, {2}]}]}
+    assert type(output['b']) is dict
+
+
+def test_validate_python_batch():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    results = v.validate_python_batch([{'a': '1', 'b': 'x'}, {'a': 'wrong', 'b': 'y'}, {'a': 3}, {'a': 4, 'b': 'z'}])
+    assert len(results) == 4
+    assert results[0] == {'a': 1, 'b': 'x'}
+    assert results[3] == {'a': 4, 'b': 'z'}
+
+    assert isinstance(results[1], ValidationError)
+    assert results[1].errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+    assert isinstance(results[2], ValidationError)
+    assert [e['type'] for e in results[2].errors()] == ['missing']
+
+    assert v.validate_python_batch(iter([])) == []
+    assert v.validate_python_batch(({'a': i, 'b': 'x'} for i in range(3)), strict=True) == [
+        {'a': 0, 'b': 'x'},
+        {'a': 1, 'b': 'x'},
+        {'a': 2, 'b': 'x'},
+    ]
+    assert isinstance(v.validate_python_batch([{'a': '1', 'b': 'x'}], strict=True)[0], ValidationError)
+
+
+def test_validate_python_batch_model_fields():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.with_default_schema(core_schema.str_schema(), default='x')),
+            }
+        )
+    )
+    results = v.validate_python_batch([{'a': '1'}, {'a': 'wrong'}, {'a': 3, 'b': 'y'}, {}])
+    assert results[0] == ({'a': 1, 'b': 'x'}, None, {'a'})
+    assert results[2] == ({'a': 3, 'b': 'y'}, None, {'a', 'b'})
+    assert [e['type'] for e in results[1].errors()] == ['int_parsing']
+    assert [e['type'] for e in results[3].errors()] == ['missing']
+
+
+def test_validate_python_many():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.with_default_schema(core_schema.str_schema(), default='x')),
+            }
+        )
+    )
+    results, errors = v.validate_python_many([{'a': '1'}, {'a': 'wrong'}, {'a': 3, 'b': 'y'}, {}])
+    assert results == [({'a': 1, 'b': 'x'}, None, {'a'}), None, ({'a': 3, 'b': 'y'}, None, {'a', 'b'}), None]
+    assert errors[0] is None and errors[2] is None
+    assert [e['type'] for e in errors[1].errors()] == ['int_parsing']
+    assert [e['type'] for e in errors[3].errors()] == ['missing']
+
+    assert v.validate_python_many([]) == ([], [])
+    results, errors = v.validate_python_many([{'a': '1'}, {'a': 2}], strict=True)
+    assert results == [None, ({'a': 2, 'b': 'x'}, None, {'a'})]
+    assert isinstance(errors[0], ValidationError) and errors[1] is None
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        {'a': 1, 'b': [1, 2]},
+        {'a': 'x', 'b': [1, 2]},
+        {'a': 1, 'b': [1, 'y', 'z']},
+        {'b': 'not a list'},
+        'not a dict',
+    ],
+)
+def test_track_locations(input_value):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    try:
+        expected = v.validate_python(input_value)
+    except ValidationError as e:
+        expected_errors = e.errors(include_url=False)
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value, track_locations=False)
+        # the same errors are reported, just without their locations
+        assert exc_info.value.errors(include_url=False) == [{**error, 'loc': ()} for error in expected_errors]
+    else:
+        assert v.validate_python(input_value, track_locations=False) == expected
+
+    # locations are tracked again for later validation
+    if isinstance(input_value, dict) and input_value.get('a') == 'x':
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['loc'] == ('a',)
+
+
+def test_track_locations_nested():
+    inner = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    inner_errors = []
+
+    def f(input_value):
+        try:
+            return inner.validate_python(input_value)
+        except ValidationError as e:
+            inner_errors.append(e.errors(include_url=False))
+            raise
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.no_info_plain_validator_function(f)))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[1], [2, 'x']], track_locations=False)
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ()
+    # the setting belongs to the outer call, validation called from a validator still tracks locations
+    assert inner_errors[0][0]['loc'] == (1,)
+
+
 def test_validation_error_multiple(pydantic_version):
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
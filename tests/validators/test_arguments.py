@@ -901,6 +901,36 @@ def test_alias_validate_by_name(py_and_json: PyAndJson, input_value, expected):
         assert v.validate_test(input_value) == expected
 
 
+def test_alias_keyword_only() -> None:
+    def func(*, user_id: int, display_name: str = 'anon'):
+        return user_id, display_name
+
+    v = SchemaValidator(
+        core_schema.call_schema(
+            core_schema.arguments_schema(
+                [
+                    core_schema.arguments_parameter(
+                        name='user_id', schema=core_schema.int_schema(), mode='keyword_only', alias='userId'
+                    ),
+                    core_schema.arguments_parameter(
+                        name='display_name',
+                        schema=core_schema.with_default_schema(core_schema.str_schema(), default='anon'),
+                        mode='keyword_only',
+                        alias='displayName',
+                    ),
+                ]
+            ),
+            func,
+        )
+    )
+    assert v.validate_python(ArgsKwargs((), {'userId': '1', 'displayName': 'Ada'})) == (1, 'Ada')
+    assert v.validate_python(ArgsKwargs((), {'userId': 2})) == (2, 'anon')
+    with pytest.raises(
+        ValidationError, match=r'userId\n +Missing required keyword only argument \[type=missing_keyword_only_argument,'
+    ):
+        v.validate_python(ArgsKwargs((), {'user_id': 3}))
+
+
 def test_only_validate_by_name(py_and_json) -> None:
     schema = core_schema.arguments_schema(
         [
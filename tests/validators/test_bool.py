@@ -80,15 +80,36 @@ def test_bool_repr():
     v = SchemaValidator(cs.bool_schema())
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false,str_coercion:None}),'
+        'definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.bool_schema(strict=True))
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true,str_coercion:None}),'
+        'definitions=[],cache_strings=True)'
     )
 
 
+def test_bool_str_coercion():
+    v = SchemaValidator(
+        cs.typed_dict_schema(
+            {
+                'coerced': cs.typed_dict_field(cs.bool_schema(strict=True, str_coercion=True)),
+                'rejected': cs.typed_dict_field(cs.bool_schema(str_coercion=False)),
+            }
+        )
+    )
+    assert v.validate_python({'coerced': 'true', 'rejected': 1}) == {'coerced': True, 'rejected': True}
+    assert v.validate_json('{"coerced": "off", "rejected": false}') == {'coerced': False, 'rejected': False}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'coerced': 'true', 'rejected': 'true'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'bool_type', 'loc': ('rejected',), 'msg': 'Input should be a valid boolean', 'input': 'true'}
+    ]
+
+
 def test_bool_key(py_and_json: PyAndJson):
     v = py_and_json({'type': 'dict', 'keys_schema': {'type': 'bool'}, 'values_schema': {'type': 'int'}})
     assert v.validate_test({True: 1, False: 2}) == {True: 1, False: 2}
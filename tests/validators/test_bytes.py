@@ -3,7 +3,7 @@ from typing import Any
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import CoreConfig, SchemaValidator, ValidationError
 from pydantic_core import core_schema as cs
 
 from ..conftest import Err, PyAndJson
@@ -122,3 +122,89 @@ def test_length_ctx():
             'ctx': {'max_length': 3},
         }
     ]
+
+
+def test_base64_encoded_and_decoded_max_length():
+    config = CoreConfig(val_json_bytes='base64')
+    v = SchemaValidator(cs.bytes_schema(max_length=5, encoded_max_length=10), config=config)
+    assert v.validate_json('"YWJj"') == b'abc'
+
+    # 8 encoded bytes are within `encoded_max_length`, but decode to 6 bytes
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"YWJjZGVm"')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_too_long',
+            'loc': (),
+            'msg': 'Data should have at most 5 bytes',
+            'input': 'YWJjZGVm',
+            'ctx': {'max_length': 5},
+        }
+    ]
+
+    # 8 encoded bytes exceed `encoded_max_length`, even though they decode to 4 bytes
+    v = SchemaValidator(cs.bytes_schema(max_length=5, encoded_max_length=6), config=config)
+    assert v.validate_json('"YWJj"') == b'abc'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"YWJjZA=="')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_encoded_too_long',
+            'loc': (),
+            'msg': 'Encoded data should have at most 6 bytes',
+            'input': 'YWJjZA==',
+            'ctx': {'max_length': 6},
+        }
+    ]
+
+
+@pytest.mark.parametrize('schema', [cs.bytes_schema, lambda **kwargs: cs.bytes_schema(max_length=10, **kwargs)])
+def test_bytearray_input(schema):
+    byte_array = bytearray(b'abc')
+
+    v = SchemaValidator(schema())
+    output = v.validate_python(byte_array)
+    assert output == b'abc'
+    assert type(output) is bytes
+
+    v = SchemaValidator(schema(keep_bytearray=True))
+    assert v.validate_python(byte_array) is byte_array
+    assert v.validate_python(b'abc') == b'abc'
+
+    v = SchemaValidator(schema(keep_bytearray=True, strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid bytes \[type=bytes_type,'):
+        v.validate_python(byte_array)
+
+
+@pytest.mark.parametrize('schema', [cs.bytes_schema, lambda **kwargs: cs.bytes_schema(max_length=10, **kwargs)])
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'abc', b'abc'),
+        ('café'.encode(), b'caf\xc3\xa9'),
+        (bytearray(b'\xf0\x9f\x90\x8d'), b'\xf0\x9f\x90\x8d'),
+        (b'\xff', Err('invalid utf-8 sequence of 1 bytes from index 0')),
+        (b'ab\xc3', Err('incomplete utf-8 byte sequence from index 2')),
+    ],
+)
+def test_require_utf8(schema, input_value, expected):
+    v = SchemaValidator(schema(require_utf8=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'bytes_invalid_utf8',
+                'loc': (),
+                'msg': f'Data should be valid UTF-8: {expected.message}',
+                'input': input_value,
+                'ctx': {'utf8_error': expected.message},
+            }
+        ]
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is bytes
+
+    # without `require_utf8`, any bytes are valid
+    assert SchemaValidator(schema()).validate_python(input_value) == bytes(input_value)
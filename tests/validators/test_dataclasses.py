@@ -6,7 +6,7 @@ import weakref
 from typing import Any, ClassVar, Optional, Union
 
 import pytest
-from dirty_equals import IsListOrTuple, IsStr
+from dirty_equals import HasRepr, IsListOrTuple, IsStr
 
 from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError, core_schema
 from pydantic_core.core_schema import ExtraBehavior
@@ -456,6 +456,57 @@ def test_dataclass_post_init_args_multiple():
     assert dc_args == (True, 42)
 
 
+def test_dataclass_post_init_raises():
+    @dataclasses.dataclass
+    class Range:
+        start: int
+        end: int
+        step: dataclasses.InitVar[int]
+
+        def __post_init__(self, step: int):
+            if self.end < self.start:
+                raise ValueError('end must not be before start')
+            assert (self.end - self.start) % step == 0, 'range must be a multiple of step'
+
+    schema = core_schema.dataclass_schema(
+        Range,
+        core_schema.dataclass_args_schema(
+            'Range',
+            [
+                core_schema.dataclass_field(name='start', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(name='end', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(name='step', schema=core_schema.int_schema(), init_only=True),
+            ],
+            collect_init_only=True,
+        ),
+        ['start', 'end'],
+        post_init=True,
+    )
+
+    v = SchemaValidator(schema)
+    r = v.validate_python({'start': '1', 'end': 7, 'step': 3})
+    assert dataclasses.asdict(r) == {'start': 1, 'end': 7}
+
+    input_value = {'start': 5, 'end': 1, 'step': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': 'Value error, end must not be before start',
+            'input': input_value,
+            'ctx': {'error': HasRepr(repr(ValueError('end must not be before start')))},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 1, 'end': 6, 'step': 4})
+    error = exc_info.value.errors(include_url=False)[0]
+    assert error['type'] == 'assertion_error'
+    assert error['msg'] == 'Assertion failed, range must be a multiple of step'
+
+
 @pytest.mark.parametrize(
     'revalidate_instances,input_value,expected',
     [
@@ -334,3 +334,32 @@ def test_val_temporal_unit_date(val_temporal_unit, input_value, expected):
     )
     output = v.validate_python(input_value)
     assert output == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (0, date(1980, 1, 6)),
+        ('0', date(1980, 1, 6)),
+        (86_400, date(1980, 1, 7)),
+        ('86400', date(1980, 1, 7)),
+        (43_200, Err('should have zero time - e.g. be exact dates [type=date_from_datetime_inexact,')),
+        ('1980-01-06', date(1980, 1, 6)),
+    ],
+)
+def test_date_epoch_offset_seconds(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.date_schema(epoch_offset_seconds=315_964_800))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_date_epoch_offset_seconds_partial_day():
+    # timestamps only an exact date once shifted are accepted
+    v = SchemaValidator(core_schema.date_schema(epoch_offset_seconds=43_200))
+    assert v.validate_python(43_200) == date(1970, 1, 2)
+    assert v.validate_python('-43200') == date(1970, 1, 1)
+    with pytest.raises(ValidationError, match='date_from_datetime_inexact'):
+        v.validate_python(0)
@@ -560,3 +560,84 @@ def test_val_temporal_unit_datetime(val_temporal_unit, input_value, expected):
     )
     output = v.validate_python(input_value)
     assert output == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('Wed, 02 Oct 2002 13:00:00 GMT', datetime(2002, 10, 2, 13, 0, 0, tzinfo=timezone.utc)),
+        ('2 Oct 2002 13:00 +0130', datetime(2002, 10, 2, 13, 0, 0, tzinfo=timezone(timedelta(hours=1, minutes=30)))),
+        ('Tue, 01 Jul 2003 10:52:37 PDT', datetime(2003, 7, 1, 10, 52, 37, tzinfo=timezone(timedelta(hours=-7)))),
+        ('2002-10-02T13:00:00Z', datetime(2002, 10, 2, 13, 0, 0, tzinfo=timezone.utc)),
+        (
+            'Wed, 32 Oct 2002 13:00:00 GMT',
+            Err('Input should be a valid datetime or date, invalid character in year [type=datetime_from_date_parsing'),
+        ),
+        (
+            'Wed, 02 Foo 2002 13:00:00 GMT',
+            Err('Input should be a valid datetime or date, invalid character in year [type=datetime_from_date_parsing'),
+        ),
+    ],
+)
+def test_datetime_rfc2822(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(rfc2822=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_datetime_rfc2822_disabled():
+    v = SchemaValidator(core_schema.datetime_schema())
+    with pytest.raises(ValidationError, match=r'type=datetime_from_date_parsing'):
+        v.validate_python('Wed, 02 Oct 2002 13:00:00 GMT')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (1_600_000_000, datetime(2020, 9, 13, 12, 26, 40, tzinfo=timezone.utc)),
+        ('1600000000', datetime(2020, 9, 13, 12, 26, 40, tzinfo=timezone.utc)),
+        ('2020-01-01T12:00:00', datetime(2020, 1, 1, 12, 0, 0, tzinfo=timezone.utc)),
+        ('2020-01-01', datetime(2020, 1, 1, tzinfo=timezone.utc)),
+        ('2020-01-01T12:00:00+01:00', datetime(2020, 1, 1, 12, 0, 0, tzinfo=timezone(timedelta(hours=1)))),
+    ],
+)
+def test_datetime_assume_tz(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(assume_tz='utc'))
+    output = v.validate_test(input_value)
+    assert output == expected
+    assert output.utcoffset() == expected.utcoffset()
+
+
+def test_datetime_assume_tz_offset():
+    v = SchemaValidator(core_schema.datetime_schema(assume_tz='-05:30'))
+    assert v.validate_python('2020-01-01T12:00:00') == datetime(
+        2020, 1, 1, 12, 0, 0, tzinfo=timezone(-timedelta(hours=5, minutes=30))
+    )
+    # datetime instances are left unchanged
+    assert v.validate_python(datetime(2020, 1, 1)).tzinfo is None
+
+    with pytest.raises(SchemaError, match='Invalid `assume_tz` "EST"'):
+        SchemaValidator(core_schema.datetime_schema(assume_tz='EST'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (0, datetime(1980, 1, 6, tzinfo=timezone.utc)),
+        (86_400, datetime(1980, 1, 7, tzinfo=timezone.utc)),
+        (-1.5, datetime(1980, 1, 5, 23, 59, 58, 500_000, tzinfo=timezone.utc)),
+        (1_000_000_000_000, datetime(2011, 9, 14, 1, 46, 40, tzinfo=timezone.utc)),
+        # numeric strings are shifted like numbers, datetime strings aren't
+        ('0', datetime(1980, 1, 6, tzinfo=timezone.utc)),
+        ('86400.5', datetime(1980, 1, 7, 0, 0, 0, 500_000, tzinfo=timezone.utc)),
+        ('1980-01-06T00:00:00', datetime(1980, 1, 6)),
+    ],
+)
+def test_datetime_epoch_offset_seconds(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(epoch_offset_seconds=315_964_800))
+    output = v.validate_test(input_value)
+    assert output == expected
+    assert output.utcoffset() == expected.utcoffset()
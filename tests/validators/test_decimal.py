@@ -241,6 +241,16 @@ def test_decimal_multiple_of(py_and_json: PyAndJson, multiple_of: float, input_v
         assert isinstance(output, Decimal)
 
 
+def test_decimal_multiple_of_exact():
+    v = SchemaValidator(cs.decimal_schema(multiple_of=0.1))
+    assert v.validate_python(Decimal('0.3')) == Decimal('0.3')
+    assert v.validate_python(0.3) == Decimal('0.3')
+
+    # dividing first would round the quotient to 28 digits and accept this value
+    with pytest.raises(ValidationError, match='Input should be a multiple of 0.1'):
+        v.validate_python(Decimal('1.0000000000000000000000000000001'))
+
+
 def test_union_decimal_py():
     v = SchemaValidator(cs.union_schema(choices=[cs.decimal_schema(strict=True), cs.decimal_schema(multiple_of=7)]))
     assert v.validate_python('14') == 14
@@ -1095,3 +1095,48 @@ def test_no_exponential_blowup():
     )
 
     SchemaValidator(schema)
+
+
+def test_reentrant_validation() -> None:
+    nested_results = []
+    calls = []
+
+    def validate_nested(input_value):
+        # call back into the validator with the same input while the outer validation holds its recursion guard
+        if not calls:
+            calls.append(input_value)
+            nested_results.append(validator.validate_python(input_value))
+        return input_value
+
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('Branch'),
+        [
+            core_schema.no_info_before_validator_function(
+                validate_nested,
+                core_schema.typed_dict_schema(
+                    {
+                        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'sub_branch': core_schema.typed_dict_field(
+                            core_schema.with_default_schema(
+                                core_schema.nullable_schema(core_schema.definition_reference_schema('Branch')),
+                                default=None,
+                            )
+                        ),
+                    }
+                ),
+                ref='Branch',
+            )
+        ],
+    )
+    validator = SchemaValidator(schema)
+
+    input_value = {'name': 'root', 'sub_branch': {'name': 'b1'}}
+    expected = {'name': 'root', 'sub_branch': {'name': 'b1', 'sub_branch': None}}
+    assert validator.validate_python(input_value) == expected
+    assert nested_results == [expected]
+
+    # the recursion guard of the outer call is intact, cyclic data is still detected
+    cyclic_data = {'name': 'cyclic'}
+    cyclic_data['sub_branch'] = cyclic_data
+    with pytest.raises(ValidationError, match='Recursion error - cyclic reference detected'):
+        validator.validate_python(cyclic_data)
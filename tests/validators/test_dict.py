@@ -2,12 +2,13 @@ import re
 import sys
 from collections import OrderedDict
 from collections.abc import Mapping
+from enum import Enum
 from typing import Any
 
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 from pydantic_core import core_schema as cs
 
 from ..conftest import Err, PyAndJson
@@ -196,6 +197,31 @@ def test_mapping_error_yield_1(mapping_items):
     ]
 
 
+def test_items_only():
+    class ItemsOnly:
+        def __init__(self, pairs):
+            self._pairs = pairs
+
+        def items(self):
+            yield from self._pairs
+
+    v = SchemaValidator(cs.dict_schema(keys_schema=cs.int_schema(), values_schema=cs.int_schema()))
+    assert v.validate_python(ItemsOnly([('1', 2), (3, '4')])) == {1: 2, 3: 4}
+    assert v.validate_python(ItemsOnly([])) == {}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ItemsOnly([('a', 1)]))
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('a', '[key]')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ItemsOnly(['ab']))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'mapping_type'
+
+    v = SchemaValidator(cs.dict_schema(strict=True, keys_schema=cs.int_schema(), values_schema=cs.int_schema()))
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python(ItemsOnly([(1, 2)]))
+
+
 @pytest.mark.parametrize(
     'kwargs,input_value,expected',
     [
@@ -316,6 +342,134 @@ def test_dict_fail_fast(fail_fast, expected):
     assert exc_info.value.errors(include_url=False) == expected
 
 
+def test_dict_strict_keys():
+    v = SchemaValidator(cs.dict_schema(cs.int_schema(), cs.int_schema()))
+    assert v.validate_json('{"1": 2}') == {1: 2}
+
+    v = SchemaValidator(cs.dict_schema(cs.int_schema(), cs.int_schema(), strict_keys=True))
+    assert v.validate_python({1: 2}) == {1: 2}
+    # values are still coerced
+    assert v.validate_python({1: '2'}) == {1: 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"1": 2}')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('1', '[key]'), 'msg': 'Input should be a valid integer', 'input': '1'}
+    ]
+
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python({'1': 2})
+
+
+def test_dict_enum_keys_by_name():
+    class Color(Enum):
+        RED = 'red'
+        GREEN = 'green'
+
+    keys_schema = cs.enum_schema(Color, list(Color.__members__.values()))
+    v = SchemaValidator(cs.dict_schema(keys_schema, cs.int_schema(), enum_keys_by_name=True))
+    assert v.validate_json('{"RED": 1}') == {Color.RED: 1}
+    assert v.validate_python({'RED': 1, 'green': '2', Color.GREEN: 3}) == {Color.RED: 1, Color.GREEN: 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"BLUE": 1}')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'enum',
+            'loc': ('BLUE', '[key]'),
+            'msg': "Input should be 'red' or 'green'",
+            'input': 'BLUE',
+            'ctx': {'expected': "'red' or 'green'"},
+        }
+    ]
+
+    # without `enum_keys_by_name`, keys must be enum values
+    v = SchemaValidator(cs.dict_schema(keys_schema, cs.int_schema()))
+    with pytest.raises(ValidationError, match="Input should be 'red' or 'green'"):
+        v.validate_json('{"RED": 1}')
+
+    with pytest.raises(SchemaError, match='`enum_keys_by_name` requires `keys_schema` to be an enum schema'):
+        SchemaValidator(cs.dict_schema(cs.str_schema(), cs.int_schema(), enum_keys_by_name=True))
+
+
+class DictSubclass(dict):
+    pass
+
+
+@pytest.mark.parametrize(
+    'keys_schema,values_schema,input_value',
+    [
+        (cs.str_schema(), cs.int_schema(), {f'key{i}': i for i in range(1000)}),
+        (cs.int_schema(), cs.float_schema(), {i: i / 3 for i in range(1000)}),
+        (cs.str_schema(), cs.bool_schema(), {str(i): i % 2 == 0 for i in range(1000)}),
+        (cs.any_schema(), cs.any_schema(), {i: [i] for i in range(1000)}),
+        # the last item needs coercion, so the generic path is used
+        (cs.str_schema(), cs.int_schema(), {**{f'key{i}': i for i in range(999)}, 'last': '999'}),
+        (cs.str_schema(), cs.int_schema(), {**{f'key{i}': i for i in range(999)}, 'last': True}),
+        (cs.str_schema(), cs.float_schema(), {**{f'key{i}': float(i) for i in range(999)}, 'last': 1}),
+        (cs.str_schema(), cs.float_schema(), {**{f'key{i}': float(i) for i in range(999)}, 'last': float('nan')}),
+    ],
+)
+def test_dict_scalar_items_matches_generic(keys_schema, values_schema, input_value):
+    v = SchemaValidator(cs.dict_schema(keys_schema, values_schema))
+    # dict subclasses are always validated with the generic path
+    expected = v.validate_python(DictSubclass(input_value))
+    output = v.validate_python(input_value)
+    assert output == expected
+    assert output is not input_value
+    assert type(output) is dict
+    assert list(output) == list(expected)
+
+
+def test_dict_scalar_items_errors():
+    v = SchemaValidator(cs.dict_schema(cs.str_schema(), cs.float_schema(allow_inf_nan=False), max_length=1000))
+    input_value = {f'key{i}': float(i) for i in range(1000)}
+    assert v.validate_python(input_value) == input_value
+
+    input_value['key5'] = float('inf')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'finite_number', 'loc': ('key5',), 'msg': 'Input should be a finite number', 'input': float('inf')}
+    ]
+
+    input_value['key5'] = 5.0
+    input_value['key1000'] = 1000.0
+    with pytest.raises(ValidationError, match='Dictionary should have at most 1000 items after validation, not 1001'):
+        v.validate_python(input_value)
+
+    v = SchemaValidator(cs.dict_schema(cs.str_schema(), cs.str_schema()))
+    output = v.validate_python({'a': ''.join(['x', 'y']), 'b': ''.join(['x', 'y'])}, intern_strings=True)
+    assert output['a'] is output['b']
+
+
+def test_dict_nan_key_mode():
+    nan = float('nan')
+    v = SchemaValidator(cs.dict_schema(cs.float_schema(), cs.int_schema()))
+    output = v.validate_python({nan: 1, 'nan': 2})
+    assert len(output) == 2
+    assert all(k != k for k in output)
+
+    v = SchemaValidator(cs.dict_schema(cs.float_schema(), cs.int_schema(), nan_key_mode='forbid'))
+    assert v.validate_python({1.5: 1}) == {1.5: 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({nan: 1, 2: 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'finite_number', 'loc': ('nan', '[key]'), 'msg': 'Input should be a finite number', 'input': nan}
+    ]
+    with pytest.raises(ValidationError, match=r'\[key\]\n  Input should be a finite number'):
+        v.validate_json('{"NaN": 1}')
+
+    v = SchemaValidator(cs.dict_schema(nan_key_mode='forbid'))
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python({nan: 1})
+
+    with pytest.raises(SchemaError, match="Invalid nan_key_mode: skip, should be 'allow' or 'forbid'"):
+        SchemaValidator(cs.dict_schema(nan_key_mode='skip'))
+
+
 @pytest.mark.skipif(
     sys.implementation.name == 'graalpy',
     reason='GraalPy has a bug where PyMapping.items() does not preserve OrderedDict order. See: https://github.com/oracle/graalpython/issues/553',
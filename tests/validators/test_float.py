@@ -1,6 +1,7 @@
 import math
 import re
 import sys
+import warnings
 from decimal import Decimal
 from typing import Any
 
@@ -208,12 +209,12 @@ def test_float_repr():
     v = SchemaValidator(cs.float_schema())
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true,warn_lossy:false,reject_subnormal:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.float_schema(strict=True))
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true,warn_lossy:false,reject_subnormal:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.float_schema(multiple_of=7))
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
@@ -437,3 +438,68 @@ def test_allow_inf_nan_false_json() -> None:
     assert exc_info3.value.errors(include_url=False) == [
         {'type': 'finite_number', 'loc': (), 'msg': 'Input should be a finite number', 'input': float('-inf')}
     ]
+
+
+def test_float_context_bounds() -> None:
+    v = SchemaValidator(core_schema.float_schema(le_context_key='limit', ge_context_key='minimum'))
+    assert v.validate_python(1.5, context={'limit': 1.5}) == 1.5
+    assert v.validate_python(2.5) == 2.5
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(2.5, context={'limit': 1.5})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'less_than_equal',
+            'loc': (),
+            'msg': 'Input should be less than or equal to 1.5',
+            'input': 2.5,
+            'ctx': {'le': 1.5, 'input': 2.5},
+        }
+    ]
+
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 0.5'):
+        v.validate_python(0.25, context={'minimum': 0.5})
+
+
+@pytest.mark.parametrize('kwargs', [{}, {'ge': 0}], ids=repr)
+def test_warn_lossy(kwargs) -> None:
+    v = SchemaValidator(core_schema.float_schema(warn_lossy=True, **kwargs))
+
+    for input_value in [2**53 + 1, 2**64 + 1]:
+        with pytest.warns(UserWarning, match=rf'Lossy coercion to `float` from {input_value}'):
+            assert v.validate_python(input_value) == float(input_value)
+    with pytest.warns(UserWarning, match=r'Lossy coercion to `float` from 9007199254740993'):
+        assert v.validate_json('9007199254740993') == 2.0**53
+
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert v.validate_python(2**53) == 2.0**53
+        assert v.validate_python(2**100) == 2.0**100
+        assert v.validate_python(1) == 1.0
+        assert v.validate_python(1.5) == 1.5
+        assert v.validate_python('0.1') == 0.1
+        assert SchemaValidator(core_schema.float_schema()).validate_python(2**53 + 1) == 2.0**53
+
+
+@pytest.mark.parametrize('kwargs', [{}, {'ge': 0}], ids=repr)
+def test_reject_subnormal(kwargs) -> None:
+    v = SchemaValidator(core_schema.float_schema(reject_subnormal=True, **kwargs))
+
+    for input_value in [5e-324, 2.2e-308, '5e-324']:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'float_subnormal',
+                'loc': (),
+                'msg': 'Input should not be a subnormal float',
+                'input': input_value,
+            }
+        ]
+    with pytest.raises(ValidationError, match='Input should not be a subnormal float'):
+        v.validate_json('5e-324')
+
+    assert v.validate_python(0.0) == 0.0
+    assert v.validate_python(2.2250738585072014e-308) == 2.2250738585072014e-308
+    assert v.validate_python(1.5) == 1.5
+    assert SchemaValidator(core_schema.float_schema()).validate_python(5e-324) == 5e-324
@@ -77,7 +77,7 @@ def test_frozenset_no_validators_both(py_and_json: PyAndJson, input_value, expec
         ({1: 10, 2: 20, '3': '30'}.values(), frozenset({10, 20, 30})),
         ({1: 10, 2: 20, '3': '30'}, Err('Input should be a valid frozenset [type=frozen_set_type,')),
         ((x for x in [1, 2, '3']), frozenset({1, 2, 3})),
-        ({'abc'}, Err('0\n  Input should be a valid integer')),
+        ({'abc'}, Err('frozenset[int]\n  Input should be a valid integer')),
         ({1, 2, 'wrong'}, Err('Input should be a valid integer')),
         ({1: 2}, Err('1 validation error for frozenset[int]\n  Input should be a valid frozenset')),
         ('abc', Err('Input should be a valid frozenset')),
@@ -113,12 +113,12 @@ def test_frozenset_multiple_errors():
     assert exc_info.value.errors(include_url=False) == [
         {
             'type': 'int_parsing',
-            'loc': (0,),
+            'loc': (),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'a',
         },
-        {'type': 'int_type', 'loc': (1,), 'msg': 'Input should be a valid integer', 'input': (1, 2)},
-        {'type': 'int_type', 'loc': (2,), 'msg': 'Input should be a valid integer', 'input': []},
+        {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': (1, 2)},
+        {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': []},
     ]
 
 
@@ -201,14 +201,14 @@ def test_union_frozenset_list(input_value, expected):
                 errors=[
                     {
                         'type': 'int_type',
-                        'loc': ('frozenset[int]', 1),
+                        'loc': ('frozenset[int]',),
                         'msg': 'Input should be a valid integer',
                         'input': 'a',
                     },
                     # second because validation on the string choice comes second
                     {
                         'type': 'string_type',
-                        'loc': ('frozenset[str]', 0),
+                        'loc': ('frozenset[str]',),
                         'msg': 'Input should be a valid string',
                         'input': 1,
                     },
@@ -251,7 +251,8 @@ def test_repr():
         'validator=FrozenSet(FrozenSetValidator{'
         'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,'
         'name:"frozenset[any]",'
-        'fail_fast:false'
+        'fail_fast:false,'
+        'reject_mutable_input:false'
         '}),'
         'definitions=[],'
         'cache_strings=True)'
@@ -309,7 +310,7 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
             [
                 {
                     'type': 'int_parsing',
-                    'loc': (1,),
+                    'loc': (),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
                 },
@@ -321,13 +322,13 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
             [
                 {
                     'type': 'int_parsing',
-                    'loc': (1,),
+                    'loc': (),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
                 },
                 {
                     'type': 'int_parsing',
-                    'loc': (2,),
+                    'loc': (),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'again',
                 },
@@ -343,3 +344,21 @@ def test_frozenset_fail_fast(fail_fast, expected):
         v.validate_python([1, 'not-num', 'again'])
 
     assert exc_info.value.errors(include_url=False) == expected
+
+
+@pytest.mark.parametrize('reject_mutable_input', [None, False, True])
+def test_frozenset_output_hashable(reject_mutable_input):
+    v = SchemaValidator(cs.frozenset_schema(items_schema=cs.int_schema(), reject_mutable_input=reject_mutable_input))
+    output = v.validate_python(frozenset({'1', 2}))
+    assert type(output) is frozenset
+    assert {output: 'value'}[frozenset({1, 2})] == 'value'
+
+    output = v.validate_python([1, 2])
+    assert type(output) is frozenset
+    assert hash(output) == hash(frozenset({1, 2}))
+
+    if reject_mutable_input:
+        with pytest.raises(ValidationError, match=r'Input should be a valid frozenset \[type=frozen_set_type,'):
+            v.validate_python({1, 2})
+    else:
+        assert v.validate_python({1, 2}) == frozenset({1, 2})
@@ -1,5 +1,6 @@
 import json
 import re
+import warnings
 from decimal import Decimal
 from fractions import Fraction
 from typing import Any
@@ -360,7 +361,8 @@ def test_int_kwargs(py_and_json: PyAndJson, kwargs: dict[str, Any], input_value,
         errors = exc_info.value.errors(include_url=False)
         assert len(errors) == 1
         if 'ctx' in errors[0]:
-            assert errors[0]['ctx'] == kwargs
+            expected_ctx = kwargs if 'multiple_of' in kwargs else {**kwargs, 'input': input_value}
+            assert errors[0]['ctx'] == expected_ctx
     else:
         output = v.validate_test(input_value)
         assert output == expected
@@ -412,12 +414,12 @@ def test_int_repr():
     v = SchemaValidator(cs.int_schema())
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false,warn_lossy:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.int_schema(strict=True))
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true,warn_lossy:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.int_schema(multiple_of=7))
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
@@ -471,6 +473,28 @@ def test_long_python_inequality():
         v.validate_python(s)
 
 
+@pytest.mark.parametrize('mode', ['python', 'json'])
+def test_long_input_i64_bounds(mode):
+    v = SchemaValidator(cs.int_schema(le=2**62, ge=-(2**62)))
+    big = 123_456_789_012_345_678_901_234_567_890
+
+    cases = [
+        (big, 'less_than_equal', {'le': 2**62, 'input': big}),
+        (-big, 'greater_than_equal', {'ge': -(2**62), 'input': -big}),
+    ]
+    for value, error_type, ctx in cases:
+        with pytest.raises(ValidationError) as exc_info:
+            if mode == 'python':
+                v.validate_python(value)
+            else:
+                v.validate_json(str(value))
+        errors = exc_info.value.errors(include_url=False)
+        assert len(errors) == 1
+        assert errors[0]['type'] == error_type
+        assert errors[0]['input'] == value
+        assert errors[0]['ctx'] == ctx
+
+
 def test_long_json():
     v = SchemaValidator(cs.int_schema())
 
@@ -586,3 +610,85 @@ def test_json_big_int_key():
     assert v.validate_python({big_integer: 'x'}) == {big_integer: 'x'}
     assert v.validate_json('{"' + str(big_integer) + '": "x"}') == {big_integer: 'x'}
     assert v.validate_strings({str(big_integer): 'x'}) == {big_integer: 'x'}
+
+
+def test_int_context_bounds() -> None:
+    v = SchemaValidator(cs.int_schema(le_context_key='limit', ge_context_key='minimum'))
+    assert v.validate_python(10, context={'limit': 10}) == 10
+    assert v.validate_python(11) == 11
+    assert v.validate_python(11, context={}) == 11
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(11, context={'limit': 10})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'less_than_equal',
+            'loc': (),
+            'msg': 'Input should be less than or equal to 10',
+            'input': 11,
+            'ctx': {'le': 10, 'input': 11},
+        }
+    ]
+
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 5'):
+        v.validate_python(4, context={'limit': 10, 'minimum': 5})
+
+
+@pytest.mark.parametrize('kwargs', [{}, {'ge': 0}], ids=repr)
+def test_warn_lossy(kwargs) -> None:
+    v = SchemaValidator(cs.int_schema(warn_lossy=True, **kwargs))
+
+    for input_value in [1.0, Decimal('1.0'), '1.0']:
+        with pytest.warns(UserWarning, match=r'Lossy coercion to `int` from '):
+            assert v.validate_python(input_value) == 1
+    with pytest.warns(UserWarning, match=r'Lossy coercion to `int` from 1.0'):
+        assert v.validate_json('1.0') == 1
+
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert v.validate_python(1) == 1
+        assert v.validate_python('1') == 1
+        assert v.validate_python(True) == 1
+        assert v.validate_json('1') == 1
+        assert SchemaValidator(cs.int_schema()).validate_python(1.0) == 1
+
+
+def test_warn_lossy_union() -> None:
+    v = SchemaValidator(cs.union_schema([cs.int_schema(warn_lossy=True), cs.float_schema()]))
+
+    # the float member is an exact match for a float, so the int member's coercion isn't used
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert v.validate_python(1.0) == 1.0
+        assert v.validate_python(1) == 1
+
+    with pytest.warns(UserWarning, match=r"Lossy coercion to `int` from '1.0'"):
+        assert v.validate_python('1.0') == 1
+
+    v = SchemaValidator(
+        cs.union_schema(
+            [cs.list_schema(cs.union_schema([cs.int_schema(warn_lossy=True), cs.str_schema()])), cs.list_schema()],
+            mode='left_to_right',
+        )
+    )
+    with pytest.warns(UserWarning, match=r'Lossy coercion to `int` from 2.0') as record:
+        assert v.validate_python([2.0, 'a']) == [2, 'a']
+    assert len(record) == 1
+
+    # warnings from a member which fails validation after coercing an int aren't emitted either
+    v = SchemaValidator(
+        cs.union_schema(
+            [
+                cs.typed_dict_schema(
+                    {
+                        'a': cs.typed_dict_field(cs.int_schema(warn_lossy=True)),
+                        'b': cs.typed_dict_field(cs.str_schema()),
+                    }
+                ),
+                cs.dict_schema(),
+            ]
+        )
+    )
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert v.validate_python({'a': 2.0, 'b': 1}) == {'a': 2.0, 'b': 1}
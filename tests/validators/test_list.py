@@ -3,12 +3,13 @@ import re
 from collections import deque
 from collections.abc import Iterator
 from dataclasses import dataclass
+from types import MappingProxyType
 from typing import Any, Union
 
 import pytest
 from dirty_equals import Contains, HasRepr, IsInstance, IsList, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 from pydantic_core import core_schema as cs
 
 from ..conftest import Err, PyAndJson, infinite_generator
@@ -43,6 +44,158 @@ def test_list_strict():
     ]
 
 
+def test_list_tuple_coercion():
+    v = SchemaValidator(cs.list_schema(items_schema=cs.int_schema()))
+    assert v.validate_python((1, 2, 3)) == [1, 2, 3]
+    with pytest.raises(ValidationError, match='Input should be a valid list'):
+        v.validate_python((1, 2, 3), strict=True)
+
+    v = SchemaValidator(cs.list_schema(items_schema=cs.int_schema(), tuple_coercion=False))
+    assert v.validate_python([1, 2, 3]) == [1, 2, 3]
+    assert v.validate_python(deque([1, 2, 3])) == [1, 2, 3]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 2, 3))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'list_type', 'loc': (), 'msg': 'Input should be a valid list', 'input': (1, 2, 3)}
+    ]
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'pad_to': 3, 'pad_value': '0'}, [1], [1, 0, 0]),
+        ({'pad_to': 3, 'pad_value': 0}, ['1'], [1, 0, 0]),
+        ({'pad_to': 3, 'pad_value': 0}, [1, 2, 3, 4], [1, 2, 3, 4]),
+        ({'truncate_to': 2}, [1, 2, 3, 4], [1, 2]),
+        ({'truncate_to': 2}, ['1'], [1]),
+        ({'pad_to': 2, 'truncate_to': 2, 'pad_value': -1}, [1], [1, -1]),
+        ({'pad_to': 2, 'truncate_to': 2, 'pad_value': -1}, (1, 2, 3), [1, 2]),
+        ({'truncate_to': 0}, [1, 2], []),
+    ],
+)
+def test_list_pad_truncate(kwargs, input_value, expected):
+    v = SchemaValidator(cs.list_schema(items_schema=cs.int_schema(), **kwargs))
+    assert v.validate_python(input_value) == expected
+
+
+def test_list_pad_truncate_any_items():
+    v = SchemaValidator(cs.list_schema(pad_to=3, truncate_to=3, pad_value='x'))
+    assert v.validate_python([1]) == [1, 'x', 'x']
+    assert v.validate_python([1, 2, 3, 4]) == [1, 2, 3]
+    assert v.validate_json('[1]') == [1, 'x', 'x']
+
+
+def test_list_pad_truncate_after_length_checks():
+    v = SchemaValidator(cs.list_schema(items_schema=cs.int_schema(), min_length=2, pad_to=3, pad_value=0))
+    assert v.validate_python([1, 2]) == [1, 2, 0]
+    with pytest.raises(ValidationError, match='List should have at least 2 items'):
+        v.validate_python([1])
+
+
+def test_list_pad_value_invalid():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(cs.list_schema(items_schema=cs.int_schema(), pad_to=3))
+    assert exc_info.value.errors() == [
+        {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': None}
+    ]
+
+    v = SchemaValidator(cs.list_schema(items_schema=cs.nullable_schema(cs.int_schema()), pad_to=3))
+    assert v.validate_python([1]) == [1, None, None]
+
+    with pytest.raises(SchemaError, match=r'`pad_to` \(3\) can\'t be greater than `max_length`'):
+        SchemaValidator(cs.list_schema(items_schema=cs.int_schema(), max_length=2, pad_to=3, pad_value=0))
+
+    with pytest.raises(SchemaError, match="`pad_to` can't be used with `prefix_items`"):
+        SchemaValidator(cs.list_schema(cs.int_schema(), prefix_items=[cs.int_schema()], pad_to=3, pad_value=0))
+
+
+def test_list_pad_value_definitions():
+    # the items schema references a definition which is only filled after the list is built
+    v = SchemaValidator(
+        cs.definitions_schema(
+            cs.list_schema(cs.definition_reference_schema('small-int'), pad_to=2, pad_value='5'),
+            [cs.int_schema(le=10, ref='small-int')],
+        )
+    )
+    assert v.validate_python([1]) == [1, 5]
+
+    with pytest.raises(SchemaError, match='Input should be less than or equal to 10'):
+        SchemaValidator(
+            cs.definitions_schema(
+                cs.list_schema(cs.definition_reference_schema('small-int'), pad_to=2, pad_value=100),
+                [cs.int_schema(le=10, ref='small-int')],
+            )
+        )
+
+
+def test_list_prefix_items():
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), prefix_items=[cs.int_schema(), cs.str_schema()]))
+    assert v.validate_python([1, 'a', 2, 3]) == [1, 'a', 2, 3]
+    assert v.validate_python(('1', 'a', '2')) == [1, 'a', 2]
+    assert v.validate_json('[1, "a", 2, 3]') == [1, 'a', 2, 3]
+    assert v.validate_python([1]) == [1]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2, 'b'])
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (1,), 'msg': 'Input should be a valid string', 'input': 2},
+        {
+            'type': 'int_parsing',
+            'loc': (2,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'b',
+        },
+    ]
+
+    v = SchemaValidator(cs.list_schema(prefix_items=[cs.int_schema()]))
+    assert v.validate_python(['1', 'a', None]) == [1, 'a', None]
+
+
+def test_list_deque_output():
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), collection='deque', maxlen=3))
+    output = v.validate_python(['1', 2])
+    assert type(output) is deque
+    assert output == deque([1, 2])
+    assert output.maxlen == 3
+    assert v.validate_python(deque([1, 2, 3, 4], maxlen=4)) == deque([2, 3, 4])
+    assert v.validate_json('[1, 2, 3, 4, 5]') == deque([3, 4, 5])
+
+    v = SchemaValidator(cs.list_schema(collection='deque'))
+    output = v.validate_python([1, 'a'])
+    assert output == deque([1, 'a'])
+    assert output.maxlen is None
+
+
+def test_list_deque_deep_freeze():
+    v = SchemaValidator(cs.list_schema(cs.list_schema(), collection='deque', maxlen=2))
+    output = v.validate_python([[1], [2], [3]], deep_freeze=True)
+    # the deque is kept so its `maxlen` still applies, its items are frozen
+    assert type(output) is deque
+    assert output == deque([(2,), (3,)])
+    assert output.maxlen == 2
+
+    v = SchemaValidator(cs.list_schema(collection='deque'))
+    output = v.validate_python([[1], {'a': [2]}], deep_freeze=True)
+    assert output == deque([(1,), {'a': (2,)}])
+    assert type(output[1]) is MappingProxyType
+
+
+def test_list_deque_length_checked_before_maxlen():
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), collection='deque', maxlen=2, max_length=3, min_length=3))
+    assert v.validate_python([1, 2, 3]) == deque([2, 3])
+    with pytest.raises(ValidationError, match='List should have at most 3 items after validation, not 4'):
+        v.validate_python([1, 2, 3, 4])
+    with pytest.raises(ValidationError, match='List should have at least 3 items after validation, not 2'):
+        v.validate_python([1, 2])
+
+
+def test_list_deque_schema_errors():
+    with pytest.raises(SchemaError, match="Invalid collection: set, should be 'list' or 'deque'"):
+        SchemaValidator(cs.list_schema(collection='set'))
+    with pytest.raises(SchemaError, match="`maxlen` can only be used with collection='deque'"):
+        SchemaValidator(cs.list_schema(maxlen=2))
+
+
 def test_list_no_copy():
     v = SchemaValidator(cs.list_schema())
     assert v.validate_python([1, 2, 3]) is not [1, 2, 3]  # noqa: F632
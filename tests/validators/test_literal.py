@@ -172,6 +172,20 @@ def test_literal_none():
     assert plain_repr(v)[: len(expected_repr_start)] == expected_repr_start
 
 
+@pytest.mark.parametrize('expected', [[None], [None, 'a', 1, True], ['a', None]], ids=repr)
+def test_literal_none_python_and_json(expected):
+    v = SchemaValidator(core_schema.literal_schema(expected))
+    assert v.validate_python(None) is None
+    assert v.validate_json('null') is None
+    assert v.isinstance_json('null') is True
+
+    v = SchemaValidator(core_schema.literal_schema([0, '', False]))
+    for validate, input_value in [(v.validate_python, None), (v.validate_json, 'null')]:
+        with pytest.raises(ValidationError) as exc_info:
+            validate(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['type'] == 'literal_error'
+
+
 def test_union():
     v = SchemaValidator(core_schema.union_schema([core_schema.literal_schema(['a', 'b']), core_schema.int_schema()]))
     assert v.validate_python('a') == 'a'
@@ -402,3 +416,27 @@ def test_enum_for_str() -> None:
     for val in [val_enum, val_str]:
         assert val.validate_python('a') == 'a'
         assert val.validate_python(S.a) == 'a'
+
+
+def test_case_insensitive() -> None:
+    class Level(Enum):
+        warning = 'WARNING'
+
+    v = SchemaValidator(core_schema.literal_schema(['DEBUG', 'Info', 1, None, Level.warning], case_insensitive=True))
+    assert v.validate_python('debug') == 'DEBUG'
+    assert v.validate_python('DeBuG') == 'DEBUG'
+    assert v.validate_python('INFO') == 'Info'
+    assert v.validate_json('"debug"') == 'DEBUG'
+    assert v.validate_python(1) == 1
+    assert v.validate_python(None) is None
+    assert v.validate_python(Level.warning) is Level.warning
+
+    for input_value in ['warning', 'debug ', '1', 'none']:
+        with pytest.raises(ValidationError, match='Input should be'):
+            v.validate_python(input_value)
+
+    with pytest.raises(ValidationError, match="Input should be 'DEBUG'"):
+        SchemaValidator(core_schema.literal_schema(['DEBUG'])).validate_python('debug')
+
+    with pytest.raises(SchemaError, match="`expected` has strings which only differ in case: 'a'"):
+        SchemaValidator(core_schema.literal_schema(['A', 'a'], case_insensitive=True))
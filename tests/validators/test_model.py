@@ -1,16 +1,20 @@
+import pickle
 import re
 import sys
 from collections import defaultdict
-from copy import deepcopy
+from copy import copy, deepcopy
+from datetime import date
 from decimal import Decimal
 from typing import Any, Callable, Union
 
 import pytest
 from dirty_equals import HasRepr, IsInstance
 
-from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import CoreConfig, SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 from pydantic_core.core_schema import ExtraBehavior
 
+from ..conftest import PyAndJson
+
 
 def test_model_class():
     class MyModel:
@@ -216,6 +220,38 @@ def test_model_class_setattr():
     assert setattr_calls == []
 
 
+def test_model_config_title():
+    class MyModel:
+        pass
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+        config=CoreConfig(title='Custom Title'),
+    )
+    v = SchemaValidator(schema)
+    assert v.title == 'Custom Title'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.title == 'Custom Title'
+    assert str(exc_info.value).startswith('1 validation error for Custom Title\n')
+
+    # the title only applies to the model as the outermost schema, its name elsewhere is still the class name
+    v = SchemaValidator(core_schema.list_schema(schema))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'a': 'x'}])
+    assert exc_info.value.title == 'list[MyModel]'
+    v = SchemaValidator(core_schema.union_schema([schema, core_schema.int_schema()]))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('MyModel', 'a')
+
+    v = SchemaValidator(
+        core_schema.definitions_schema(core_schema.definition_reference_schema('m'), [{**schema, 'ref': 'm'}])
+    )
+    assert v.title == 'Custom Title'
+
+
 def test_model_class_root_validator_wrap():
     class MyModel:
         def __init__(self, **kwargs: Any) -> None:
@@ -255,6 +291,56 @@ def test_model_class_root_validator_wrap():
     ]
 
 
+def test_model_wrap_validator_retry():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    calls = []
+
+    def f(input_value: dict[str, Any], handler: core_schema.ValidatorFunctionWrapHandler):
+        calls.append(input_value)
+        try:
+            return handler(input_value)
+        except ValidationError:
+            # retry once with string values stripped of whitespace
+            return handler({k: v.strip() if isinstance(v, str) else v for k, v in input_value.items()})
+
+    v = SchemaValidator(
+        core_schema.no_info_wrap_validator_function(
+            f,
+            core_schema.model_schema(
+                MyModel,
+                core_schema.model_fields_schema(
+                    {'a': core_schema.model_field(core_schema.str_schema(pattern='^[a-z]+$'))}
+                ),
+            ),
+        )
+    )
+
+    m = v.validate_python({'a': ' abc '})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'a': 'abc'}
+    assert m.__pydantic_fields_set__ == {'a'}
+    assert calls == [{'a': ' abc '}]
+
+    # the handler also sets attributes on `self_instance` when validating from `__init__`
+    m = MyModel.__new__(MyModel)
+    assert v.validate_python({'a': ' xyz'}, self_instance=m) is m
+    assert m.__dict__ == {'a': 'xyz'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': ' 123 '})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_pattern_mismatch',
+            'loc': ('a',),
+            'msg': "String should match pattern '^[a-z]+$'",
+            'input': '123',
+            'ctx': {'pattern': '^[a-z]+$'},
+        }
+    ]
+
+
 def test_model_class_root_validator_before():
     class MyModel:
         def __init__(self, **kwargs: Any) -> None:
@@ -613,6 +699,548 @@ def test_model_class_instance_subclass_revalidate():
     assert post_init_calls == ['call1', 'call2']
 
 
+def test_model_private_attributes():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        field_a: str
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            cls=MyModel,
+            schema=core_schema.model_fields_schema(
+                fields={'field_a': core_schema.model_field(schema=core_schema.str_schema())}
+            ),
+            private_attributes={
+                '_state': core_schema.model_private_attribute(default='init'),
+                '_history': core_schema.model_private_attribute(default=[]),
+                '_field_a_upper': core_schema.model_private_attribute(
+                    default_factory=lambda data: data['field_a'].upper(), default_factory_takes_data=True
+                ),
+                '_unset': core_schema.model_private_attribute(),
+            },
+        )
+    )
+
+    m1 = v.validate_python({'field_a': 'test'})
+    assert m1.field_a == 'test'
+    assert m1.__pydantic_private__ == {'_state': 'init', '_history': [], '_field_a_upper': 'TEST'}
+
+    m2 = MyModel()
+    v.validate_python({'field_a': 'other'}, self_instance=m2)
+    assert m2.__pydantic_private__ == {'_state': 'init', '_history': [], '_field_a_upper': 'OTHER'}
+    # mutable defaults are copied for each instance
+    assert m2.__pydantic_private__['_history'] is not m1.__pydantic_private__['_history']
+
+
+def test_model_from_tuple():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        field_a: int
+        field_b: str
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            cls=MyModel,
+            schema=core_schema.model_fields_schema(
+                fields={
+                    'field_a': core_schema.model_field(schema=core_schema.int_schema()),
+                    'field_b': core_schema.model_field(schema=core_schema.str_schema()),
+                }
+            ),
+            from_tuple=True,
+        )
+    )
+
+    m = v.validate_python([1, 'x'])
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'field_a': 1, 'field_b': 'x'}
+    assert m.__pydantic_fields_set__ == {'field_a', 'field_b'}
+    assert v.validate_python(('2', 'y')).__dict__ == {'field_a': 2, 'field_b': 'y'}
+    assert v.validate_json('[3, "z"]').__dict__ == {'field_a': 3, 'field_b': 'z'}
+    assert v.validate_python({'field_a': 4, 'field_b': 'w'}).__dict__ == {'field_a': 4, 'field_b': 'w'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x', 'extra'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'model_tuple_length',
+            'loc': (),
+            'msg': 'Input should have 2 items to match the model fields, not 3',
+            'input': [1, 'x', 'extra'],
+            'ctx': {'expected_length': 2, 'actual_length': 3},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['x', 'x'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('field_a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_model_from_tuple_requires_fields():
+    class MyModel:
+        pass
+
+    with pytest.raises(SchemaError, match='`from_tuple` requires a `model-fields` schema'):
+        SchemaValidator(
+            core_schema.model_schema(cls=MyModel, schema=core_schema.int_schema(), root_model=True, from_tuple=True)
+        )
+
+
+def test_model_from_tuple_strict():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            cls=MyModel,
+            schema=core_schema.model_fields_schema(
+                fields={
+                    'field_a': core_schema.model_field(schema=core_schema.int_schema()),
+                    'field_b': core_schema.model_field(schema=core_schema.date_schema()),
+                }
+            ),
+            from_tuple=True,
+        )
+    )
+
+    assert v.validate_python((1, date(2020, 1, 1)), strict=True).__dict__ == {'field_a': 1, 'field_b': date(2020, 1, 1)}
+    # JSON items keep their input type, so a date string is valid in strict mode
+    assert v.validate_json('[1, "2020-01-01"]', strict=True).__dict__ == {'field_a': 1, 'field_b': date(2020, 1, 1)}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, date(2020, 1, 1)], strict=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'model_type',
+            'loc': (),
+            'msg': 'Input should be a valid dictionary or instance of Model',
+            'input': [1, date(2020, 1, 1)],
+            'ctx': {'class_name': 'Model'},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(('1', '2020-01-01'), strict=True)
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_type', 'date_type']
+
+
+def test_model_from_tuple_path_alias():
+    class MyModel:
+        pass
+
+    with pytest.raises(SchemaError, match="does not support the path `validation_alias` of field 'field_a'"):
+        SchemaValidator(
+            core_schema.model_schema(
+                cls=MyModel,
+                schema=core_schema.model_fields_schema(
+                    fields={
+                        'field_a': core_schema.model_field(
+                            schema=core_schema.int_schema(), validation_alias=[['foo', 'bar']]
+                        )
+                    }
+                ),
+                from_tuple=True,
+            )
+        )
+
+
+def test_slotted_model_extra_slot_missing():
+    class MyModel:
+        __slots__ = '__pydantic_fields_set__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            cls=MyModel,
+            schema=core_schema.model_fields_schema(
+                fields={'field_a': core_schema.model_field(schema=core_schema.str_schema())},
+                extra_behavior='allow',
+            ),
+        )
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'test', 'field_b': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('field_b',), 'msg': 'Extra inputs are not permitted', 'input': 1}
+    ]
+
+
+def test_slotted_model_dict_slot_extra():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            cls=MyModel,
+            schema=core_schema.model_fields_schema(
+                fields={'field_a': core_schema.model_field(schema=core_schema.str_schema())},
+                extra_behavior='allow',
+            ),
+        )
+    )
+    # with a `__dict__` slot, extras can be stored without a `__pydantic_extra__` slot
+    m = v.validate_python({'field_a': 'test', 'field_b': 1})
+    assert m.field_a == 'test'
+    assert m.__pydantic_extra__ == {'field_b': 1}
+
+
+def test_extra_field_name():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', 'extras', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {'field_a': core_schema.model_field(core_schema.str_schema())}, extra_behavior='allow'
+        ),
+        extra_field_name='extras',
+        revalidate_instances='always',
+        extra_behavior='allow',
+    )
+    v = SchemaValidator(schema)
+    m = v.validate_python({'field_a': 'test', 'field_b': 1})
+    assert m.__dict__ == {'field_a': 'test'}
+    assert m.extras == {'field_b': 1}
+    assert not hasattr(m, '__pydantic_extra__')
+
+    m2 = v.validate_python(m)
+    assert m2 is not m
+    assert m2.extras == {'field_b': 1}
+
+    v.validate_assignment(m, 'field_c', 2)
+    assert m.extras == {'field_b': 1, 'field_c': 2}
+
+    assert SchemaSerializer(schema).to_python(m) == {'field_a': 'test', 'field_b': 1, 'field_c': 2}
+
+
+def test_require_all():
+    class MyModel:
+        pass
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=2)),
+                }
+            ),
+            require_all=True,
+        )
+    )
+    m = v.validate_python({'a': 1, 'b': 3})
+    assert m.a == 1
+    assert m.b == 3
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 1}}
+    ]
+
+
+def test_require_all_not_inherited():
+    class MyModel:
+        pass
+
+    inner_fields = core_schema.model_fields_schema(
+        {'x': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=1))}
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema({'sub': core_schema.model_field(inner_fields)}),
+            require_all=True,
+        )
+    )
+    # only the model's own fields are required, not those of nested schemas
+    m = v.validate_python({'sub': {}})
+    assert m.sub == ({'x': 1}, None, set())
+
+    with pytest.raises(SchemaError, match='`require_all` requires a `model-fields` schema'):
+        SchemaValidator(core_schema.model_schema(MyModel, core_schema.int_schema(), require_all=True))
+
+
+def test_require_all_wrapped_fields_on_error():
+    class MyModel:
+        pass
+
+    fields_schema = core_schema.model_fields_schema(
+        {
+            'a': core_schema.model_field(core_schema.int_schema()),
+            'b': core_schema.model_field(
+                core_schema.with_default_schema(core_schema.int_schema(), default=2, on_error='default')
+            ),
+        }
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.no_info_wrap_validator_function(lambda value, handler: handler(value), fields_schema),
+            require_all=True,
+            config=core_schema.CoreConfig(title='Wrapped'),
+        )
+    )
+    # on_error='default' still applies to present fields
+    m = v.validate_python({'a': 1, 'b': 'x'})
+    assert m.a == 1
+    assert m.b == 2
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1})
+    assert exc_info.value.title == 'Wrapped'
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 1}}
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,actual_count',
+    [({}, 0), ({'a': 1}, None), ({'b': 2}, None), ({'a': 1, 'b': 2}, 2)],
+)
+def test_model_required_one_of(input_value, actual_count):
+    class MyModel:
+        pass
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            cls=MyModel,
+            schema=core_schema.model_fields_schema(
+                fields={
+                    'a': core_schema.model_field(
+                        schema=core_schema.with_default_schema(core_schema.int_schema(), default=None)
+                    ),
+                    'b': core_schema.model_field(
+                        schema=core_schema.with_default_schema(core_schema.int_schema(), default=None)
+                    ),
+                }
+            ),
+            required_one_of=[['a', 'b']],
+        )
+    )
+
+    if actual_count is None:
+        m = v.validate_python(input_value)
+        assert m.__pydantic_fields_set__ == set(input_value)
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'exactly_one_required',
+                'loc': (),
+                'msg': f"Exactly one of 'a', 'b' should be provided, not {actual_count}",
+                'input': input_value,
+                'ctx': {'fields': "'a', 'b'", 'actual_count': actual_count},
+            }
+        ]
+
+
+def test_model_required_one_of_assignment():
+    class MyModel:
+        pass
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            cls=MyModel,
+            schema=core_schema.model_fields_schema(
+                fields={
+                    'a': core_schema.model_field(
+                        schema=core_schema.with_default_schema(core_schema.int_schema(), default=None)
+                    ),
+                    'b': core_schema.model_field(
+                        schema=core_schema.with_default_schema(core_schema.int_schema(), default=None)
+                    ),
+                }
+            ),
+            required_one_of=[['a', 'b']],
+        )
+    )
+
+    m = v.validate_python({'a': 1})
+    v.validate_assignment(m, 'a', 2)
+    assert m.a == 2
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'b', 3)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'exactly_one_required',
+            'loc': (),
+            'msg': "Exactly one of 'a', 'b' should be provided, not 2",
+            'input': 3,
+            'ctx': {'fields': "'a', 'b'", 'actual_count': 2},
+        }
+    ]
+    # the failed assignment leaves the model unchanged
+    assert m.b is None
+    assert m.__pydantic_fields_set__ == {'a'}
+
+
+def test_model_required_one_of_invalid():
+    class MyModel:
+        pass
+
+    fields_schema = core_schema.model_fields_schema(
+        fields={'a': core_schema.model_field(schema=core_schema.int_schema())}
+    )
+    with pytest.raises(SchemaError, match="`required_one_of` field 'c' is not a field of the model"):
+        SchemaValidator(core_schema.model_schema(MyModel, fields_schema, required_one_of=[['a', 'c']]))
+
+    with pytest.raises(SchemaError, match='`required_one_of` groups must not be empty'):
+        SchemaValidator(core_schema.model_schema(MyModel, fields_schema, required_one_of=[[]]))
+
+
+class LazyModel:
+    __slots__ = (
+        '__dict__',
+        '__pydantic_fields_set__',
+        '__pydantic_extra__',
+        '__pydantic_private__',
+        '__pydantic_lazy__',
+    )
+    __pydantic_validator__: SchemaValidator
+
+    def __getattr__(self, name):
+        pending = object.__getattribute__(self, '__pydantic_lazy__')
+        if name not in pending:
+            raise AttributeError(name)
+        self.__pydantic_validator__.validate_assignment(self, name, pending[name])
+        return self.__dict__[name]
+
+    def __eq__(self, other):
+        return self.__dict__ == other.__dict__ and self.__pydantic_lazy__ == other.__pydantic_lazy__
+
+
+def test_model_lazy_fields():
+    calls = []
+
+    def f(input_value, info):
+        calls.append((info.field_name, info.data))
+        return input_value * 2
+
+    schema = core_schema.model_schema(
+        cls=LazyModel,
+        schema=core_schema.model_fields_schema(
+            fields={
+                'a': core_schema.model_field(schema=core_schema.int_schema()),
+                'b': core_schema.model_field(
+                    schema=core_schema.with_info_after_validator_function(f, core_schema.int_schema())
+                ),
+                'c': core_schema.model_field(
+                    schema=core_schema.with_default_schema(core_schema.int_schema(), default=0)
+                ),
+            }
+        ),
+        lazy_fields=['b', 'c'],
+    )
+    v = LazyModel.__pydantic_validator__ = SchemaValidator(schema)
+
+    m = v.validate_python({'a': 1, 'b': '2', 'c': 'x'})
+    assert calls == []
+    assert m.__dict__ == {'a': 1}
+    assert m.__pydantic_lazy__ == {'b': '2', 'c': 'x'}
+    assert m.__pydantic_fields_set__ == {'a', 'b', 'c'}
+    assert m == v.validate_python({'a': 1, 'b': '2', 'c': 'x'})
+    m_copy = copy(m)
+    m_pickled = pickle.loads(pickle.dumps(m))
+
+    assert m.a == 1
+    assert m.b == 4
+    assert calls == [('b', {'a': 1})]
+    assert m.b == 4
+    assert len(calls) == 1
+    assert m.__dict__ == {'a': 1, 'b': 4}
+    assert m.__pydantic_lazy__ == {'c': 'x'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        m.c
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('c',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    # copies resolve their pending values independently
+    assert m_copy.__dict__ == {'a': 1}
+    assert m_copy.__pydantic_lazy__ == {'b': '2', 'c': 'x'}
+    assert m_pickled.b == 4
+    assert m_pickled.__pydantic_lazy__ == {'c': 'x'}
+
+    # serializing resolves any pending values
+    m = v.validate_python({'a': 1, 'b': 3})
+    s = SchemaSerializer(schema)
+    assert s.to_python(m) == {'a': 1, 'b': 6, 'c': 0}
+    assert s.to_json(m) == b'{"a":1,"b":6,"c":0}'
+    assert m.__dict__ == {'a': 1, 'b': 6, 'c': 0}
+    assert m.__pydantic_lazy__ == {}
+
+    # assigning a lazy field validates it against its declared schema
+    v.validate_assignment(m, 'b', '5')
+    assert m.b == 10
+    with pytest.raises(ValidationError, match=r'b\n  Input should be a valid integer'):
+        v.validate_assignment(m, 'b', 'x')
+
+
+def test_model_lazy_fields_frozen():
+    class MyModel(LazyModel):
+        pass
+
+    v = MyModel.__pydantic_validator__ = SchemaValidator(
+        core_schema.model_schema(
+            cls=MyModel,
+            schema=core_schema.model_fields_schema(
+                fields={'a': core_schema.model_field(schema=core_schema.int_schema())}
+            ),
+            lazy_fields=['a'],
+            frozen=True,
+        )
+    )
+    m = v.validate_python({'a': '1'})
+    # resolving a pending value isn't an assignment, so it's allowed on a frozen model
+    assert m.a == 1
+    with pytest.raises(ValidationError, match='Instance is frozen'):
+        v.validate_assignment(m, 'a', 2)
+
+
+def test_model_mixed_aliases(py_and_json: PyAndJson):
+    class MyModel:
+        pass
+
+    v = py_and_json(
+        core_schema.model_schema(
+            cls=MyModel,
+            schema=core_schema.model_fields_schema(
+                fields={
+                    'field_a': core_schema.model_field(schema=core_schema.int_schema(), validation_alias='FieldA'),
+                    'field_b': core_schema.model_field(schema=core_schema.int_schema()),
+                }
+            ),
+        )
+    )
+    m = v.validate_test({'FieldA': 1, 'field_b': 2})
+    assert m.field_a == 1
+    assert m.field_b == 2
+    assert m.__pydantic_fields_set__ == {'field_a', 'field_b'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'field_a': 1, 'field_b': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('FieldA',), 'msg': 'Field required', 'input': {'field_a': 1, 'field_b': 2}}
+    ]
+
+
 def test_model_class_strict():
     class MyModel:
         def __init__(self):
@@ -1006,6 +1634,57 @@ def test_post_init_mutate():
     assert m.__dict__ == {'field_a': 'testtest', 'field_b': 12}
 
 
+def test_extra_order_preserved_from_json():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, extra_behavior='allow'
+        ),
+        extra_behavior='allow',
+    )
+    v = SchemaValidator(schema)
+
+    m = v.validate_json('{"a":1,"z":2,"b":3}')
+    assert list(m.__pydantic_extra__) == ['z', 'b']
+    assert SchemaSerializer(schema).to_json(m, round_trip=True) == b'{"a":1,"z":2,"b":3}'
+
+
+def test_extra_types():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, extra_behavior='allow'
+        ),
+        extra_behavior='allow',
+    )
+    v = SchemaValidator(schema)
+
+    result = v.validate_python({'a': 1, 'b': 'x', 'c': [1, 2]}, extra_types=True)
+    assert result.value.__pydantic_extra__ == {'b': 'x', 'c': [1, 2]}
+    assert result.extra_types == {('b',): str, ('c',): list}
+
+    assert v.validate_python({'a': 1}, extra_types=True).extra_types == {}
+    assert isinstance(v.validate_python({'a': 1, 'b': 'x'}), MyModel)
+
+    # extra fields are keyed by their location, and only the union member chosen contributes
+    typed_dict_schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(core_schema.str_schema()),
+        }
+    )
+    v = SchemaValidator(core_schema.list_schema(core_schema.union_schema([schema, typed_dict_schema])))
+    result = v.validate_python([{'a': 1, 'b': 2}, {'a': 1, 'b': 'x'}], extra_types=True)
+    assert isinstance(result.value[0], MyModel) and result.value[1] == {'a': 1, 'b': 'x'}
+    assert result.extra_types == {(0, 'b'): int}
+
+
 def test_validate_assignment():
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
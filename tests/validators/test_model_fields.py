@@ -206,6 +206,30 @@ def test_forbid_extra():
     ]
 
 
+def test_forbid_extra_allowed_keys():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={'field_a': core_schema.model_field(schema=core_schema.str_schema())},
+            extra_behavior='forbid',
+            allowed_extra_keys=['_meta'],
+        )
+    )
+
+    assert v.validate_python({'field_a': 'abc', '_meta': {'x': 1}}) == (
+        {'field_a': 'abc'},
+        {'_meta': {'x': 1}},
+        {'field_a', '_meta'},
+    )
+    assert v.validate_python({'field_a': 'abc'}) == ({'field_a': 'abc'}, {}, {'field_a'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'abc', '_meta': 1, 'field_b': 2})
+
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('field_b',), 'msg': 'Extra inputs are not permitted', 'input': 2}
+    ]
+
+
 def test_allow_extra_invalid():
     with pytest.raises(SchemaError, match='extras_schema can only be used if extra_behavior=allow'):
         SchemaValidator(
@@ -69,7 +69,7 @@ def test_frozenset_no_validators_both(py_and_json: PyAndJson, input_value, expec
         ({1: 10, 2: 20, '3': '30'}.values(), {10, 20, 30}),
         ({1: 10, 2: 20, '3': '30'}, Err('Input should be a valid set [type=set_type,')),
         ((x for x in [1, 2, '3']), {1, 2, 3}),
-        ({'abc'}, Err('0\n  Input should be a valid integer')),
+        ({'abc'}, Err('set[int]\n  Input should be a valid integer')),
         ({1: 2}, Err('1 validation error for set[int]\n  Input should be a valid set')),
         ('abc', Err('Input should be a valid set')),
     ],
@@ -97,12 +97,12 @@ def test_set_multiple_errors():
     assert exc_info.value.errors(include_url=False) == [
         {
             'type': 'int_parsing',
-            'loc': (0,),
+            'loc': (),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'a',
         },
-        {'type': 'int_type', 'loc': (1,), 'msg': 'Input should be a valid integer', 'input': (1, 2)},
-        {'type': 'int_type', 'loc': (2,), 'msg': 'Input should be a valid integer', 'input': []},
+        {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': (1, 2)},
+        {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': []},
     ]
 
 
@@ -117,8 +117,8 @@ def test_list_with_unhashable_items():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python([{'a': 'b'}, unhashable])
     assert exc_info.value.errors(include_url=False) == [
-        {'type': 'set_item_not_hashable', 'loc': (0,), 'msg': 'Set items should be hashable', 'input': {'a': 'b'}},
-        {'type': 'set_item_not_hashable', 'loc': (1,), 'msg': 'Set items should be hashable', 'input': unhashable},
+        {'type': 'set_item_not_hashable', 'loc': (), 'msg': 'Set items should be hashable', 'input': {'a': 'b'}},
+        {'type': 'set_item_not_hashable', 'loc': (), 'msg': 'Set items should be hashable', 'input': unhashable},
     ]
 
 
@@ -156,6 +156,16 @@ def generate_repeats():
         # length check after set creation
         ({'max_length': 3}, [1, 1, 2, 2, 3, 3], {1, 2, 3}),
         ({'max_length': 3}, generate_repeats(), {1, 2, 3}),
+        (
+            {'min_length': 2},
+            [1, 1, 1],
+            Err('Set should have at least 2 items after validation, not 1 [type=too_short,'),
+        ),
+        (
+            {'min_length': 2, 'items_schema': {'type': 'int'}},
+            [1, '1', 1.0],
+            Err('Set should have at least 2 items after validation, not 1 [type=too_short,'),
+        ),
         (
             {'max_length': 3},
             infinite_generator(),
@@ -197,14 +207,14 @@ def test_union_set_list(input_value, expected):
                 errors=[
                     {
                         'type': 'int_type',
-                        'loc': ('set[int]', 1),
+                        'loc': ('set[int]',),
                         'msg': 'Input should be a valid integer',
                         'input': 'a',
                     },
                     # second because validation on the string choice comes second
                     {
                         'type': 'string_type',
-                        'loc': ('set[str]', 0),
+                        'loc': ('set[str]',),
                         'msg': 'Input should be a valid string',
                         'input': 1,
                     },
@@ -305,7 +315,7 @@ def test_set_any(input_value, expected):
             [
                 {
                     'type': 'int_parsing',
-                    'loc': (1,),
+                    'loc': (),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
                 },
@@ -317,13 +327,13 @@ def test_set_any(input_value, expected):
             [
                 {
                     'type': 'int_parsing',
-                    'loc': (1,),
+                    'loc': (),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
                 },
                 {
                     'type': 'int_parsing',
-                    'loc': (2,),
+                    'loc': (),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'again',
                 },
@@ -339,3 +349,21 @@ def test_set_fail_fast(fail_fast, expected):
         v.validate_python([1, 'not-num', 'again'])
 
     assert exc_info.value.errors(include_url=False) == expected
+
+
+def test_set_item_error_location():
+    v = SchemaValidator(cs.set_schema(items_schema=cs.int_schema()))
+    assert v.validate_python({1, '2'}) == {1, 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1, 'not-num'})
+
+    # the item's position in the set isn't included in the location, the item is the error's input
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'not-num',
+        }
+    ]
@@ -271,6 +271,26 @@ def test_lax_subclass_plain_enum(kwargs):
     assert repr(p) == "'one'"
 
 
+@pytest.mark.parametrize('kwargs', [{}, {'to_lower': True}], ids=repr)
+def test_lax_path(kwargs):
+    v = SchemaValidator(core_schema.str_schema(**kwargs))
+
+    from pathlib import PurePosixPath
+
+    p = v.validate_python(PurePosixPath('/tmp/foo.txt'))
+    assert p == '/tmp/foo.txt'
+    assert type(p) is str
+
+    class BytesPath:
+        def __fspath__(self):
+            return b'/tmp/foo.txt'
+
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(BytesPath())
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(PurePosixPath('/tmp/foo.txt'), strict=True)
+
+
 def test_subclass_preserved() -> None:
     class StrSubclass(str):
         pass
@@ -391,6 +411,27 @@ def test_backtracking_regex_python(mode) -> None:
         v.validate_python('r#"#')
 
 
+def test_lookahead_regex_python() -> None:
+    pattern = r'^(?=.*\d)[a-z\d]+$'
+    with pytest.raises(SchemaError, match='look-around, including look-ahead and look-behind, is not supported'):
+        SchemaValidator(core_schema.str_schema(pattern=pattern))
+
+    v = SchemaValidator(core_schema.str_schema(pattern=pattern, regex_engine='python-re'))
+    assert v.validate_python('abc1') == 'abc1'
+    assert v.validate_json('"abc1"') == 'abc1'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('abc')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_pattern_mismatch',
+            'loc': (),
+            'msg': f"String should match pattern '{pattern}'",
+            'input': 'abc',
+            'ctx': {'pattern': pattern},
+        }
+    ]
+
+
 @pytest.mark.parametrize('number', (42, 443, 10242))
 def test_coerce_numbers_to_str_schema(number: int):
     v = SchemaValidator(core_schema.str_schema(coerce_numbers_to_str=True))
@@ -427,3 +468,297 @@ def test_compiled_regex(engine) -> None:
     v = SchemaValidator(core_schema.str_schema(pattern=re.compile('abc', re.IGNORECASE), regex_engine=engine))
     assert v.validate_python('abc') == 'abc'
     assert v.validate_python('ABC') == 'ABC'
+
+
+def test_in_set() -> None:
+    allowed = {f'value_{i}' for i in range(1000)}
+    v = SchemaValidator(core_schema.str_schema(in_set=allowed, strip_whitespace=True, to_lower=True))
+    assert v.validate_python('value_0') == 'value_0'
+    assert v.validate_python(' VALUE_999 ') == 'value_999'
+    assert v.validate_json('"Value_500"') == 'value_500'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('value_1000')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_not_in_set',
+            'loc': (),
+            'msg': 'String should be one of the allowed values',
+            'input': 'value_1000',
+        }
+    ]
+
+
+def test_collapse_whitespace() -> None:
+    v = SchemaValidator(core_schema.str_schema(collapse_whitespace=True))
+    assert v.validate_python('a   b\tc') == 'a b c'
+    assert v.validate_python(' a \n\n b ') == ' a b '
+    assert v.validate_json('"a \\t\\n b"') == 'a b'
+
+    v = SchemaValidator(core_schema.str_schema(collapse_whitespace=True, strip_whitespace=True, max_length=5))
+    assert v.validate_python('  a   b\tc  ') == 'a b c'
+    with pytest.raises(ValidationError, match='String should have at most 5 characters'):
+        v.validate_python('a   b\tc d')
+
+
+@pytest.mark.parametrize(
+    'kwargs,expected',
+    [
+        ({'strip_leading': True}, 'foo  '),
+        ({'strip_trailing': True}, '\t foo'),
+        ({'strip_leading': True, 'strip_trailing': True}, 'foo'),
+        ({'strip_whitespace': True}, 'foo'),
+        ({'strip_whitespace': True, 'strip_leading': False}, 'foo'),
+    ],
+)
+def test_strip_leading_trailing(kwargs, expected) -> None:
+    v = SchemaValidator(core_schema.str_schema(**kwargs))
+    assert v.validate_python('\t foo  ') == expected
+    assert v.validate_json('"\\t foo  "') == expected
+
+
+def test_strip_leading_before_length() -> None:
+    v = SchemaValidator(core_schema.str_schema(strip_leading=True, min_length=4))
+    assert v.validate_python('  foo ') == 'foo '
+    with pytest.raises(ValidationError, match='String should have at least 4 characters'):
+        v.validate_python('  foo')
+    with pytest.raises(ValidationError, match='String should have at least 4 characters'):
+        v.validate_json('"  foo"')
+
+
+@pytest.mark.parametrize(
+    'form,expected',
+    [
+        ('NFC', 'caf\u00e9 \ufb01'),
+        ('NFD', 'cafe\u0301 \ufb01'),
+        ('NFKC', 'caf\u00e9 fi'),
+        ('NFKD', 'cafe\u0301 fi'),
+    ],
+)
+def test_normalize(form, expected) -> None:
+    v = SchemaValidator(core_schema.str_schema(normalize=form))
+    assert v.validate_python('caf\u00e9 \ufb01') == expected
+    assert v.validate_python('cafe\u0301 \ufb01') == expected
+    assert v.validate_json('"cafe\\u0301 \\ufb01"') == expected
+
+
+def test_normalize_before_length() -> None:
+    v = SchemaValidator(core_schema.str_schema(normalize='NFC', max_length=4, pattern='^caf\u00e9$'))
+    assert v.validate_python('cafe\u0301') == 'caf\u00e9'
+    assert v.validate_json('"cafe\u0301"') == 'caf\u00e9'
+
+    # without normalization the decomposed form is 5 characters
+    v = SchemaValidator(core_schema.str_schema(max_length=4))
+    with pytest.raises(ValidationError, match='String should have at most 4 characters'):
+        v.validate_python('cafe\u0301')
+
+    with pytest.raises(SchemaError, match="Invalid normalization form: nfc, should be 'NFC', 'NFD', 'NFKC' or 'NFKD'"):
+        SchemaValidator(core_schema.str_schema(normalize='nfc'))
+
+
+def test_strict_nonempty() -> None:
+    v = SchemaValidator(core_schema.str_schema(strict_nonempty=True))
+    assert v.validate_python(' x ') == ' x '
+    assert v.validate_json('"x"') == 'x'
+
+    for input_value in ('   ', '', '\t\n'):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        # insert_assert(exc_info.value.errors(include_url=False))
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'string_too_short',
+                'loc': (),
+                'msg': 'String should have at least 1 character',
+                'input': input_value,
+                'ctx': {'min_length': 1},
+            }
+        ]
+
+    v = SchemaValidator(core_schema.str_schema(strict_nonempty=True, strip_whitespace=True))
+    assert v.validate_python(' x ') == 'x'
+    with pytest.raises(ValidationError, match='String should have at least 1 character'):
+        v.validate_python('   ')
+
+
+def test_intern_strings() -> None:
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    # build equal strings at runtime so they're distinct objects
+    input_value = [''.join(['cat', 'egory']) for _ in range(3)] + [''.join(['oth', 'er'])]
+    assert input_value[0] is not input_value[1]
+
+    output = v.validate_python(input_value)
+    assert output[0] is not output[1]
+
+    output = v.validate_python(input_value, intern_strings=True)
+    assert output == ['category', 'category', 'category', 'other']
+    assert output[0] is output[1] is output[2]
+    assert output[3] is not output[0]
+
+    # constrained strings are interned after their transformations
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema(to_lower=True)))
+    output = v.validate_python(['A', 'a', 'B'], intern_strings=True)
+    assert output == ['a', 'a', 'b']
+    assert output[0] is output[1]
+
+
+def test_format_uri() -> None:
+    v = SchemaValidator(core_schema.str_schema(format='uri'))
+    # the original string is returned, not a normalised URL
+    output = v.validate_python('HTTPS://Example.com')
+    assert output == 'HTTPS://Example.com'
+    assert type(output) is str
+    assert v.validate_json('"mailto:foo@example.com"') == 'mailto:foo@example.com'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('not a uri')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_format',
+            'loc': (),
+            'msg': 'String should be a valid uri, relative URL without a base',
+            'input': 'not a uri',
+            'ctx': {'format': 'uri', 'error': 'relative URL without a base'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'format,value',
+    [
+        ('email', 'foo.bar+baz@example.com'),
+        ('email', 'x@sub.example.co.uk'),
+        ('hostname', 'example.com'),
+        ('hostname', 'localhost'),
+        ('hostname', 'my-host.example.com.'),
+        ('ipv4', '192.168.0.1'),
+        ('ipv6', '::1'),
+        ('ipv6', '2001:db8::8a2e:370:7334'),
+        ('base64', ''),
+        ('base64', 'aGVsbG8='),
+        ('base64', 'aGVsbG8hIQ=='),
+        ('base64', 'a+b/c9Z0'),
+        ('base32', 'NBSWY3DP'),
+        ('base32', 'NBSWY3DPEE======'),
+        ('base32', 'NBSWY3DPEEQQ===='),
+        ('json_pointer', ''),
+        ('json_pointer', '/'),
+        ('json_pointer', '/a/b'),
+        ('json_pointer', '/a~1b/0/~0c'),
+    ],
+)
+def test_format_valid(format, value) -> None:
+    v = SchemaValidator(core_schema.str_schema(format=format))
+    assert v.validate_python(value) == value
+
+
+@pytest.mark.parametrize(
+    'format,value,error',
+    [
+        ('email', 'foobar.com', "missing an '@' sign"),
+        ('email', '@example.com', "the part before the '@' should have 1 to 64 characters"),
+        ('email', 'foo..bar@example.com', "the part before the '@' contains invalid characters"),
+        ('email', 'foo@localhost', "the domain should contain a '.'"),
+        ('email', 'foo@exa_mple.com', 'the domain is invalid, labels should only contain letters, digits and hyphens'),
+        ('hostname', '', 'should have 1 to 253 characters'),
+        ('hostname', 'foo..com', 'each label should have 1 to 63 characters'),
+        ('hostname', 'a' * 64 + '.com', 'each label should have 1 to 63 characters'),
+        ('hostname', '-foo.com', 'labels should not start or end with a hyphen'),
+        ('hostname', 'foo bar', 'labels should only contain letters, digits and hyphens'),
+        ('ipv4', '256.0.0.1', 'invalid IPv4 address syntax'),
+        ('ipv4', '::1', 'invalid IPv4 address syntax'),
+        ('ipv6', '192.168.0.1', 'invalid IPv6 address syntax'),
+        ('ipv6', '1:2:3:4:5:6:7:8:9', 'invalid IPv6 address syntax'),
+        ('base64', 'aGVsbG8', 'the length should be a multiple of 4'),
+        ('base64', 'aGVs bG8=', 'contains invalid characters'),
+        ('base64', 'aGV-bG8_', 'contains invalid characters'),
+        ('base64', 'aG=sbG8=', 'contains invalid characters'),
+        ('base64', 'a===', 'the padding is invalid'),
+        ('base32', 'nbswy3dp', 'contains invalid characters'),
+        ('base32', 'NBSWY3D', 'the length should be a multiple of 8'),
+        ('base32', 'NBSWYE==', 'the padding is invalid'),
+        ('language_tag', 'e', 'should start with a language subtag of 2 to 8 letters'),
+        ('language_tag', '12-US', 'should start with a language subtag of 2 to 8 letters'),
+        ('language_tag', 'en--US', 'each subtag should have 1 to 8 letters or digits'),
+        ('language_tag', 'en_US', 'each subtag should have 1 to 8 letters or digits'),
+        ('language_tag', 'en-abcdefghi', 'each subtag should have 1 to 8 letters or digits'),
+        ('language_tag', 'en-US-u', 'a singleton subtag should be followed by another subtag'),
+        ('json_pointer', 'a/b', "should be empty or start with '/'"),
+        ('json_pointer', '/a~2b', "'~' should be followed by '0' or '1'"),
+        ('json_pointer', '/a~', "'~' should be followed by '0' or '1'"),
+    ],
+)
+def test_format_invalid_value(format, value, error) -> None:
+    v = SchemaValidator(core_schema.str_schema(format=format))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_format',
+            'loc': (),
+            'msg': f'String should be a valid {format}, {error}',
+            'input': value,
+            'ctx': {'format': format, 'error': error},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        ('en', 'en'),
+        ('EN', 'en'),
+        ('en-us', 'en-US'),
+        ('zh-hant-tw', 'zh-Hant-TW'),
+        ('es-419', 'es-419'),
+        ('sl-ROZAJ-biske', 'sl-rozaj-biske'),
+        ('de-DE-u-CO-PHONEBK', 'de-DE-u-co-phonebk'),
+        ('en-a-BB-x-Private', 'en-a-bb-x-private'),
+        ('X-WHATEVER', 'x-whatever'),
+    ],
+)
+def test_format_language_tag(value, expected) -> None:
+    v = SchemaValidator(core_schema.str_schema(format='language_tag'))
+    assert v.validate_python(value) == expected
+    assert v.validate_json(f'"{value}"') == expected
+
+
+def test_format_invalid() -> None:
+    with pytest.raises(SchemaError, match='Invalid string format: uuid'):
+        SchemaValidator(core_schema.str_schema(format='uuid'))
+
+
+def test_regex_step_limit() -> None:
+    # a pattern which is catastrophic for backtracking engines
+    v = SchemaValidator(core_schema.str_schema(pattern=r'^(a+)+$', regex_step_limit=1000))
+    assert v.validate_python('a' * 10) == 'a' * 10
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v.validate_python('a' * 10 + 'b')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('a' * 200 + 'b')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'regex_timeout',
+            'loc': (),
+            'msg': 'Matching the pattern would exceed the limit of 1000 steps',
+            'input': 'a' * 200 + 'b',
+            'ctx': {'step_limit': 1000},
+        }
+    ]
+
+    # a bigger limit allows longer values
+    v = SchemaValidator(core_schema.str_schema(pattern=r'^(a+)+$', regex_step_limit=100_000))
+    assert v.validate_python('a' * 200) == 'a' * 200
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v.validate_python('a' * 200 + 'b')
+
+
+def test_regex_step_limit_unsupported() -> None:
+    with pytest.raises(SchemaError, match="'regex_step_limit' is only supported by the 'rust-regex' regex engine"):
+        SchemaValidator(core_schema.str_schema(pattern='a+', regex_engine='python-re', regex_step_limit=1000))
+    with pytest.raises(SchemaError, match="'regex_step_limit' is not supported with a compiled `re.Pattern`"):
+        SchemaValidator(core_schema.str_schema(pattern=re.compile('a+'), regex_step_limit=1000))
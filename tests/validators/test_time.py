@@ -193,7 +193,7 @@ def test_time_bound_ctx():
             'loc': (),
             'msg': 'Input should be greater than 12:13:14.123456',
             'input': '12:13',
-            'ctx': {'gt': '12:13:14.123456'},
+            'ctx': {'gt': '12:13:14.123456', 'input': '12:13:00'},
         }
     ]
 
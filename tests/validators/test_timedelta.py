@@ -284,6 +284,53 @@ def test_large_value():
         v.validate_python(f'{999_999_999 + 1}days, 12:34')
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('P1DT2H30M', timedelta(days=1, hours=2, minutes=30)),
+        ('-P1D', timedelta(days=-1)),
+        ('PT-5M', timedelta(minutes=-5)),
+        ('PT1H-5M', timedelta(minutes=55)),
+        ('PT0.5S', timedelta(seconds=0.5)),
+        ('P0,5D', timedelta(hours=12)),
+        ('P1W', timedelta(days=7)),
+        (90.5, timedelta(seconds=90.5)),
+        ('01:02:03', Err("ISO 8601 duration should start with 'P', got '01:02:03' [type=time_delta_parsing")),
+        ('PT5X', Err("invalid component '5X' in ISO 8601 duration [type=time_delta_parsing")),
+        ('P1', Err("invalid component '1' in ISO 8601 duration [type=time_delta_parsing")),
+        ('PT', Err("ISO 8601 duration 'PT' has no components after 'T' [type=time_delta_parsing")),
+    ],
+)
+def test_duration_format_iso8601(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.timedelta_schema(duration_format='iso8601'))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_duration_format_number_or_colon(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.timedelta_schema(duration_format='number-or-colon'))
+    assert v.validate_test('01:02:03') == timedelta(hours=1, minutes=2, seconds=3)
+    assert v.validate_test(90.5) == timedelta(seconds=90.5)
+    with pytest.raises(ValidationError, match=re.escape("ISO 8601 duration 'P1D' is not accepted")):
+        v.validate_test('P1D')
+
+
+def test_duration_format_iso8601_precision():
+    v = SchemaValidator(core_schema.timedelta_schema(duration_format='iso8601', microseconds_precision='error'))
+    assert v.validate_python('PT0.000001S') == timedelta(microseconds=1)
+    with pytest.raises(ValidationError, match="component '0.0000001S' in ISO 8601 duration is more precise than"):
+        v.validate_python('PT0.0000001S')
+
+    v = SchemaValidator(core_schema.timedelta_schema(duration_format='iso8601'))
+    assert v.validate_python('PT0.0000019S') == timedelta(microseconds=1)
+
+    with pytest.raises(SchemaError, match="Invalid duration_format: iso, should be 'number-or-colon' or 'iso8601'"):
+        SchemaValidator(core_schema.timedelta_schema(duration_format='iso'))
+
+
 @pytest.mark.skipif(not pandas, reason='pandas not installed')
 def test_pandas():
     v = SchemaValidator(core_schema.timedelta_schema(ge=timedelta(hours=2)))
@@ -1,11 +1,11 @@
 import re
 from collections import deque
-from typing import Any
+from typing import Any, NamedTuple
 
 import pytest
 from dirty_equals import IsNonNegative, IsTuple
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -558,3 +558,105 @@ def test_tuple_fail_fast(fail_fast, expected):
         v.validate_python(['str', 'not-num', 'again'])
 
     assert exc_info.value.errors(include_url=False) == expected
+
+
+@pytest.mark.parametrize(
+    'sequence_coercion,strict,input_value,expected',
+    [
+        (None, False, [1, '2'], (1, 2)),
+        (None, True, [1, '2'], Err('Input should be a valid tuple [type=tuple_type,')),
+        ('lax', False, [1, '2'], (1, 2)),
+        ('lax', True, [1, 2], Err('Input should be a valid tuple [type=tuple_type,')),
+        ('always', True, [1, 2], (1, 2)),
+        ('always', True, (1, 2), (1, 2)),
+        ('always', False, '12', Err('Input should be a valid tuple [type=tuple_type,')),
+        ('always', True, {1, 2}, Err('Input should be a valid tuple [type=tuple_type,')),
+        ('always', False, {1, 2}, (1, 2)),
+        ('never', False, [1, 2], Err('Input should be a valid tuple [type=tuple_type,')),
+        ('never', False, (1, '2'), (1, 2)),
+    ],
+)
+def test_sequence_coercion(sequence_coercion, strict, input_value, expected):
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [core_schema.int_schema()], variadic_item_index=0, sequence_coercion=sequence_coercion, strict=strict
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_sequence_coercion_always_strict():
+    v = SchemaValidator(
+        core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0, sequence_coercion='always')
+    )
+    assert v.validate_python([1, 2], strict=True) == (1, 2)
+    # only lists are let through, their items are still validated strictly
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid integer [type=int_type,')):
+        v.validate_python(['1'], strict=True)
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid tuple [type=tuple_type,')):
+        v.validate_python(iter([1, 2]), strict=True)
+
+
+def test_sequence_coercion_invalid():
+    with pytest.raises(SchemaError, match='Invalid sequence coercion: `sometimes`, expected `lax`, `always` or `never`'):
+        SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], sequence_coercion='sometimes'))
+
+
+class Point(NamedTuple):
+    x: int
+    y: str
+
+
+def test_namedtuple_cls():
+    v = SchemaValidator(
+        core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()], namedtuple_cls=Point)
+    )
+    output = v.validate_python(('1', 'a'))
+    assert type(output) is Point
+    assert output == Point(1, 'a')
+    assert v.validate_python([2, 'b']) == Point(2, 'b')
+    assert v.validate_python(Point('3', 'c')) == Point(3, 'c')
+    assert type(v.validate_json('[4, "d"]')) is Point
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(('x', 1))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('x',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'string_type', 'loc': ('y',), 'msg': 'Input should be a valid string', 'input': 1},
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1,))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('y',), 'msg': 'Field required', 'input': (1,)}
+    ]
+
+
+def test_namedtuple_cls_invalid():
+    with pytest.raises(SchemaError, match='`namedtuple_cls` must have `_fields` and a `_make` method'):
+        SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], namedtuple_cls=tuple))
+
+    class BadFields(Point):
+        _fields = (1, 2)
+
+    with pytest.raises(SchemaError, match='`namedtuple_cls._fields` must be a sequence of strings'):
+        SchemaValidator(
+            core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()], namedtuple_cls=BadFields)
+        )
+    with pytest.raises(SchemaError, match='`namedtuple_cls` has 2 fields but `items_schema` has 1 items'):
+        SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], namedtuple_cls=Point))
+    with pytest.raises(SchemaError, match="`namedtuple_cls` can't be used with `variadic_item_index`"):
+        SchemaValidator(
+            core_schema.tuple_schema(
+                [core_schema.int_schema(), core_schema.str_schema()], variadic_item_index=1, namedtuple_cls=Point
+            )
+        )
@@ -154,6 +154,20 @@ def test_config(config: CoreConfig, input_value, expected):
         assert output_dict == expected
 
 
+def test_config_title():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())},
+            cls_name='MyTypedDict',
+            config=CoreConfig(title='Custom Title'),
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.title == 'Custom Title'
+    assert str(exc_info.value).startswith('1 validation error for Custom Title\n')
+
+
 def test_ignore_extra():
     v = SchemaValidator(
         core_schema.typed_dict_schema(
@@ -189,6 +203,228 @@ def test_forbid_extra(schema_extra_behavior: dict[str, Any], validate_fn_extra_k
     ]
 
 
+def test_forbid_extra_suggestion():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={
+                'name': core_schema.typed_dict_field(schema=core_schema.str_schema(), required=False),
+                'age': core_schema.typed_dict_field(schema=core_schema.int_schema(), required=False),
+            },
+            extra_behavior='forbid',
+        )
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'nme': 'Samuel', 'something_else': 1})
+
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'extra_forbidden',
+            'loc': ('nme',),
+            'msg': 'Extra inputs are not permitted',
+            'input': 'Samuel',
+            'ctx': {'suggestion': 'name'},
+        },
+        {'type': 'extra_forbidden', 'loc': ('something_else',), 'msg': 'Extra inputs are not permitted', 'input': 1},
+    ]
+
+    # a field that's already in the input isn't suggested
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'Samuel', 'nme': 'Samuel'})
+
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('nme',), 'msg': 'Extra inputs are not permitted', 'input': 'Samuel'}
+    ]
+
+
+def test_forbid_extra_suggestion_alias():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={
+                'name': core_schema.typed_dict_field(
+                    schema=core_schema.str_schema(), validation_alias='fullName', required=False
+                ),
+            },
+            extra_behavior='forbid',
+        )
+    )
+
+    # the alias is what the input should use, so it's suggested rather than the field name
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'fulName': 'Samuel'})
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'suggestion': 'fullName'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'nme': 'Samuel'})
+    assert 'ctx' not in exc_info.value.errors(include_url=False)[0]
+
+    # validating by name, the field name is accepted as well
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'nme': 'Samuel'}, by_name=True)
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'suggestion': 'name'}
+
+
+def test_forbid_extra_allowed_keys():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={'field_a': core_schema.typed_dict_field(schema=core_schema.str_schema())},
+            extra_behavior='forbid',
+            allowed_extra_keys=['_meta'],
+        )
+    )
+
+    assert v.validate_python({'field_a': 'abc', '_meta': 1}) == {'field_a': 'abc', '_meta': 1}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'abc', '_meta': 1, 'other': 2})
+
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('other',), 'msg': 'Extra inputs are not permitted', 'input': 2}
+    ]
+
+
+def test_coerce_keys():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={
+                '1': core_schema.typed_dict_field(schema=core_schema.str_schema()),
+                'b': core_schema.typed_dict_field(schema=core_schema.int_schema(), required=False),
+            },
+            coerce_keys=True,
+        )
+    )
+    assert v.validate_python({1: 'x'}) == {'1': 'x'}
+    assert v.validate_python({1: 'x', 'b': '2'}) == {'1': 'x', 'b': 2}
+    assert v.validate_python({'1': 'x'}) == {'1': 'x'}
+
+    # errors report the original input, not the coerced copy
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 'x', 'b': 'y'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'y',
+        }
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({2: 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('1',), 'msg': 'Field required', 'input': {2: 'x'}}
+    ]
+
+    # an integer key and a string key which are the same once coerced collide
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 'a', '1': 'b'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'key_collision',
+            'loc': ('1',),
+            'msg': 'Multiple keys are the same once coerced to a string',
+            'input': {1: 'a', '1': 'b'},
+        }
+    ]
+
+    # keys are only coerced in lax mode
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 'x'}, strict=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('1',), 'msg': 'Field required', 'input': {1: 'x'}}
+    ]
+
+
+def test_case_insensitive_keys():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={
+                'name': core_schema.typed_dict_field(schema=core_schema.str_schema()),
+                'age': core_schema.typed_dict_field(schema=core_schema.int_schema(), required=False),
+            },
+            extra_behavior='forbid',
+            case_insensitive_keys=True,
+        )
+    )
+    assert v.validate_python({'Name': 'x'}) == {'name': 'x'}
+    assert v.validate_python({'NAME': 'x', 'Age': '2'}) == {'name': 'x', 'age': 2}
+    assert v.validate_python({'name': 'x'}) == {'name': 'x'}
+
+    # errors are located at the input's own key
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'NAME': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': ('NAME',), 'msg': 'Input should be a valid string', 'input': 1}
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'Name': 'x', 'name': 'y'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'ambiguous_key',
+            'loc': ('name',),
+            'msg': 'Multiple keys match this field when ignoring case',
+            'input': {'Name': 'x', 'name': 'y'},
+        }
+    ]
+
+    with pytest.raises(SchemaError, match="Fields 'a' and 'A' only differ in case"):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                fields={
+                    'a': core_schema.typed_dict_field(schema=core_schema.int_schema()),
+                    'A': core_schema.typed_dict_field(schema=core_schema.int_schema()),
+                },
+                case_insensitive_keys=True,
+            )
+        )
+
+
+def test_case_insensitive_keys_json_and_mapping():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={
+                'name': core_schema.typed_dict_field(schema=core_schema.str_schema()),
+                'age': core_schema.typed_dict_field(schema=core_schema.int_schema(), required=False),
+            },
+            extra_behavior='forbid',
+            case_insensitive_keys=True,
+        )
+    )
+    assert v.validate_json('{"NAME": "x", "Age": 2}') == {'name': 'x', 'age': 2}
+    assert v.validate_json('{"name": "x", "name": "y"}') == {'name': 'y'}
+    assert v.validate_python(Map(Name='x', AGE='3')) == {'name': 'x', 'age': 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"Name": "x", "name": "y", "other": 1}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'ambiguous_key',
+            'loc': ('name',),
+            'msg': 'Multiple keys match this field when ignoring case',
+            'input': {'Name': 'x', 'name': 'y', 'other': 1},
+        },
+        {'type': 'extra_forbidden', 'loc': ('other',), 'msg': 'Extra inputs are not permitted', 'input': 1},
+    ]
+
+
+def test_case_insensitive_keys_alias():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={
+                'name': core_schema.typed_dict_field(schema=core_schema.str_schema(), validation_alias='fullName'),
+                'city': core_schema.typed_dict_field(
+                    schema=core_schema.str_schema(), validation_alias=['address', 'city'], required=False
+                ),
+            },
+            extra_behavior='forbid',
+            case_insensitive_keys=True,
+        )
+    )
+    assert v.validate_python({'FULLNAME': 'x', 'Address': {'city': 'y'}}) == {'name': 'x', 'city': 'y'}
+    assert v.validate_json('{"fullname": "x"}') == {'name': 'x'}
+    assert v.validate_python({'Name': 'x'}, by_name=True) == {'name': 'x'}
+
+
 def test_allow_extra_invalid():
     with pytest.raises(SchemaError, match='extras_schema can only be used if extra_behavior=allow'):
         SchemaValidator(
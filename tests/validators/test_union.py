@@ -1427,3 +1427,82 @@ def test_smart_union_wrap_validator_should_not_change_nested_model_field_counts(
     assert isinstance(m, RootModel)
     assert isinstance(m.ab, ModelA)
     assert m.ab.sub.x == 'y'
+
+
+@pytest.mark.parametrize('mode', ['smart', 'left_to_right'])
+def test_union_matches(mode) -> None:
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode=mode)
+        )
+    )
+
+    result = v.validate_python([1, 'a', 2, 'b'], union_matches=True)
+    assert result.value == [1, 'a', 2, 'b']
+    assert result.union_matches == [((0,), 0), ((1,), 1), ((2,), 0), ((3,), 1)]
+    assert v.validate_python([], union_matches=True).union_matches == []
+
+
+def test_union_matches_nested() -> None:
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.list_schema(
+                    core_schema.union_schema([core_schema.int_schema(strict=True), core_schema.bool_schema()])
+                ),
+                core_schema.list_schema(core_schema.str_schema()),
+            ]
+        )
+    )
+
+    # matches of unions within a losing member aren't recorded
+    assert v.validate_python(['a', 'b'], union_matches=True).union_matches == [((), 1)]
+    assert v.validate_python([1, True], union_matches=True).union_matches == [((0,), 0), ((1,), 1), ((), 0)]
+
+
+def test_union_matches_losing_success() -> None:
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.list_schema(
+                    core_schema.union_schema([core_schema.float_schema(), core_schema.str_schema()])
+                ),
+                core_schema.list_schema(core_schema.int_schema()),
+            ]
+        )
+    )
+
+    # the first member succeeds but the second is an exact match, so only its matches are kept
+    assert v.validate_python([1], union_matches=True).union_matches == [((), 1)]
+    assert v.validate_python([1.5], union_matches=True).union_matches == [((0,), 0), ((), 0)]
+
+
+def test_union_matches_tagged() -> None:
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'pet': core_schema.typed_dict_field(
+                    core_schema.tagged_union_schema(
+                        discriminator='kind',
+                        choices={
+                            'cat': core_schema.typed_dict_schema(
+                                {
+                                    'kind': core_schema.typed_dict_field(core_schema.literal_schema(['cat'])),
+                                    'lives': core_schema.typed_dict_field(
+                                        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()])
+                                    ),
+                                }
+                            ),
+                            'dog': core_schema.typed_dict_schema(
+                                {'kind': core_schema.typed_dict_field(core_schema.literal_schema(['dog']))}
+                            ),
+                        },
+                    )
+                )
+            }
+        )
+    )
+
+    result = v.validate_python({'pet': {'kind': 'cat', 'lives': 9}}, union_matches=True)
+    assert result.union_matches == [(('pet', 'lives'), 0), (('pet',), 'cat')]
+    assert v.validate_python({'pet': {'kind': 'dog'}}, union_matches=True).union_matches == [(('pet',), 'dog')]
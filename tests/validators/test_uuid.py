@@ -180,6 +180,31 @@ def test_uuid_version(input_value, version, expected):
         assert isinstance(output, UUID)
 
 
+@pytest.mark.parametrize(
+    'input_value,observed_version',
+    [
+        ('a6cc5730-2261-11ee-9c43-2eb5a363657c', 1),
+        (UUID('a6cc5730-2261-11ee-9c43-2eb5a363657c'), 1),
+        ('00000000-0000-4000-0000-000000000000', None),
+        (UUID('00000000-0000-4000-0000-000000000000'), None),
+    ],
+)
+def test_uuid_version_context(input_value, observed_version):
+    v = SchemaValidator(core_schema.uuid_schema(version=4))
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'uuid_version',
+            'loc': (),
+            'msg': 'UUID version 4 expected',
+            'input': input_value,
+            'ctx': {'expected_version': 4, 'observed_version': observed_version},
+        }
+    ]
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
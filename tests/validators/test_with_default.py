@@ -1002,3 +1002,100 @@ def test_default_validate_default_after_validator_field_name() -> None:
     val.validate_python({})
 
     assert field_name == 'a'
+
+
+def test_validate_defaults_on_build() -> None:
+    schema = core_schema.typed_dict_schema(
+        {
+            'x': core_schema.typed_dict_field(
+                core_schema.with_default_schema(core_schema.int_schema(le=10), default=100)
+            ),
+        }
+    )
+    # by default an invalid default is only noticed if it's validated
+    assert SchemaValidator(schema).validate_python({}) == {'x': 100}
+
+    schema['config'] = core_schema.CoreConfig(validate_defaults_on_build=True)
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(schema)
+    assert exc_info.value.errors() == [
+        {'type': 'less_than_equal', 'loc': (), 'msg': 'Input should be less than or equal to 10', 'input': 100}
+    ]
+
+    schema['fields']['x']['schema']['default'] = 5
+    assert SchemaValidator(schema).validate_python({}) == {'x': 5}
+
+
+def test_validate_defaults_on_build_definitions() -> None:
+    # the default's schema references a definition which is only filled after the default is built
+    schema = core_schema.definitions_schema(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.definition_reference_schema('small-int'), default=100)
+                ),
+            },
+            config=core_schema.CoreConfig(validate_defaults_on_build=True),
+        ),
+        [core_schema.int_schema(le=10, ref='small-int')],
+    )
+    with pytest.raises(SchemaError, match='Input should be less than or equal to 10'):
+        SchemaValidator(schema)
+
+    schema['schema']['config']['validate_defaults_on_build'] = False
+    assert SchemaValidator(schema).validate_python({}) == {'x': 100}
+
+
+def test_defaults_used() -> None:
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default=2)),
+                'c': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=3, on_error='default')
+                ),
+                'd': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default_factory=list)
+                ),
+            }
+        )
+    )
+    result = v.validate_python({'a': 1, 'c': 'x', 'd': 4}, defaults_used=True)
+    assert result.value == {'a': 1, 'b': 2, 'c': 3, 'd': 4}
+    assert result.defaults_used == {('b',), ('c',)}
+
+    result = v.validate_python({'a': 1, 'b': 5, 'c': 6}, defaults_used=True)
+    assert result.value == {'a': 1, 'b': 5, 'c': 6, 'd': []}
+    assert result.defaults_used == {('d',)}
+
+    assert v.validate_python({'a': 1, 'b': 5, 'c': 6, 'd': 7}, defaults_used=True).defaults_used == set()
+    assert v.validate_python({'a': 1}) == {'a': 1, 'b': 2, 'c': 3, 'd': []}
+
+
+def test_defaults_used_nested_union() -> None:
+    inner = core_schema.typed_dict_schema(
+        {
+            'x': core_schema.typed_dict_field(core_schema.int_schema()),
+            'y': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+        }
+    )
+    other = core_schema.typed_dict_schema(
+        {
+            'z': core_schema.typed_dict_field(core_schema.int_schema()),
+            'y': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default=1)),
+        }
+    )
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'items': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.union_schema([other, inner]))
+                ),
+                'y': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default=2)),
+            }
+        )
+    )
+    result = v.validate_python({'items': [{'x': 1}, {'x': 2, 'y': 3}, {'z': 4}]}, defaults_used=True)
+    assert result.value == {'items': [{'x': 1, 'y': 0}, {'x': 2, 'y': 3}, {'z': 4, 'y': 1}], 'y': 2}
+    assert result.defaults_used == {('items', 0, 'y'), ('items', 2, 'y'), ('y',)}